@@ -5,6 +5,8 @@ pub const AFTER_FAILED_AUTOMATION_REPLY_ID: u64 = 2;
 pub const AFTER_DELEGATION_REPLY_ID: u64 = 3;
 pub const AFTER_PROVIDE_LIQUIDITY_REPLY_ID: u64 = 4;
 pub const AFTER_BOND_LP_TOKENS_REPLY_ID: u64 = 5;
+pub const AFTER_EXECUTE_TRIGGER_REPLY_ID: u64 = 6;
+pub const AFTER_RETRY_POST_EXECUTION_ACTION_REPLY_ID: u64 = 7;
 
 pub const ONE_MICRON: Uint128 = Uint128::new(1);
 pub const TWO_MICRONS: Uint128 = Uint128::new(2);
@@ -14,7 +16,16 @@ pub const TEN: Uint128 = Uint128::new(10000000);
 pub const ONE_HUNDRED: Uint128 = Uint128::new(100000000);
 pub const ONE_THOUSAND: Uint128 = Uint128::new(1000000000);
 
+pub const MINIMUM_SWAP_AMOUNT: Uint128 = Uint128::new(50000);
+
 pub const ONE_DECIMAL: Decimal = Decimal::new(Uint128::new(1000000000000000000));
 pub const TWO_DECIMAL: Decimal = Decimal::new(Uint128::new(2000000000000000000));
 
+pub const MAX_POST_EXECUTION_ACTION_ATTEMPTS: u8 = 3;
+
+pub const DEFAULT_MIGRATION_LIMIT: u16 = 200;
+
+pub const IBC_TRANSFER_PORT: &str = "transfer";
+pub const IBC_TRANSFER_TIMEOUT_SECONDS: u64 = 3600;
+
 pub const SWAP_FEE_RATE: &str = "0.001";
@@ -1,41 +1,63 @@
 use crate::constants::{
-    AFTER_BOND_LP_TOKENS_REPLY_ID, AFTER_DELEGATION_REPLY_ID, AFTER_FAILED_AUTOMATION_REPLY_ID,
-    AFTER_PROVIDE_LIQUIDITY_REPLY_ID, AFTER_SWAP_REPLY_ID,
+    AFTER_BOND_LP_TOKENS_REPLY_ID, AFTER_DELEGATION_REPLY_ID, AFTER_EXECUTE_TRIGGER_REPLY_ID,
+    AFTER_FAILED_AUTOMATION_REPLY_ID, AFTER_PROVIDE_LIQUIDITY_REPLY_ID,
+    AFTER_RETRY_POST_EXECUTION_ACTION_REPLY_ID, AFTER_SWAP_REPLY_ID,
 };
 use crate::error::ContractError;
+use crate::handlers::accept_admin::accept_admin_handler;
+use crate::handlers::accept_vault_ownership::accept_vault_ownership_handler;
 use crate::handlers::cancel_vault::cancel_vault_handler;
+use crate::handlers::claim_vault::claim_vault_handler;
 use crate::handlers::create_custom_swap_fee::create_custom_swap_fee_handler;
 use crate::handlers::create_pair::create_pair_handler;
 use crate::handlers::create_pairs::create_pairs_handler;
 use crate::handlers::create_vault::create_vault_handler;
+use crate::handlers::delete_pair::delete_pair_handler;
 use crate::handlers::deposit::deposit_handler;
 use crate::handlers::disburse_escrow::disburse_escrow_handler;
 use crate::handlers::disburse_funds::disburse_funds_handler;
 use crate::handlers::execute_trigger::execute_trigger_handler;
-use crate::handlers::fix_position_type::fix_position_type;
+use crate::handlers::execute_triggers::{execute_triggers_handler, log_execute_trigger_result};
 use crate::handlers::get_config::get_config_handler;
 use crate::handlers::get_custom_swap_fees::get_custom_swap_fees_handler;
 use crate::handlers::get_disburse_escrow_tasks::get_disburse_escrow_tasks_handler;
 use crate::handlers::get_events::get_events_handler;
 use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+use crate::handlers::get_failed_post_execution_actions::get_failed_post_execution_actions_handler;
 use crate::handlers::get_pairs::get_pairs_handler;
+use crate::handlers::get_price_trigger_ids::get_price_trigger_ids_handler;
 use crate::handlers::get_time_trigger_ids::get_time_trigger_ids_handler;
 use crate::handlers::get_vault::get_vault_handler;
 use crate::handlers::get_vault_performance::get_vault_performance_handler;
+use crate::handlers::get_vault_schedule::get_vault_schedule_handler;
 use crate::handlers::get_vaults::get_vaults_handler;
 use crate::handlers::get_vaults_by_address::get_vaults_by_address_handler;
 use crate::handlers::handle_failed_automation::handle_failed_automation_handler;
+use crate::handlers::ibc_hook::ibc_hook_handler;
+use crate::handlers::ibc_lifecycle_complete::ibc_lifecycle_complete_handler;
 use crate::handlers::instantiate::instantiate_handler;
 use crate::handlers::migrate::migrate_handler;
+use crate::handlers::pause_contract::pause_contract_handler;
+use crate::handlers::pause_vault::pause_vault_handler;
 use crate::handlers::remove_custom_swap_fee::remove_custom_swap_fee_handler;
+use crate::handlers::resume_contract::resume_contract_handler;
+use crate::handlers::resume_vault::resume_vault_handler;
+use crate::handlers::retry_post_execution_action::{
+    log_retry_post_execution_action_result, retry_post_execution_action_handler,
+};
+use crate::handlers::simulate_execution::simulate_execution_handler;
+use crate::handlers::transfer_admin::transfer_admin_handler;
+use crate::handlers::transfer_vault_ownership::transfer_vault_ownership_handler;
 use crate::handlers::update_config::update_config_handler;
 use crate::handlers::update_swap_adjustment_handler::update_swap_adjustment_handler;
 use crate::handlers::update_vault::update_vault_handler;
+use crate::handlers::withdraw_from_vault::withdraw_from_vault_handler;
 use crate::handlers::z_delegate::{log_delegation_result, z_delegate_handler};
 use crate::handlers::z_provide_liquidity::{
     bond_lp_tokens, log_bond_lp_tokens_result, z_provide_liquidity_handler,
 };
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg};
+use crate::types::vault_settings::VaultSettings;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{
     entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
@@ -71,7 +93,21 @@ pub fn execute(
             base_denom,
             quote_denom,
             route,
-        } => create_pair_handler(deps, info, base_denom, quote_denom, route),
+            weighted_routes,
+            minimum_swap_amounts,
+            twap_period,
+            default_slippage_tolerance,
+        } => create_pair_handler(
+            deps,
+            info,
+            base_denom,
+            quote_denom,
+            route,
+            weighted_routes,
+            minimum_swap_amounts,
+            twap_period,
+            default_slippage_tolerance,
+        ),
         ExecuteMsg::CreateVault {
             owner,
             label,
@@ -85,6 +121,13 @@ pub fn execute(
             target_start_time_utc_seconds,
             performance_assessment_strategy,
             swap_adjustment_strategy,
+            target_price,
+            stop_loss,
+            take_profit,
+            execution_window,
+            end_time_utc_seconds,
+            max_executions,
+            accumulation,
         } => create_vault_handler(
             deps,
             env,
@@ -101,6 +144,15 @@ pub fn execute(
             target_start_time_utc_seconds,
             performance_assessment_strategy,
             swap_adjustment_strategy,
+            VaultSettings {
+                target_price,
+                stop_loss,
+                take_profit,
+                execution_window,
+                end_time_utc_seconds,
+                max_executions,
+                accumulation,
+            },
         ),
         ExecuteMsg::UpdateVault {
             vault_id,
@@ -110,8 +162,13 @@ pub fn execute(
             minimum_receive_amount,
             time_interval,
             swap_adjustment_strategy,
+            stop_loss,
+            take_profit,
+            clear_stop_loss,
+            clear_take_profit,
         } => update_vault_handler(
             deps,
+            env,
             info,
             vault_id,
             label,
@@ -120,12 +177,33 @@ pub fn execute(
             minimum_receive_amount,
             time_interval,
             swap_adjustment_strategy,
+            stop_loss,
+            take_profit,
+            clear_stop_loss,
+            clear_take_profit,
         ),
         ExecuteMsg::CancelVault { vault_id } => cancel_vault_handler(deps, env, info, vault_id),
-        ExecuteMsg::ExecuteTrigger { trigger_id } => execute_trigger_handler(deps, env, trigger_id),
+        ExecuteMsg::PauseVault { vault_id } => pause_vault_handler(deps, env, info, vault_id),
+        ExecuteMsg::ResumeVault { vault_id } => resume_vault_handler(deps, env, info, vault_id),
+        ExecuteMsg::TransferVaultOwnership {
+            vault_id,
+            new_owner,
+        } => transfer_vault_ownership_handler(deps, info, vault_id, new_owner),
+        ExecuteMsg::AcceptVaultOwnership { vault_id } => {
+            accept_vault_ownership_handler(deps, env, info, vault_id)
+        }
+        ExecuteMsg::ExecuteTrigger { trigger_id } => {
+            execute_trigger_handler(deps, env, info, trigger_id)
+        }
+        ExecuteMsg::ExecuteTriggers { trigger_ids } => {
+            execute_triggers_handler(deps, env, info, trigger_ids)
+        }
         ExecuteMsg::Deposit { address, vault_id } => {
             deposit_handler(deps, env, info, address, vault_id)
         }
+        ExecuteMsg::WithdrawFromVault { vault_id, amount } => {
+            withdraw_from_vault_handler(deps, env, info, vault_id, amount)
+        }
         ExecuteMsg::UpdateConfig {
             executors,
             fee_collectors,
@@ -137,6 +215,11 @@ pub fn execute(
             risk_weighted_average_escrow_level,
             twap_period,
             default_slippage_tolerance,
+            retry_policy,
+            keeper_reward_percent,
+            pair_managers,
+            fee_managers,
+            pausers,
         } => update_config_handler(
             deps,
             info,
@@ -150,6 +233,11 @@ pub fn execute(
             risk_weighted_average_escrow_level,
             twap_period,
             default_slippage_tolerance,
+            retry_policy,
+            keeper_reward_percent,
+            pair_managers,
+            fee_managers,
+            pausers,
         ),
         ExecuteMsg::CreateCustomSwapFee {
             denom,
@@ -189,7 +277,31 @@ pub fn execute(
             slippage_tolerance,
         ),
         ExecuteMsg::CreatePairs { pairs } => create_pairs_handler(deps, info, pairs),
-        ExecuteMsg::FixPositionType { vault_id } => fix_position_type(deps, vault_id),
+        ExecuteMsg::DeletePair {
+            base_denom,
+            quote_denom,
+            force_cancel_vaults,
+        } => delete_pair_handler(
+            deps,
+            env,
+            info,
+            base_denom,
+            quote_denom,
+            force_cancel_vaults,
+        ),
+        ExecuteMsg::TransferAdmin { new_admin } => transfer_admin_handler(deps, info, new_admin),
+        ExecuteMsg::AcceptAdmin {} => accept_admin_handler(deps, info),
+        ExecuteMsg::PauseContract {} => pause_contract_handler(deps, info),
+        ExecuteMsg::ResumeContract {} => resume_contract_handler(deps, info),
+        ExecuteMsg::ClaimVault { vault_id } => claim_vault_handler(deps, env, info, vault_id),
+        ExecuteMsg::RetryPostExecutionAction { vault_id } => {
+            retry_post_execution_action_handler(deps, env, info, vault_id)
+        }
+        ExecuteMsg::IbcHook {
+            channel_id,
+            original_sender,
+            msg,
+        } => ibc_hook_handler(deps, env, info, channel_id, original_sender, *msg),
     }
 }
 
@@ -201,9 +313,18 @@ pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, Contract
         AFTER_DELEGATION_REPLY_ID => log_delegation_result(reply),
         AFTER_PROVIDE_LIQUIDITY_REPLY_ID => bond_lp_tokens(deps.as_ref(), env),
         AFTER_BOND_LP_TOKENS_REPLY_ID => log_bond_lp_tokens_result(deps, reply),
-        id => Err(ContractError::CustomError {
-            val: format!("unhandled DCA contract reply id: {}", id),
-        }),
+        AFTER_EXECUTE_TRIGGER_REPLY_ID => log_execute_trigger_result(deps, reply),
+        AFTER_RETRY_POST_EXECUTION_ACTION_REPLY_ID => {
+            log_retry_post_execution_action_result(deps, env, reply)
+        }
+        id => Err(ContractError::UnhandledReplyId { id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::IbcLifecycleComplete(result) => ibc_lifecycle_complete_handler(deps, env, result),
     }
 }
 
@@ -216,6 +337,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetTimeTriggerIds { limit } => {
             to_binary(&get_time_trigger_ids_handler(deps, env, limit)?)
         }
+        QueryMsg::GetPriceTriggerIds { start_after, limit } => to_binary(
+            &get_price_trigger_ids_handler(deps, env, start_after, limit)?,
+        ),
         QueryMsg::GetVaults { start_after, limit } => {
             to_binary(&get_vaults_handler(deps, start_after, limit)?)
         }
@@ -257,5 +381,14 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetDisburseEscrowTasks { limit } => {
             to_binary(&get_disburse_escrow_tasks_handler(deps, env, limit)?)
         }
+        QueryMsg::SimulateExecution { vault_id } => {
+            to_binary(&simulate_execution_handler(deps, env, vault_id)?)
+        }
+        QueryMsg::GetVaultSchedule { vault_id, limit } => {
+            to_binary(&get_vault_schedule_handler(deps, vault_id, limit)?)
+        }
+        QueryMsg::GetFailedPostExecutionActions { vault_id } => {
+            to_binary(&get_failed_post_execution_actions_handler(deps, vault_id)?)
+        }
     }
 }
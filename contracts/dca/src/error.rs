@@ -1,4 +1,4 @@
-use cosmwasm_std::{CheckedMultiplyRatioError, OverflowError, StdError};
+use cosmwasm_std::{CheckedMultiplyRatioError, Decimal, OverflowError, StdError, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -11,6 +11,278 @@ pub enum ContractError {
 
     #[error("Error: {val}")]
     CustomError { val: String },
+
+    #[error("Error: contract is paused")]
+    ContractPaused {},
+
+    #[error("Error: received {count} denoms but required exactly 1")]
+    InvalidFundsCount { count: usize },
+
+    #[error("Error: received asset with denom {received}, but needed {expected}")]
+    UnexpectedDenom { received: String, expected: String },
+
+    #[error("Error: {label} address {address} is invalid")]
+    InvalidAddress { label: String, address: String },
+
+    #[error("Error: there is no pending admin transfer")]
+    NoPendingAdminTransfer {},
+
+    #[error("Error: new admin must be different to the current admin")]
+    NewAdminIsCurrentAdmin {},
+
+    #[error("Error: new owner must be different to the current vault owner")]
+    NewOwnerIsCurrentOwner {},
+
+    #[error("Error: vault {vault_id} has no pending ownership transfer")]
+    NoPendingOwnershipTransfer { vault_id: Uint128 },
+
+    #[error("Error: provided an incorrect owner address for vault id {vault_id}")]
+    IncorrectVaultOwner { vault_id: Uint128 },
+
+    #[error("Error: vault is already cancelled")]
+    VaultAlreadyCancelled { vault_id: Uint128 },
+
+    #[error("Error: vault with id {vault_id} is cancelled, and is not available for execution")]
+    VaultCancelled { vault_id: Uint128 },
+
+    #[error("Error: vault with id {vault_id} has no trigger attached, and is not available for execution")]
+    VaultHasNoTrigger { vault_id: Uint128 },
+
+    #[error("Error: only active vaults can be paused")]
+    VaultNotActive { vault_id: Uint128 },
+
+    #[error("Error: only paused vaults can be resumed")]
+    VaultNotPaused { vault_id: Uint128 },
+
+    #[error("Error: vault {vault_id} has no balance left to resume")]
+    VaultBalanceIsEmpty { vault_id: Uint128 },
+
+    #[error("Error: claim_threshold must be greater than 0")]
+    ZeroClaimThreshold {},
+
+    #[error("Error: vault {vault_id} has no claimable funds")]
+    NothingToClaim { vault_id: Uint128 },
+
+    #[error("Error: withdrawal amount must be greater than 0")]
+    ZeroWithdrawalAmount {},
+
+    #[error("Error: cannot withdraw {amount} from vault with a balance of {balance}")]
+    InsufficientVaultBalance { amount: Uint128, balance: Uint128 },
+
+    #[error("Error: cannot withdraw more than {maximum} while the vault performance is being compared to a standard dca")]
+    WithdrawalExceedsStandardDcaBalance { maximum: Uint128 },
+
+    #[error("Error: Escrow is not available to be disbursed yet")]
+    EscrowNotDue { vault_id: Uint128 },
+
+    #[error("Error: swap amount must be greater than {minimum_swap_amount}")]
+    SwapAmountTooSmall { minimum_swap_amount: Uint128 },
+
+    #[error("Error: Vault label cannot be longer than 100 characters")]
+    LabelTooLong {},
+
+    #[error("Error: target_start_time_utc_seconds must be some time in the future")]
+    StartTimeInPast {},
+
+    #[error("Error: end_time_utc_seconds must be after the vault start time and some time in the future")]
+    InvalidEndTime {},
+
+    #[error("Error: max_executions must be greater than 0")]
+    ZeroMaxExecutions {},
+
+    #[error("Error: cannot provide both a target_start_time_utc_seconds and a target_price")]
+    StartTimeWithTargetPrice {},
+
+    #[error("Error: trigger execution time has not yet elapsed")]
+    TriggerTimeNotElapsed {},
+
+    #[error("Error: trigger target price {target_price} has not yet been reached (current price {current_price})")]
+    TriggerPriceNotReached {
+        target_price: Decimal,
+        current_price: Decimal,
+    },
+
+    #[error("Error: target_price must be greater than 0")]
+    ZeroTargetPrice {},
+
+    #[error("Error: stop_loss and take_profit prices must be greater than 0")]
+    ZeroTerminationPrice {},
+
+    #[error("Error: belief price must be greater than 0")]
+    ZeroBeliefPrice {},
+
+    #[error("Error: stop_loss price must be less than take_profit price")]
+    StopLossNotBelowTakeProfit {},
+
+    #[error("Error: custom time interval must be at least 60 seconds")]
+    CustomTimeIntervalTooShort { seconds: u64 },
+
+    #[error("Error: calendar time interval must include at least one day")]
+    EmptyCalendarTimeInterval {},
+
+    #[error("Error: days of the month must be between 1 and 31")]
+    InvalidDayOfMonth {},
+
+    #[error("Error: {hour:02}:{minute:02} is not a valid UTC time of day")]
+    InvalidTimeOfDay { hour: u8, minute: u8 },
+
+    #[error(
+        "Error: execution window start hour must be less than 24 and end hour must be at most 24"
+    )]
+    InvalidExecutionWindowHours { start_hour: u8, end_hour: u8 },
+
+    #[error("Error: execution window start and end hours must be different")]
+    EmptyExecutionWindow {},
+
+    #[error("Error: execution window must not exclude every day of the week")]
+    ExecutionWindowExcludesEveryDay {},
+
+    #[error("Error: no more than 10 destinations can be provided")]
+    TooManyDestinations { count: usize },
+
+    #[error("Error: destination allocations must add up to 1")]
+    InvalidDestinationAllocation { total: Decimal },
+
+    #[error("Error: all destination allocations must be greater than 0")]
+    ZeroDestinationAllocation {},
+
+    #[error("Error: Cannot invoke provided destination callback against the DCA contract")]
+    InvalidDestinationCallback {},
+
+    #[error("Error: ibc channel id {channel_id} is invalid")]
+    InvalidIbcChannel { channel_id: String },
+
+    #[error("Error: ibc transfer receiver must not be empty")]
+    EmptyIbcReceiver {},
+
+    #[error("Error: ibc transfer memo must be a json object without an ibc_callback field")]
+    InvalidIbcMemo {},
+
+    #[error(
+        "Error: no pending ibc transfer found for channel {channel_id} and sequence {sequence}"
+    )]
+    IbcTransferNotFound { channel_id: String, sequence: u64 },
+
+    #[error("Error: ibc hook messages must wrap a create_vault or deposit message")]
+    UnsupportedIbcHookMsg {},
+
+    #[error("Error: vaults created via ibc hooks must provide an owner")]
+    IbcHookOwnerRequired {},
+
+    #[error("Error: vault {vault_id} has no failed post execution actions to retry")]
+    NoFailedPostExecutionActions { vault_id: Uint128 },
+
+    #[error("Error: at least one trigger id must be provided")]
+    NoTriggerIds {},
+
+    #[error("Error: no more than {limit} trigger ids can be provided")]
+    TooManyTriggerIds { limit: u16 },
+
+    #[error("Error: no pending trigger id found for execute trigger reply")]
+    NoPendingTriggerId {},
+
+    #[error("Error: keeper_reward_percent must be less than or equal to 100%, and expressed as a ratio out of 1 (i.e. use 0.1 to represent a reward of 10% of the fee)")]
+    InvalidKeeperRewardPercent { keeper_reward_percent: Decimal },
+
+    #[error("Error: retry policy backoff must be at least 60 seconds")]
+    RetryBackoffTooShort { backoff_seconds: u64 },
+
+    #[error("Error: twap_period must be between 30 and 3600")]
+    InvalidTwapPeriod { twap_period: u64 },
+
+    #[error("Error: slippage tolerance must be less than or equal to 1")]
+    InvalidSlippageTolerance { slippage_tolerance: Decimal },
+
+    #[error("Error: fee collector allocations must add up to 1")]
+    InvalidFeeCollectorAllocation { total: Decimal },
+
+    #[error("Error: no more than 10 fee collectors are allowed")]
+    TooManyFeeCollectors { count: usize },
+
+    #[error("Error: risk_weighted_average_escrow_level cannot be greater than 100%")]
+    InvalidEscrowLevel { escrow_level: Decimal },
+
+    #[error("Error: fee level cannot be larger than 5%")]
+    FeeLevelTooLarge { fee_percent: Decimal },
+
+    #[error("Error: Custom fee for {denom} does not exist")]
+    CustomFeeNotFound { denom: String },
+
+    #[error("Error: limit cannot be less than 30.")]
+    PageLimitTooSmall { limit: u16 },
+
+    #[error("Error: limit cannot be greater than 1000.")]
+    PageLimitTooLarge { limit: u16 },
+
+    #[error("Error: validator {validator_address} is invalid")]
+    InvalidValidator { validator_address: String },
+
+    #[error("Error: {denom} is not the bond denomination")]
+    NotBondDenom { denom: String },
+
+    #[error("Error: {denom} is not supported")]
+    UnsupportedDenom { denom: String },
+
+    #[error("Error: swapping {swap_denom} to {target_denom} not supported")]
+    UnsupportedPair {
+        swap_denom: String,
+        target_denom: String,
+    },
+
+    #[error("Error: pair for {base_denom} and {quote_denom} does not exist")]
+    PairNotFound {
+        base_denom: String,
+        quote_denom: String,
+    },
+
+    #[error("Error: cannot delete pair for {base_denom} and {quote_denom} while {vault_count} active, scheduled or paused vaults are still using it")]
+    PairInUse {
+        base_denom: String,
+        quote_denom: String,
+        vault_count: usize,
+    },
+
+    #[error("Error: minimum swap amount denom {denom} must be a pair denom and appear only once")]
+    InvalidMinimumSwapAmountDenom { denom: String },
+
+    #[error("Error: Swap route must not be empty")]
+    EmptyRoute {},
+
+    #[error("Error: Swap route must not contain duplicate entries")]
+    DuplicateRouteEntries {},
+
+    #[error("Error: Weighted routes must not be empty")]
+    EmptyWeightedRoutes {},
+
+    #[error("Error: Weighted routes must not contain duplicate routes")]
+    DuplicateWeightedRoutes {},
+
+    #[error("Error: Weighted route weights must be greater than 0")]
+    ZeroWeightedRouteWeight {},
+
+    #[error("Error: Weighted route weights must add up to 1")]
+    InvalidWeightedRouteWeights { total: Decimal },
+
+    #[error("Error: incompatible swap adjustment and performance assessment strategies")]
+    IncompatibleStrategies {},
+
+    #[error(
+        "Error: swap adjustment value for strategy {strategy} must be between {min} and {max}"
+    )]
+    InvalidSwapAdjustmentValue {
+        strategy: String,
+        min: Decimal,
+        max: Decimal,
+    },
+
+    #[error("Error: cannot update swap adjustment strategy from {from} to {to}")]
+    InvalidSwapAdjustmentStrategyUpdate { from: String, to: String },
+
+    #[error("Error: Cannot set weighted scale multiplier to more than 10")]
+    WeightedScaleMultiplierTooLarge { multiplier: Decimal },
+
+    #[error("Error: unhandled DCA contract reply id: {id}")]
+    UnhandledReplyId { id: u64 },
 }
 
 impl From<OverflowError> for ContractError {
@@ -31,8 +303,46 @@ impl From<ContractError> for StdError {
     fn from(from: ContractError) -> Self {
         match from {
             ContractError::Std(err) => err,
-            ContractError::CustomError { val } => StdError::generic_err(val),
-            _ => StdError::generic_err(format!("{:#?}", from)),
+            ContractError::Unauthorized {} => StdError::generic_err(format!("{:#?}", from)),
+            err => {
+                let message = err.to_string();
+                StdError::generic_err(message.strip_prefix("Error: ").unwrap_or(&message))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_errors_keep_their_messages_when_converted_to_std_errors() {
+        let error: StdError = ContractError::VaultCancelled {
+            vault_id: Uint128::one(),
+        }
+        .into();
+
+        assert_eq!(
+            error.to_string(),
+            "Generic error: vault with id 1 is cancelled, and is not available for execution"
+        );
+    }
+
+    #[test]
+    fn custom_errors_keep_their_messages_when_converted_to_std_errors() {
+        let error: StdError = ContractError::CustomError {
+            val: String::from("Error: nested"),
         }
+        .into();
+
+        assert_eq!(error.to_string(), "Generic error: Error: nested");
+    }
+
+    #[test]
+    fn unauthorized_errors_keep_their_messages_when_converted_to_std_errors() {
+        let error: StdError = ContractError::Unauthorized {}.into();
+
+        assert_eq!(error.to_string(), "Generic error: Unauthorized");
     }
 }
@@ -0,0 +1,84 @@
+use crate::error::ContractError;
+use crate::helpers::validation::assert_sender_is_pending_admin;
+use crate::state::config::{get_config, update_config};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn accept_admin_handler(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    assert_sender_is_pending_admin(deps.storage, &info.sender)?;
+
+    let mut config = get_config(deps.storage)?;
+
+    let previous_admin = config.admin.clone();
+
+    config.admin = info.sender.clone();
+    config.pending_admin = None;
+
+    update_config(deps.storage, config)?;
+
+    Ok(Response::new()
+        .add_attribute("accept_admin", "true")
+        .add_attribute("previous_admin", previous_admin)
+        .add_attribute("admin", info.sender))
+}
+
+#[cfg(test)]
+mod accept_admin_tests {
+    use super::*;
+    use crate::handlers::transfer_admin::transfer_admin_handler;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    const NEW_ADMIN: &str = "new-admin";
+
+    #[test]
+    fn with_no_pending_admin_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = accept_admin_handler(deps.as_mut(), mock_info(NEW_ADMIN, &[])).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: there is no pending admin transfer");
+    }
+
+    #[test]
+    fn with_sender_other_than_pending_admin_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        transfer_admin_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(NEW_ADMIN),
+        )
+        .unwrap();
+
+        let err = accept_admin_handler(deps.as_mut(), mock_info(USER, &[])).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn makes_pending_admin_the_admin() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        transfer_admin_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(NEW_ADMIN),
+        )
+        .unwrap();
+
+        accept_admin_handler(deps.as_mut(), mock_info(NEW_ADMIN, &[])).unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.admin, Addr::unchecked(NEW_ADMIN));
+        assert_eq!(config.pending_admin, None);
+    }
+}
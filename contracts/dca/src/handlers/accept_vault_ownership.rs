@@ -0,0 +1,346 @@
+use crate::error::ContractError;
+use crate::helpers::validation::{
+    assert_sender_is_pending_vault_owner, assert_vault_is_not_cancelled,
+};
+use crate::state::events::create_event;
+use crate::state::vaults::{get_vault, update_vault};
+use crate::types::destination::{Destination, DestinationAction};
+use crate::types::event::{EventBuilder, EventData};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn accept_vault_ownership_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+) -> Result<Response, ContractError> {
+    let mut vault = get_vault(deps.storage, vault_id)?;
+
+    assert_sender_is_pending_vault_owner(&vault, info.sender.clone())?;
+    assert_vault_is_not_cancelled(&vault)?;
+
+    let previous_owner = vault.owner.clone();
+
+    vault.destinations = vault
+        .destinations
+        .into_iter()
+        .map(|destination| {
+            if destination.address != previous_owner {
+                return destination;
+            }
+            match destination.action {
+                DestinationAction::Send {}
+                | DestinationAction::Delegate { .. }
+                | DestinationAction::ProvideLiquidity { .. } => Destination {
+                    address: info.sender.clone(),
+                    ..destination
+                },
+                // deposits can only go into vaults owned by the destination address, so the new
+                // owner receives the funds directly instead
+                DestinationAction::DepositToVault { .. } => Destination {
+                    address: info.sender.clone(),
+                    action: DestinationAction::Send {},
+                    ..destination
+                },
+                DestinationAction::ExecuteContract { .. }
+                | DestinationAction::IbcTransfer { .. } => destination,
+            }
+        })
+        .collect();
+
+    vault.owner = info.sender.clone();
+    vault.pending_owner = None;
+
+    update_vault(deps.storage, vault.clone())?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            vault.id,
+            env.block,
+            EventData::DcaVaultOwnershipTransferred {
+                previous_owner: previous_owner.clone(),
+                new_owner: vault.owner.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("accept_vault_ownership", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("previous_owner", previous_owner)
+        .add_attribute("owner", vault.owner))
+}
+
+#[cfg(test)]
+mod accept_vault_ownership_tests {
+    use super::*;
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::handlers::transfer_vault_ownership::transfer_vault_ownership_handler;
+    use crate::state::vaults::get_vaults_by_address;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::vault::{Vault, VaultStatus};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{to_binary, Addr, Decimal};
+
+    const NEW_OWNER: &str = "new-owner";
+
+    #[test]
+    fn without_pending_transfer_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err =
+            accept_vault_ownership_handler(deps.as_mut(), env, mock_info(NEW_OWNER, &[]), vault.id)
+                .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: vault {} has no pending ownership transfer",
+                vault.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_sender_other_than_pending_owner_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                ..Vault::default()
+            },
+        );
+
+        let err =
+            accept_vault_ownership_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_cancelled_vault_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Cancelled,
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                ..Vault::default()
+            },
+        );
+
+        let err =
+            accept_vault_ownership_handler(deps.as_mut(), env, mock_info(NEW_OWNER, &[]), vault.id)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: vault is already cancelled");
+    }
+
+    #[test]
+    fn transfers_ownership_and_moves_owner_indexes() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        transfer_vault_ownership_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            vault.id,
+            Addr::unchecked(NEW_OWNER),
+        )
+        .unwrap();
+
+        accept_vault_ownership_handler(deps.as_mut(), env, mock_info(NEW_OWNER, &[]), vault.id)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.owner, Addr::unchecked(NEW_OWNER));
+        assert_eq!(updated_vault.pending_owner, None);
+        assert!(
+            get_vaults_by_address(deps.as_ref().storage, vault.owner, None, None, None)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            get_vaults_by_address(
+                deps.as_ref().storage,
+                Addr::unchecked(NEW_OWNER),
+                Some(VaultStatus::Active),
+                None,
+                None
+            )
+            .unwrap(),
+            vec![updated_vault]
+        );
+    }
+
+    #[test]
+    fn updates_destinations_that_defaulted_to_previous_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let callback_destination = Destination {
+            allocation: Decimal::percent(50),
+            address: Addr::unchecked(USER),
+            action: DestinationAction::ExecuteContract {
+                msg: to_binary("callback").unwrap(),
+            },
+        };
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![
+                    Destination {
+                        allocation: Decimal::percent(50),
+                        address: Addr::unchecked(USER),
+                        action: DestinationAction::Send {},
+                    },
+                    callback_destination.clone(),
+                ],
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                ..Vault::default()
+            },
+        );
+
+        accept_vault_ownership_handler(deps.as_mut(), env, mock_info(NEW_OWNER, &[]), vault.id)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.destinations,
+            vec![
+                Destination {
+                    allocation: Decimal::percent(50),
+                    address: Addr::unchecked(NEW_OWNER),
+                    action: DestinationAction::Send {},
+                },
+                callback_destination,
+            ]
+        );
+    }
+
+    #[test]
+    fn updates_automated_destinations_acting_for_previous_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![
+                    Destination {
+                        allocation: Decimal::percent(50),
+                        address: Addr::unchecked(USER),
+                        action: DestinationAction::Delegate {
+                            validator_address: Addr::unchecked("validator"),
+                        },
+                    },
+                    Destination {
+                        allocation: Decimal::percent(50),
+                        address: Addr::unchecked(USER),
+                        action: DestinationAction::DepositToVault {
+                            vault_id: Uint128::new(8),
+                        },
+                    },
+                ],
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                ..Vault::default()
+            },
+        );
+
+        accept_vault_ownership_handler(deps.as_mut(), env, mock_info(NEW_OWNER, &[]), vault.id)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.destinations,
+            vec![
+                Destination {
+                    allocation: Decimal::percent(50),
+                    address: Addr::unchecked(NEW_OWNER),
+                    action: DestinationAction::Delegate {
+                        validator_address: Addr::unchecked("validator"),
+                    },
+                },
+                Destination {
+                    allocation: Decimal::percent(50),
+                    address: Addr::unchecked(NEW_OWNER),
+                    action: DestinationAction::Send {},
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn publishes_ownership_transferred_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                ..Vault::default()
+            },
+        );
+
+        accept_vault_ownership_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(NEW_OWNER, &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultOwnershipTransferred {
+                    previous_owner: vault.owner,
+                    new_owner: Addr::unchecked(NEW_OWNER),
+                },
+            )
+            .build(1)
+        ));
+    }
+}
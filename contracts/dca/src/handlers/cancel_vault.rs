@@ -1,17 +1,12 @@
 use crate::error::ContractError;
-use crate::helpers::coin::empty_of;
 use crate::helpers::validation::{
     assert_sender_is_admin_or_vault_owner, assert_vault_is_not_cancelled,
 };
-use crate::state::disburse_escrow_tasks::save_disburse_escrow_task;
-use crate::state::events::create_event;
-use crate::state::triggers::delete_trigger;
-use crate::state::vaults::{get_vault, update_vault};
-use crate::types::event::{EventBuilder, EventData};
-use crate::types::vault::{Vault, VaultStatus};
+use crate::helpers::vault::cancel_vault;
+use crate::state::vaults::get_vault;
 #[cfg(not(feature = "library"))]
-use cosmwasm_std::{BankMsg, DepsMut, Response, Uint128};
-use cosmwasm_std::{Env, MessageInfo, SubMsg};
+use cosmwasm_std::{DepsMut, Response, Uint128};
+use cosmwasm_std::{Env, MessageInfo};
 
 pub fn cancel_vault_handler(
     deps: DepsMut,
@@ -24,44 +19,14 @@ pub fn cancel_vault_handler(
     assert_sender_is_admin_or_vault_owner(deps.storage, vault.owner.clone(), info.sender)?;
     assert_vault_is_not_cancelled(&vault)?;
 
-    create_event(
-        deps.storage,
-        EventBuilder::new(vault.id, env.block.clone(), EventData::DcaVaultCancelled {}),
-    )?;
-
-    if vault.escrowed_amount.amount > Uint128::zero() {
-        save_disburse_escrow_task(
-            deps.storage,
-            vault.id,
-            vault.get_expected_execution_completed_date(env.block.time),
-        )?;
-    };
-
-    let mut submessages = Vec::<SubMsg>::new();
-
-    if vault.balance.amount > Uint128::zero() {
-        submessages.push(SubMsg::new(BankMsg::Send {
-            to_address: vault.owner.to_string(),
-            amount: vec![vault.balance.clone()],
-        }));
-    }
-
-    let updated_vault = update_vault(
-        deps.storage,
-        Vault {
-            status: VaultStatus::Cancelled,
-            balance: empty_of(vault.balance.clone()),
-            ..vault.clone()
-        },
-    )?;
-
-    delete_trigger(deps.storage, updated_vault.id)?;
+    let submessages = cancel_vault(deps.storage, &env, vault.clone())?;
 
     Ok(Response::new()
         .add_attribute("cancel_vault", "true")
         .add_attribute("vault_id", vault.id)
         .add_attribute("owner", vault.owner)
         .add_attribute("refunded_amount", vault.balance.to_string())
+        .add_attribute("claimed_amount", vault.claimable_amount.to_string())
         .add_submessages(submessages))
 }
 
@@ -73,7 +38,7 @@ mod cancel_vault_tests {
     use crate::handlers::get_vault::get_vault_handler;
     use crate::state::disburse_escrow_tasks::get_disburse_escrow_tasks;
     use crate::tests::helpers::{instantiate_contract, setup_vault};
-    use crate::tests::mocks::{ADMIN, DENOM_UOSMO};
+    use crate::tests::mocks::{ADMIN, DENOM_STAKE, DENOM_UOSMO};
     use crate::types::event::{EventBuilder, EventData};
     use crate::types::vault::{Vault, VaultStatus};
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
@@ -97,6 +62,48 @@ mod cancel_vault_tests {
         })));
     }
 
+    #[test]
+    fn should_pay_out_claimable_amount_to_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                claimable_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                ..Vault::default()
+            },
+        );
+
+        let response = cancel_vault_handler(deps.as_mut(), env.clone(), info, vault.id).unwrap();
+
+        let updated_vault = get_vault_handler(deps.as_ref(), vault.id).unwrap().vault;
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![vault.claimable_amount.clone()],
+        })));
+        assert_eq!(updated_vault.claimable_amount, Coin::new(0, DENOM_STAKE));
+        assert!(events.contains(
+            &EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultFundsClaimed {
+                    amount: vault.claimable_amount
+                }
+            )
+            .build(2)
+        ));
+    }
+
     #[test]
     fn should_publish_vault_cancelled_event() {
         let mut deps = mock_dependencies();
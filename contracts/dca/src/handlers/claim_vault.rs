@@ -0,0 +1,189 @@
+use crate::error::ContractError;
+use crate::helpers::disbursement::get_disbursement_messages;
+use crate::helpers::validation::assert_sender_is_admin_or_vault_owner;
+use crate::state::cache::VAULT_CACHE;
+use crate::state::events::create_event;
+use crate::state::vaults::{get_vault, update_vault};
+use crate::types::event::{EventBuilder, EventData};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn claim_vault_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+) -> Result<Response, ContractError> {
+    let mut vault = get_vault(deps.storage, vault_id)?;
+
+    assert_sender_is_admin_or_vault_owner(deps.storage, vault.owner.clone(), info.sender)?;
+
+    if vault.claimable_amount.amount.is_zero() {
+        return Err(ContractError::NothingToClaim { vault_id });
+    }
+
+    let amount_claimed = vault.claimable_amount.clone();
+
+    vault.claimable_amount.amount = Uint128::zero();
+    update_vault(deps.storage, vault.clone())?;
+
+    VAULT_CACHE.save(deps.storage, &vault.id)?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            vault.id,
+            env.block.clone(),
+            EventData::DcaVaultFundsClaimed {
+                amount: amount_claimed.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("claim_vault", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("owner", vault.owner.clone())
+        .add_attribute("claimed_amount", amount_claimed.to_string())
+        .add_submessages(get_disbursement_messages(
+            deps.storage,
+            &env,
+            &vault,
+            amount_claimed.amount,
+        )?))
+}
+
+#[cfg(test)]
+mod claim_vault_tests {
+    use super::*;
+    use crate::constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, ONE};
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::handlers::get_vault::get_vault_handler;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, DENOM_STAKE, USER};
+    use crate::types::accumulation::Accumulation;
+    use crate::types::destination::Destination;
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{BankMsg, Coin, SubMsg};
+
+    fn accumulating_vault() -> Vault {
+        Vault {
+            destinations: vec![Destination::default()],
+            accumulation: Some(Accumulation {
+                claim_threshold: None,
+            }),
+            claimable_amount: Coin::new(ONE.into(), DENOM_STAKE),
+            ..Vault::default()
+        }
+    }
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), accumulating_vault());
+
+        let err = claim_vault_handler(deps.as_mut(), env, mock_info("not-owner", &[]), vault.id)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_nothing_to_claim_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                claimable_amount: Coin::new(0, DENOM_STAKE),
+                ..accumulating_vault()
+            },
+        );
+
+        let err =
+            claim_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: vault {} has no claimable funds", vault.id)
+        );
+    }
+
+    #[test]
+    fn disburses_claimable_amount_to_destinations() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), accumulating_vault());
+
+        let response =
+            claim_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: vault.destinations[0].address.to_string(),
+                    amount: vec![vault.claimable_amount],
+                },
+                AFTER_FAILED_AUTOMATION_REPLY_ID
+            )]
+        );
+    }
+
+    #[test]
+    fn resets_claimable_amount() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), accumulating_vault());
+
+        claim_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault_handler(deps.as_ref(), vault.id).unwrap().vault;
+
+        assert_eq!(
+            updated_vault.claimable_amount,
+            Coin::new(0, vault.target_denom)
+        );
+    }
+
+    #[test]
+    fn publishes_funds_claimed_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), accumulating_vault());
+
+        claim_vault_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), vault.id).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultFundsClaimed {
+                    amount: vault.claimable_amount,
+                },
+            )
+            .build(1)
+        ));
+    }
+}
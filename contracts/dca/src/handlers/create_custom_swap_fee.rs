@@ -1,6 +1,7 @@
+use crate::types::role::Role;
 use crate::{
     error::ContractError,
-    helpers::validation::{assert_denom_exists, assert_fee_level_is_valid, assert_sender_is_admin},
+    helpers::validation::{assert_denom_exists, assert_fee_level_is_valid, assert_sender_has_role},
     state::config::create_custom_fee,
 };
 #[cfg(not(feature = "library"))]
@@ -13,7 +14,7 @@ pub fn create_custom_swap_fee_handler(
     denom: String,
     swap_fee_percent: Decimal,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_sender_has_role(deps.storage, &info.sender, Role::FeeManager)?;
     assert_denom_exists(deps.as_ref().storage, denom.clone())?;
     assert_fee_level_is_valid(&swap_fee_percent)?;
 
@@ -30,7 +31,10 @@ mod create_custom_swap_fee_tests {
     use super::*;
     use crate::{
         handlers::get_custom_swap_fees::get_custom_swap_fees_handler,
-        state::pairs::save_pair,
+        state::{
+            config::{get_config, update_config},
+            pairs::save_pair,
+        },
         tests::{
             helpers::instantiate_contract,
             mocks::{ADMIN, DENOM_STAKE, DENOM_UATOM, DENOM_UOSMO},
@@ -39,7 +43,7 @@ mod create_custom_swap_fee_tests {
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Decimal,
+        Addr, Decimal,
     };
 
     #[test]
@@ -57,6 +61,10 @@ mod create_custom_swap_fee_tests {
                 base_denom: denom.clone(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
             },
         )
         .unwrap();
@@ -70,6 +78,64 @@ mod create_custom_swap_fee_tests {
         assert_eq!(custom_fees[0], (denom, Decimal::percent(1)));
     }
 
+    #[test]
+    fn create_custom_swap_fee_with_fee_manager_sender_should_succeed() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let mut config = get_config(deps.as_ref().storage).unwrap();
+        config.fee_managers = vec![Addr::unchecked("fee-manager")];
+        update_config(deps.as_mut().storage, config).unwrap();
+
+        let denom = DENOM_UOSMO.to_string();
+
+        save_pair(
+            deps.as_mut().storage,
+            &Pair {
+                base_denom: denom.clone(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![1],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
+            },
+        )
+        .unwrap();
+
+        create_custom_swap_fee_handler(
+            deps.as_mut(),
+            mock_info("fee-manager", &[]),
+            denom.clone(),
+            Decimal::percent(1),
+        )
+        .unwrap();
+
+        let custom_fees = get_custom_swap_fees_handler(deps.as_ref()).unwrap();
+
+        assert_eq!(custom_fees, vec![(denom, Decimal::percent(1))]);
+    }
+
+    #[test]
+    fn create_custom_swap_fee_with_pair_manager_sender_fails() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let mut config = get_config(deps.as_ref().storage).unwrap();
+        config.pair_managers = vec![Addr::unchecked("pair-manager")];
+        update_config(deps.as_mut().storage, config).unwrap();
+
+        let err = create_custom_swap_fee_handler(
+            deps.as_mut(),
+            mock_info("pair-manager", &[]),
+            DENOM_UOSMO.to_string(),
+            Decimal::percent(1),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
     #[test]
     fn create_custom_swap_fee_should_overwrite_existing_fee() {
         let mut deps = mock_dependencies();
@@ -85,6 +151,10 @@ mod create_custom_swap_fee_tests {
                 base_denom: denom.clone(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
             },
         )
         .unwrap();
@@ -126,6 +196,10 @@ mod create_custom_swap_fee_tests {
                 base_denom: denom.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
             },
         )
         .unwrap();
@@ -155,6 +229,10 @@ mod create_custom_swap_fee_tests {
                 base_denom: DENOM_UATOM.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
             },
         )
         .unwrap();
@@ -1,10 +1,15 @@
 use crate::helpers::validation::{
-    assert_route_has_no_duplicate_entries, assert_route_matches_denoms, assert_route_not_empty,
-    assert_sender_is_admin,
+    assert_pair_settings_are_valid, assert_route_has_no_duplicate_entries,
+    assert_route_matches_denoms, assert_route_not_empty, assert_sender_has_role,
+    assert_weighted_routes_are_valid,
 };
 use crate::state::pairs::save_pair;
-use crate::{error::ContractError, types::pair::Pair};
-use cosmwasm_std::DepsMut;
+use crate::types::role::Role;
+use crate::{
+    error::ContractError,
+    types::pair::{Pair, WeightedRoute},
+};
+use cosmwasm_std::{Coin, Decimal, DepsMut};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{MessageInfo, Response};
 
@@ -14,18 +19,30 @@ pub fn create_pair_handler(
     base_denom: String,
     quote_denom: String,
     route: Vec<u64>,
+    weighted_routes: Option<Vec<WeightedRoute>>,
+    minimum_swap_amounts: Option<Vec<Coin>>,
+    twap_period: Option<u64>,
+    default_slippage_tolerance: Option<Decimal>,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_admin(deps.storage, info.sender)?;
-    assert_route_not_empty(route.clone())?;
-    assert_route_has_no_duplicate_entries(route.clone())?;
+    assert_sender_has_role(deps.storage, &info.sender, Role::PairManager)?;
 
-    let pair = Pair {
+    let mut pair = Pair {
         base_denom: base_denom.clone(),
         quote_denom: quote_denom.clone(),
-        route: route.clone(),
+        route,
+        weighted_routes,
+        minimum_swap_amounts,
+        twap_period,
+        default_slippage_tolerance,
     };
 
+    pair.route = pair.primary_route();
+
+    assert_route_not_empty(pair.route.clone())?;
+    assert_route_has_no_duplicate_entries(pair.route.clone())?;
     assert_route_matches_denoms(&deps.querier, &pair)?;
+    assert_weighted_routes_are_valid(&deps.querier, &pair)?;
+    assert_pair_settings_are_valid(&pair)?;
 
     save_pair(deps.storage, &pair)?;
 
@@ -33,7 +50,7 @@ pub fn create_pair_handler(
         .add_attribute("create_pair", "true")
         .add_attribute("base_denom", base_denom)
         .add_attribute("quote_denom", quote_denom)
-        .add_attribute("route", format!("{:#?}", route)))
+        .add_attribute("route", format!("{:#?}", pair.route)))
 }
 
 #[cfg(test)]
@@ -42,13 +59,20 @@ mod create_pair_tests {
         contract::execute,
         handlers::get_pairs::get_pairs_handler,
         msg::ExecuteMsg,
-        state::pairs::find_pair,
+        state::{
+            config::{get_config, update_config},
+            pairs::find_pair,
+        },
         tests::{
             helpers::instantiate_contract,
             mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO},
         },
+        types::pair::WeightedRoute,
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Addr, Coin, Decimal, Uint128,
     };
-    use cosmwasm_std::testing::{mock_env, mock_info};
 
     #[test]
     fn with_duplicate_route_entries_fails() {
@@ -62,6 +86,10 @@ mod create_pair_tests {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![4, 1, 4, 1],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         let err = execute(deps.as_mut(), env, info, create_pair_execute_message).unwrap_err();
@@ -84,6 +112,10 @@ mod create_pair_tests {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![3],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         execute(deps.as_mut(), env, info, create_pair_execute_message).unwrap();
@@ -107,12 +139,20 @@ mod create_pair_tests {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![4, 1],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         let message = ExecuteMsg::CreatePair {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![3],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         execute(deps.as_mut(), env.clone(), info.clone(), original_message).unwrap();
@@ -143,6 +183,10 @@ mod create_pair_tests {
             base_denom: String::from("base"),
             quote_denom: String::from("quote"),
             route: vec![0],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         let result = execute(
@@ -156,6 +200,76 @@ mod create_pair_tests {
         assert_eq!(result.to_string(), "Unauthorized")
     }
 
+    #[test]
+    fn create_pair_with_pair_manager_sender_should_succeed() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let mut config = get_config(deps.as_ref().storage).unwrap();
+        config.pair_managers = vec![Addr::unchecked("pair-manager")];
+        update_config(deps.as_mut().storage, config).unwrap();
+
+        let create_pair_execute_message = ExecuteMsg::CreatePair {
+            base_denom: DENOM_UOSMO.to_string(),
+            quote_denom: DENOM_STAKE.to_string(),
+            route: vec![3],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
+        };
+
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("pair-manager", &[]),
+            create_pair_execute_message,
+        )
+        .unwrap();
+
+        let pair = find_pair(
+            deps.as_ref().storage,
+            [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(pair.route, vec![3]);
+    }
+
+    #[test]
+    fn create_pair_with_fee_manager_sender_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let mut config = get_config(deps.as_ref().storage).unwrap();
+        config.fee_managers = vec![Addr::unchecked("fee-manager")];
+        update_config(deps.as_mut().storage, config).unwrap();
+
+        let create_pair_execute_message = ExecuteMsg::CreatePair {
+            base_denom: DENOM_UOSMO.to_string(),
+            quote_denom: DENOM_STAKE.to_string(),
+            route: vec![3],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
+        };
+
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("fee-manager", &[]),
+            create_pair_execute_message,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized")
+    }
+
     #[test]
     fn create_pair_with_empty_route_should_fail() {
         let mut deps = calc_mock_dependencies();
@@ -172,6 +286,10 @@ mod create_pair_tests {
                 base_denom: DENOM_UOSMO.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
             },
         )
         .unwrap_err();
@@ -195,6 +313,10 @@ mod create_pair_tests {
                 base_denom: DENOM_UOSMO.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![2],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
             },
         )
         .unwrap_err();
@@ -217,12 +339,20 @@ mod create_pair_tests {
             quote_denom: DENOM_UOSMO.to_string(),
             base_denom: DENOM_STAKE.to_string(),
             route: vec![1, 4],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         let message = ExecuteMsg::CreatePair {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![3],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         execute(deps.as_mut(), env.clone(), info.clone(), original_message).unwrap();
@@ -238,4 +368,385 @@ mod create_pair_tests {
         assert_eq!(original_pair.route, vec![1, 4]);
         assert_eq!(pair.route, vec![3]);
     }
+
+    #[test]
+    fn create_pair_with_stableswap_and_concentrated_liquidity_routes_should_succeed() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![5],
+                weighted_routes: Some(vec![
+                    WeightedRoute {
+                        route: vec![5],
+                        weight: Decimal::percent(50),
+                    },
+                    WeightedRoute {
+                        route: vec![7],
+                        weight: Decimal::percent(50),
+                    },
+                ]),
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
+            },
+        )
+        .unwrap();
+
+        let pair = find_pair(
+            deps.as_ref().storage,
+            [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(pair.route, vec![5]);
+    }
+
+    #[test]
+    fn create_pair_with_weighted_route_weights_not_adding_up_to_1_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let result = execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                weighted_routes: Some(vec![
+                    WeightedRoute {
+                        route: vec![3],
+                        weight: Decimal::percent(60),
+                    },
+                    WeightedRoute {
+                        route: vec![4, 1],
+                        weight: Decimal::percent(30),
+                    },
+                ]),
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            result.to_string(),
+            "Error: Weighted route weights must add up to 1"
+        )
+    }
+
+    #[test]
+    fn create_pair_with_duplicate_weighted_routes_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let result = execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                weighted_routes: Some(vec![
+                    WeightedRoute {
+                        route: vec![3],
+                        weight: Decimal::percent(50),
+                    },
+                    WeightedRoute {
+                        route: vec![3],
+                        weight: Decimal::percent(50),
+                    },
+                ]),
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            result.to_string(),
+            "Error: Weighted routes must not contain duplicate routes"
+        )
+    }
+
+    #[test]
+    fn create_pair_with_invalid_weighted_route_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let result = execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                weighted_routes: Some(vec![
+                    WeightedRoute {
+                        route: vec![3],
+                        weight: Decimal::percent(50),
+                    },
+                    WeightedRoute {
+                        route: vec![2],
+                        weight: Decimal::percent(50),
+                    },
+                ]),
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            result.to_string(),
+            "Generic error: denom uosmo not found in pool id 2"
+        )
+    }
+
+    #[test]
+    fn create_pair_with_weighted_routes_should_succeed() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let weighted_routes = vec![
+            WeightedRoute {
+                route: vec![3],
+                weight: Decimal::percent(70),
+            },
+            WeightedRoute {
+                route: vec![4, 1],
+                weight: Decimal::percent(30),
+            },
+        ];
+
+        execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                weighted_routes: Some(weighted_routes.clone()),
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
+            },
+        )
+        .unwrap();
+
+        let pair = find_pair(
+            deps.as_ref().storage,
+            [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(pair.weighted_routes, Some(weighted_routes.clone()));
+        assert_eq!(pair.routes(), weighted_routes);
+    }
+
+    #[test]
+    fn create_pair_with_weighted_routes_should_derive_route_from_heaviest_weighted_route() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![4, 1],
+                weighted_routes: Some(vec![
+                    WeightedRoute {
+                        route: vec![4, 1],
+                        weight: Decimal::percent(30),
+                    },
+                    WeightedRoute {
+                        route: vec![3],
+                        weight: Decimal::percent(70),
+                    },
+                ]),
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
+            },
+        )
+        .unwrap();
+
+        let pair = find_pair(
+            deps.as_ref().storage,
+            [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(pair.route, vec![3]);
+    }
+
+    #[test]
+    fn create_pair_with_invalid_twap_period_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: Some(10),
+                default_slippage_tolerance: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: twap_period must be between 30 and 3600"
+        );
+    }
+
+    #[test]
+    fn create_pair_with_default_slippage_tolerance_larger_than_one_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: Some(Decimal::percent(101)),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: slippage tolerance must be less than or equal to 1"
+        );
+    }
+
+    #[test]
+    fn create_pair_with_pair_settings_should_save_them() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                weighted_routes: None,
+                minimum_swap_amounts: Some(vec![
+                    Coin::new(10000, DENOM_UOSMO),
+                    Coin::new(20000, DENOM_STAKE),
+                ]),
+                twap_period: Some(600),
+                default_slippage_tolerance: Some(Decimal::percent(5)),
+            },
+        )
+        .unwrap();
+
+        let pair = find_pair(
+            deps.as_ref().storage,
+            [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(
+            pair.minimum_swap_amount(DENOM_UOSMO),
+            Some(Uint128::new(10000))
+        );
+        assert_eq!(
+            pair.minimum_swap_amount(DENOM_STAKE),
+            Some(Uint128::new(20000))
+        );
+        assert_eq!(pair.twap_period, Some(600));
+        assert_eq!(pair.default_slippage_tolerance, Some(Decimal::percent(5)));
+    }
+
+    #[test]
+    fn with_minimum_swap_amount_for_other_denom_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                weighted_routes: None,
+                minimum_swap_amounts: Some(vec![Coin::new(10000, "uother")]),
+                twap_period: None,
+                default_slippage_tolerance: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: minimum swap amount denom uother must be a pair denom and appear only once"
+        );
+    }
 }
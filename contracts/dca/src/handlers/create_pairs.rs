@@ -1,8 +1,10 @@
+use crate::types::role::Role;
 use crate::{
     error::ContractError,
     helpers::validation::{
-        assert_route_has_no_duplicate_entries, assert_route_matches_denoms, assert_route_not_empty,
-        assert_sender_is_admin,
+        assert_pair_settings_are_valid, assert_route_has_no_duplicate_entries,
+        assert_route_matches_denoms, assert_route_not_empty, assert_sender_has_role,
+        assert_weighted_routes_are_valid,
     },
     state::pairs::save_pair,
     types::pair::Pair,
@@ -14,12 +16,16 @@ pub fn create_pairs_handler(
     info: MessageInfo,
     pairs: Vec<Pair>,
 ) -> Result<Response, ContractError> {
-    for pair in pairs.clone() {
-        assert_sender_is_admin(deps.storage, info.sender.clone())?;
+    for mut pair in pairs.clone() {
+        pair.route = pair.primary_route();
+
+        assert_sender_has_role(deps.storage, &info.sender, Role::PairManager)?;
         assert_route_not_empty(pair.route.clone())?;
         assert_route_has_no_duplicate_entries(pair.route.clone())?;
 
         assert_route_matches_denoms(&deps.querier, &pair)?;
+        assert_weighted_routes_are_valid(&deps.querier, &pair)?;
+        assert_pair_settings_are_valid(&pair)?;
 
         save_pair(deps.storage, &pair)?;
     }
@@ -1,14 +1,20 @@
+use crate::constants::MINIMUM_SWAP_AMOUNT;
 use crate::error::ContractError;
+use crate::helpers::price::query_belief_price;
+use crate::helpers::time::get_next_target_time;
 use crate::helpers::validation::{
-    assert_address_is_valid, assert_contract_destination_callbacks_are_valid,
-    assert_contract_is_not_paused, assert_destination_allocations_add_up_to_one,
+    assert_accumulation_is_valid, assert_address_is_valid, assert_contract_is_not_paused,
+    assert_destination_actions_are_valid, assert_destination_allocations_add_up_to_one,
     assert_destination_callback_addresses_are_valid, assert_destinations_limit_is_not_breached,
-    assert_exactly_one_asset, assert_label_is_no_longer_than_100_characters,
-    assert_no_destination_allocations_are_zero, assert_pair_exists_for_denoms,
+    assert_end_time_is_after_start_time, assert_exactly_one_asset,
+    assert_execution_window_is_valid, assert_label_is_no_longer_than_100_characters,
+    assert_max_executions_is_greater_than_zero, assert_no_destination_allocations_are_zero,
+    assert_no_target_start_time_with_target_price, assert_pair_exists_for_denoms,
     assert_slippage_tolerance_is_less_than_or_equal_to_one,
     assert_swap_adjusment_and_performance_assessment_strategies_are_compatible,
-    assert_swap_adjustment_strategy_params_are_valid, assert_swap_amount_is_greater_than_50000,
-    assert_target_start_time_is_not_in_the_past, assert_time_interval_is_valid,
+    assert_swap_adjustment_strategy_params_are_valid, assert_swap_amount_is_greater_than_minimum,
+    assert_target_price_is_greater_than_zero, assert_target_start_time_is_not_in_the_past,
+    assert_termination_conditions_are_valid, assert_time_interval_is_valid,
 };
 use crate::helpers::vault::get_risk_weighted_average_model_id;
 use crate::msg::ExecuteMsg;
@@ -18,7 +24,7 @@ use crate::state::events::create_event;
 use crate::state::pairs::find_pair;
 use crate::state::triggers::save_trigger;
 use crate::state::vaults::save_vault;
-use crate::types::destination::Destination;
+use crate::types::destination::{Destination, DestinationAction};
 use crate::types::event::{EventBuilder, EventData};
 use crate::types::performance_assessment_strategy::{
     PerformanceAssessmentStrategy, PerformanceAssessmentStrategyParams,
@@ -28,8 +34,9 @@ use crate::types::swap_adjustment_strategy::{
     SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
 use crate::types::time_interval::TimeInterval;
-use crate::types::trigger::{Trigger, TriggerConfiguration};
+use crate::types::trigger::{PriceDirection, Trigger, TriggerConfiguration};
 use crate::types::vault::{VaultBuilder, VaultStatus};
+use crate::types::vault_settings::VaultSettings;
 use cosmwasm_std::{to_binary, Addr, Coin, Decimal, SubMsg, WasmMsg};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Timestamp, Uint128, Uint64};
@@ -50,11 +57,21 @@ pub fn create_vault_handler(
     target_start_time_utc_seconds: Option<Uint64>,
     performance_assessment_strategy_params: Option<PerformanceAssessmentStrategyParams>,
     swap_adjustment_strategy_params: Option<SwapAdjustmentStrategyParams>,
+    settings: VaultSettings,
 ) -> Result<Response, ContractError> {
+    let VaultSettings {
+        target_price,
+        stop_loss,
+        take_profit,
+        execution_window,
+        end_time_utc_seconds,
+        max_executions,
+        accumulation,
+    } = settings;
+
     assert_contract_is_not_paused(deps.storage)?;
     assert_address_is_valid(deps.as_ref(), &owner, "owner")?;
     assert_exactly_one_asset(info.funds.clone())?;
-    assert_swap_amount_is_greater_than_50000(swap_amount)?;
     assert_destinations_limit_is_not_breached(&destinations)?;
     assert_time_interval_is_valid(&time_interval)?;
     assert_pair_exists_for_denoms(
@@ -66,6 +83,7 @@ pub fn create_vault_handler(
         &swap_adjustment_strategy_params,
         &performance_assessment_strategy_params,
     )?;
+    assert_termination_conditions_are_valid(&stop_loss, &take_profit)?;
 
     if let Some(label) = label.clone() {
         assert_label_is_no_longer_than_100_characters(&label)?;
@@ -79,6 +97,10 @@ pub fn create_vault_handler(
         assert_slippage_tolerance_is_less_than_or_equal_to_one(slippage_tolerance)?;
     }
 
+    if let Some(execution_window) = &execution_window {
+        assert_execution_window_is_valid(execution_window)?;
+    }
+
     if let Some(target_time) = target_start_time_utc_seconds {
         assert_target_start_time_is_not_in_the_past(
             env.block.time,
@@ -86,16 +108,42 @@ pub fn create_vault_handler(
         )?;
     }
 
+    if let Some(end_time) = end_time_utc_seconds {
+        assert_end_time_is_after_start_time(
+            target_start_time_utc_seconds
+                .map_or(env.block.time, |time| Timestamp::from_seconds(time.u64())),
+            Timestamp::from_seconds(end_time.u64()),
+        )?;
+    }
+
+    if let Some(max_executions) = max_executions {
+        assert_max_executions_is_greater_than_zero(max_executions)?;
+    }
+
+    if let Some(accumulation) = &accumulation {
+        assert_accumulation_is_valid(accumulation)?;
+    }
+
+    if let Some(target_price) = target_price {
+        assert_no_target_start_time_with_target_price(target_start_time_utc_seconds)?;
+        assert_target_price_is_greater_than_zero(target_price)?;
+    }
+
     if destinations.is_empty() {
         destinations.push(Destination {
             allocation: Decimal::percent(100),
             address: owner.clone(),
-            msg: None,
+            action: DestinationAction::Send {},
         });
     }
 
     assert_destination_callback_addresses_are_valid(deps.as_ref(), &destinations)?;
-    assert_contract_destination_callbacks_are_valid(&destinations, &env.contract.address)?;
+    assert_destination_actions_are_valid(
+        deps.storage,
+        &destinations,
+        &env.contract.address,
+        &target_denom,
+    )?;
     assert_no_destination_allocations_are_zero(&destinations)?;
     assert_destination_allocations_add_up_to_one(&destinations)?;
 
@@ -105,6 +153,12 @@ pub fn create_vault_handler(
 
     let pair = find_pair(deps.storage, [swap_denom.clone(), target_denom.clone()])?;
 
+    assert_swap_amount_is_greater_than_minimum(
+        swap_amount,
+        pair.minimum_swap_amount(&swap_denom)
+            .unwrap_or(MINIMUM_SWAP_AMOUNT),
+    )?;
+
     let swap_adjustment_strategy = swap_adjustment_strategy_params.map(|params| match params {
         SwapAdjustmentStrategyParams::RiskWeightedAverage { base_denom } => {
             SwapAdjustmentStrategy::RiskWeightedAverage {
@@ -154,7 +208,10 @@ pub fn create_vault_handler(
         target_denom: target_denom.clone(),
         swap_amount,
         position_type,
-        slippage_tolerance: slippage_tolerance.unwrap_or(config.default_slippage_tolerance),
+        slippage_tolerance: slippage_tolerance.unwrap_or(
+            pair.default_slippage_tolerance
+                .unwrap_or(config.default_slippage_tolerance),
+        ),
         minimum_receive_amount,
         balance: info.funds[0].clone(),
         time_interval,
@@ -166,6 +223,12 @@ pub fn create_vault_handler(
         escrowed_amount: Coin::new(0, target_denom),
         swap_adjustment_strategy,
         performance_assessment_strategy,
+        stop_loss,
+        take_profit,
+        execution_window,
+        end_time: end_time_utc_seconds.map(|time| Timestamp::from_seconds(time.u64())),
+        max_executions,
+        accumulation,
     };
 
     let vault = save_vault(deps.storage, vault_builder)?;
@@ -188,20 +251,47 @@ pub fn create_vault_handler(
         .add_attribute("vault_id", vault.id)
         .add_attribute("deposited_amount", vault.balance.to_string());
 
+    let is_calendar_interval = matches!(
+        vault.time_interval,
+        TimeInterval::DaysOfWeek { .. } | TimeInterval::DaysOfMonth { .. }
+    );
+
+    let trigger_configuration = match target_price {
+        Some(target_price) => {
+            let belief_price =
+                query_belief_price(&deps.as_ref(), &env, &pair, vault.get_swap_denom())?;
+
+            TriggerConfiguration::Price {
+                target_price,
+                direction: if target_price > belief_price {
+                    PriceDirection::Above
+                } else {
+                    PriceDirection::Below
+                },
+            }
+        }
+        None => TriggerConfiguration::Time {
+            target_time: match target_start_time_utc_seconds {
+                Some(time) => Timestamp::from_seconds(time.u64()),
+                None if is_calendar_interval => get_next_target_time(
+                    env.block.time,
+                    env.block.time,
+                    vault.time_interval.clone(),
+                ),
+                None => env.block.time,
+            },
+        },
+    };
+
     save_trigger(
         deps.storage,
         Trigger {
             vault_id: vault.id,
-            configuration: TriggerConfiguration::Time {
-                target_time: match target_start_time_utc_seconds {
-                    Some(time) => Timestamp::from_seconds(time.u64()),
-                    None => env.block.time,
-                },
-            },
+            configuration: trigger_configuration,
         },
     )?;
 
-    if target_start_time_utc_seconds.is_none() {
+    if target_start_time_utc_seconds.is_none() && target_price.is_none() && !is_calendar_interval {
         response = response.add_submessage(SubMsg::new(WasmMsg::Execute {
             contract_addr: env.contract.address.to_string(),
             msg: to_binary(&ExecuteMsg::ExecuteTrigger {
@@ -227,16 +317,22 @@ mod create_vault_tests {
     use crate::tests::mocks::{
         calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO, USER, VALIDATOR,
     };
+    use crate::types::accumulation::Accumulation;
     use crate::types::config::Config;
-    use crate::types::destination::Destination;
+    use crate::types::destination::{Destination, DestinationAction};
     use crate::types::event::{EventBuilder, EventData};
+    use crate::types::execution_window::ExecutionWindow;
     use crate::types::pair::Pair;
     use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
+    use crate::types::termination_condition::{TerminationAction, TerminationCondition};
+    use crate::types::time_interval::DayOfWeek;
     use crate::types::time_interval::TimeInterval;
     use crate::types::trigger::TriggerConfiguration;
     use crate::types::vault::{Vault, VaultStatus};
     use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{to_binary, Addr, Coin, Decimal, SubMsg, Timestamp, Uint128, WasmMsg};
+    use cosmwasm_std::{
+        to_binary, Addr, Coin, Decimal, SubMsg, Timestamp, Uint128, Uint64, WasmMsg,
+    };
 
     #[test]
     fn with_no_assets_fails() {
@@ -262,6 +358,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
@@ -298,6 +395,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
@@ -331,6 +429,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
@@ -356,6 +455,10 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -370,7 +473,7 @@ mod create_vault_tests {
             vec![Destination {
                 allocation: Decimal::percent(50),
                 address: Addr::unchecked(USER),
-                msg: None,
+                action: DestinationAction::Send {},
             }],
             DENOM_UOSMO.to_string(),
             None,
@@ -381,6 +484,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
@@ -406,6 +510,10 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -421,12 +529,12 @@ mod create_vault_tests {
                 Destination {
                     allocation: Decimal::percent(100),
                     address: Addr::unchecked(USER),
-                    msg: None,
+                    action: DestinationAction::Send {},
                 },
                 Destination {
                     allocation: Decimal::percent(0),
                     address: Addr::unchecked("other"),
-                    msg: None,
+                    action: DestinationAction::Send {},
                 },
             ],
             DENOM_UOSMO.to_string(),
@@ -438,6 +546,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
@@ -466,7 +575,7 @@ mod create_vault_tests {
                 .map(|i| Destination {
                     allocation: Decimal::percent(5),
                     address: Addr::unchecked(format!("destination-{}", i)),
-                    msg: None,
+                    action: DestinationAction::Send {},
                 })
                 .collect(),
             DENOM_UOSMO.to_string(),
@@ -478,6 +587,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
@@ -491,9 +601,26 @@ mod create_vault_tests {
     fn with_swap_amount_less_than_50000_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
 
         let err = create_vault_handler(
             deps.as_mut(),
@@ -511,6 +638,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
@@ -521,7 +649,7 @@ mod create_vault_tests {
     }
 
     #[test]
-    fn with_too_high_weighted_scale_multiplier_fails() {
+    fn with_swap_amount_less_than_pair_minimum_swap_amount_for_swap_denom_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let admin_info = mock_info(ADMIN, &[]);
@@ -536,16 +664,23 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            Some(vec![
+                Coin::new(200000, DENOM_STAKE),
+                Coin::new(1000, DENOM_UOSMO),
+            ]),
+            None,
+            None,
         )
         .unwrap();
 
-        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
+        let info = mock_info(USER, &[Coin::new(1000000, DENOM_STAKE)]);
 
         let err = create_vault_handler(
             deps.as_mut(),
             env,
-            &user_info,
-            user_info.sender.clone(),
+            &info,
+            info.sender.clone(),
             None,
             vec![],
             DENOM_UOSMO.to_string(),
@@ -556,63 +691,75 @@ mod create_vault_tests {
             TimeInterval::Daily,
             None,
             None,
-            Some(SwapAdjustmentStrategyParams::WeightedScale {
-                base_receive_amount: Uint128::new(100000),
-                multiplier: Decimal::percent(1100),
-                increase_only: false,
-            }),
+            None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: Cannot set weighted scale multiplier to more than 10"
+            "Error: swap amount must be greater than 200000"
         );
     }
 
     #[test]
-    fn when_contract_is_paused_fails() {
+    fn with_swap_amount_less_than_50000_and_lower_pair_minimum_swap_amount_for_swap_denom_succeeds()
+    {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
 
-        let config = get_config(deps.as_ref().storage).unwrap();
+        let pair = Pair::default();
 
-        update_config(
-            deps.as_mut().storage,
-            Config {
-                paused: true,
-                ..config
-            },
+        create_pair_handler(
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            Some(vec![
+                Coin::new(200000, DENOM_STAKE),
+                Coin::new(1000, DENOM_UOSMO),
+            ]),
+            None,
+            None,
         )
         .unwrap();
 
-        let err = create_vault_handler(
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UOSMO)]);
+
+        create_vault_handler(
             deps.as_mut(),
             env,
             &info,
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UOSMO.to_string(),
+            DENOM_STAKE.to_string(),
             None,
             None,
             None,
-            Uint128::new(100000),
+            Uint128::new(10000),
             TimeInterval::Daily,
             None,
             None,
             None,
+            VaultSettings::default(),
         )
-        .unwrap_err();
+        .unwrap();
 
-        assert_eq!(err.to_string(), "Error: contract is paused")
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.swap_amount, Uint128::new(10000));
     }
 
     #[test]
-    fn with_time_trigger_with_target_time_in_the_past_fails() {
+    fn with_no_slippage_tolerance_uses_pair_default_slippage_tolerance() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let admin_info = mock_info(ADMIN, &[]);
@@ -627,16 +774,20 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(7)),
         )
         .unwrap();
 
-        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
+        let info = mock_info(USER, &[Coin::new(1000000, DENOM_STAKE)]);
 
-        let err = create_vault_handler(
+        create_vault_handler(
             deps.as_mut(),
-            env.clone(),
-            &user_info,
-            user_info.sender.clone(),
+            env,
+            &info,
+            info.sender.clone(),
             None,
             vec![],
             DENOM_UOSMO.to_string(),
@@ -645,31 +796,50 @@ mod create_vault_tests {
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.minus_seconds(10).seconds().into()),
             None,
             None,
+            None,
+            VaultSettings::default(),
         )
-        .unwrap_err();
+        .unwrap();
 
-        assert_eq!(
-            err.to_string(),
-            "Error: target_start_time_utc_seconds must be some time in the future"
-        );
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.slippage_tolerance, Decimal::percent(7));
     }
 
     #[test]
-    fn with_invalid_custom_time_interval_fails() {
+    fn with_too_high_weighted_scale_multiplier_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
 
         let err = create_vault_handler(
             deps.as_mut(),
             env,
-            &info,
-            info.sender.clone(),
+            &user_info,
+            user_info.sender.clone(),
             None,
             vec![],
             DENOM_UOSMO.to_string(),
@@ -677,44 +847,46 @@ mod create_vault_tests {
             None,
             None,
             Uint128::new(100000),
-            TimeInterval::Custom { seconds: 23 },
-            None,
+            TimeInterval::Daily,
             None,
             None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(100000),
+                multiplier: Decimal::percent(1100),
+                increase_only: false,
+            }),
+            VaultSettings::default(),
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: custom time interval must be at least 60 seconds"
+            "Error: Cannot set weighted scale multiplier to more than 10"
         );
     }
 
     #[test]
-    fn with_no_swap_adjustment_stratgey_and_performance_assessment_strategy_fails() {
+    fn when_contract_is_paused_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
-        let pair = Pair::default();
+        let config = get_config(deps.as_ref().storage).unwrap();
 
-        create_pair_handler(
-            deps.as_mut(),
-            info.clone(),
-            pair.base_denom.clone(),
-            pair.quote_denom.clone(),
-            pair.route,
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                paused: true,
+                ..config
+            },
         )
         .unwrap();
 
-        let swap_amount = Uint128::new(100000);
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
-
         let err = create_vault_handler(
             deps.as_mut(),
-            env.clone(),
+            env,
             &info,
             info.sender.clone(),
             None,
@@ -723,89 +895,77 @@ mod create_vault_tests {
             None,
             None,
             None,
-            swap_amount,
+            Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             None,
+            None,
+            None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
-        assert_eq!(
-            err.to_string(),
-            "Error: incompatible swap adjustment and performance assessment strategies"
-        );
+        assert_eq!(err.to_string(), "Error: contract is paused")
     }
 
     #[test]
-    fn with_swap_adjustment_stratgey_and_no_performance_assessment_strategy_fails() {
+    fn with_time_trigger_with_target_time_in_the_past_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
 
         let pair = Pair::default();
 
         create_pair_handler(
             deps.as_mut(),
-            info.clone(),
+            admin_info,
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        let swap_amount = Uint128::new(100000);
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
 
         let err = create_vault_handler(
             deps.as_mut(),
             env.clone(),
-            &info,
-            info.sender.clone(),
+            &user_info,
+            user_info.sender.clone(),
             None,
             vec![],
             DENOM_UOSMO.to_string(),
             None,
             None,
             None,
-            swap_amount,
+            Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(env.block.time.minus_seconds(10).seconds().into()),
             None,
-            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: incompatible swap adjustment and performance assessment strategies"
+            "Error: target_start_time_utc_seconds must be some time in the future"
         );
     }
 
     #[test]
-    fn with_slippage_tolerance_larger_than_one_fails() {
+    fn with_invalid_custom_time_interval_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
-        let pair = Pair::default();
-
-        create_pair_handler(
-            deps.as_mut(),
-            info.clone(),
-            pair.base_denom.clone(),
-            pair.quote_denom.clone(),
-            pair.route,
-        )
-        .unwrap();
-
-        let swap_amount = Uint128::new(100000);
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
-
         let err = create_vault_handler(
             deps.as_mut(),
             env,
@@ -815,13 +975,400 @@ mod create_vault_tests {
             vec![],
             DENOM_UOSMO.to_string(),
             None,
-            Some(Decimal::percent(150)),
             None,
-            swap_amount,
-            TimeInterval::Daily,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Custom { seconds: 23 },
             None,
             None,
             None,
+            VaultSettings::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: custom time interval must be at least 60 seconds"
+        );
+    }
+
+    #[test]
+    fn with_calendar_time_interval_without_days_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::DaysOfWeek {
+                days: vec![],
+                hour: 14,
+                minute: 0,
+            },
+            None,
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: calendar time interval must include at least one day"
+        );
+    }
+
+    #[test]
+    fn with_calendar_time_interval_with_invalid_day_of_month_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::DaysOfMonth {
+                days: vec![1, 32],
+                hour: 14,
+                minute: 0,
+            },
+            None,
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: days of the month must be between 1 and 31"
+        );
+    }
+
+    #[test]
+    fn with_calendar_time_interval_with_invalid_time_of_day_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::DaysOfMonth {
+                days: vec![1, 15],
+                hour: 24,
+                minute: 0,
+            },
+            None,
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: 24:00 is not a valid UTC time of day"
+        );
+    }
+
+    #[test]
+    fn with_execution_window_with_equal_start_and_end_hours_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let info = mock_info(USER, &[Coin::new(1000000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            VaultSettings {
+                execution_window: Some(ExecutionWindow {
+                    start_hour: 8,
+                    end_hour: 8,
+                    excluded_days: vec![],
+                }),
+                ..VaultSettings::default()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: execution window start and end hours must be different"
+        );
+    }
+
+    #[test]
+    fn with_execution_window_excluding_every_day_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let info = mock_info(USER, &[Coin::new(1000000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            VaultSettings {
+                execution_window: Some(ExecutionWindow {
+                    start_hour: 8,
+                    end_hour: 20,
+                    excluded_days: DayOfWeek::ALL.to_vec(),
+                }),
+                ..VaultSettings::default()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: execution window must not exclude every day of the week"
+        );
+    }
+
+    #[test]
+    fn with_no_swap_adjustment_stratgey_and_performance_assessment_strategy_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let swap_amount = Uint128::new(100000);
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            swap_amount,
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: incompatible swap adjustment and performance assessment strategies"
+        );
+    }
+
+    #[test]
+    fn with_swap_adjustment_stratgey_and_no_performance_assessment_strategy_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let swap_amount = Uint128::new(100000);
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            swap_amount,
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            Some(SwapAdjustmentStrategyParams::default()),
+            VaultSettings::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: incompatible swap adjustment and performance assessment strategies"
+        );
+    }
+
+    #[test]
+    fn with_slippage_tolerance_larger_than_one_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let swap_amount = Uint128::new(100000);
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            Some(Decimal::percent(150)),
+            None,
+            swap_amount,
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            VaultSettings::default(),
         )
         .unwrap_err();
 
@@ -832,7 +1379,1057 @@ mod create_vault_tests {
     }
 
     #[test]
-    fn should_create_vault() {
+    fn should_create_vault() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let swap_amount = Uint128::new(100000);
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            swap_amount,
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            vault,
+            Vault {
+                minimum_receive_amount: None,
+                label: None,
+                id: Uint128::new(1),
+                owner: info.sender,
+                destinations: vec![Destination::default()],
+                created_at: env.block.time,
+                status: VaultStatus::Scheduled,
+                time_interval: TimeInterval::Daily,
+                balance: info.funds[0].clone(),
+                slippage_tolerance: config.default_slippage_tolerance,
+                swap_amount,
+                target_denom: DENOM_UOSMO.to_string(),
+                started_at: None,
+                deposited_amount: info.funds[0].clone(),
+                escrow_level: Decimal::zero(),
+                swapped_amount: Coin::new(0, DENOM_STAKE.to_string()),
+                received_amount: Coin::new(0, DENOM_UOSMO.to_string()),
+                escrowed_amount: Coin::new(0, DENOM_UOSMO.to_string()),
+                swap_adjustment_strategy: None,
+                performance_assessment_strategy: None,
+                stop_loss: None,
+                take_profit: None,
+                pending_owner: None,
+                execution_window: None,
+                end_time: None,
+                max_executions: None,
+                execution_count: 0,
+                retry_count: 0,
+                accumulation: None,
+                claimable_amount: Coin::new(0, DENOM_UOSMO.to_string()),
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: Timestamp::from_seconds(env.block.time.plus_seconds(10).seconds()),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn should_publish_deposit_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(
+                Uint128::one(),
+                env.block,
+                EventData::DcaVaultFundsDeposited {
+                    amount: info.funds[0].clone()
+                },
+            )
+            .build(1),
+        ))
+    }
+
+    #[test]
+    fn for_different_owner_should_succeed() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let owner = Addr::unchecked(USER);
+        info = mock_info(ADMIN, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            owner,
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.owner, Addr::unchecked(USER));
+    }
+
+    #[test]
+    fn with_multiple_destinations_should_succeed() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let destinations = vec![
+            Destination {
+                allocation: Decimal::percent(50),
+                address: Addr::unchecked("dest-1"),
+                action: DestinationAction::Delegate {
+                    validator_address: Addr::unchecked(VALIDATOR),
+                },
+            },
+            Destination {
+                allocation: Decimal::percent(50),
+                address: Addr::unchecked("dest-2"),
+                action: DestinationAction::Delegate {
+                    validator_address: Addr::unchecked(VALIDATOR),
+                },
+            },
+        ];
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            destinations.clone(),
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.destinations, destinations);
+    }
+
+    #[test]
+    fn should_create_swap_adjustment_strategy() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::default()),
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault.swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::default())
+        );
+    }
+
+    #[test]
+    fn should_save_no_performance_assessment_strategy_when_none_provided() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(100000),
+                multiplier: Decimal::percent(200),
+                increase_only: false,
+            }),
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.performance_assessment_strategy, None);
+    }
+
+    #[test]
+    fn should_create_performance_assessment_strategy() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::default()),
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault.performance_assessment_strategy,
+            Some(PerformanceAssessmentStrategy::CompareToStandardDca {
+                swapped_amount: Coin::new(0, vault.balance.denom),
+                received_amount: Coin::new(0, DENOM_UOSMO),
+            })
+        );
+    }
+
+    #[test]
+    fn with_large_deposit_should_select_longer_duration_model() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(1000000000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::default()),
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault
+                .swap_adjustment_strategy
+                .map(|strategy| match strategy {
+                    SwapAdjustmentStrategy::RiskWeightedAverage { model_id, .. } => model_id,
+                    _ => panic!("unexpected swap adjustment strategy"),
+                }),
+            Some(90)
+        );
+    }
+
+    #[test]
+    fn with_no_target_time_should_execute_vault() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let response = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::default()),
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            funds: vec![],
+            msg: to_binary(&ExecuteMsg::ExecuteTrigger {
+                trigger_id: Uint128::one()
+            })
+            .unwrap()
+        })));
+    }
+
+    #[test]
+    fn with_calendar_time_interval_and_no_target_time_should_trigger_at_next_calendar_time() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info,
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let response = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::DaysOfWeek {
+                days: vec![DayOfWeek::Friday],
+                hour: 14,
+                minute: 0,
+            },
+            None,
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            vault.trigger,
+            Some(TriggerConfiguration::Time {
+                target_time: Timestamp::from_seconds(1572012000),
+            })
+        );
+    }
+
+    #[test]
+    fn with_target_price_and_target_start_time_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let user_info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            VaultSettings {
+                target_price: Some(Decimal::percent(50)),
+                ..VaultSettings::default()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot provide both a target_start_time_utc_seconds and a target_price"
+        );
+    }
+
+    #[test]
+    fn with_target_price_of_zero_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let user_info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            VaultSettings {
+                target_price: Some(Decimal::zero()),
+                ..VaultSettings::default()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: target_price must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_target_price_should_create_price_trigger() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let user_info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let response = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            VaultSettings {
+                target_price: Some(Decimal::percent(50)),
+                ..VaultSettings::default()
+            },
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault.trigger,
+            Some(TriggerConfiguration::Price {
+                target_price: Decimal::percent(50),
+                direction: PriceDirection::Below,
+            })
+        );
+        assert!(response.messages.is_empty());
+    }
+
+    #[test]
+    fn should_set_appropriate_escrow_level_for_compare_dca_performance_assessment_strategy() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::default()),
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            vault.escrow_level,
+            config.risk_weighted_average_escrow_level
+        );
+    }
+
+    #[test]
+    fn should_set_appropriate_escrow_level_for_no_performance_assessment_strategy() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.escrow_level, Decimal::zero());
+    }
+
+    #[test]
+    fn executing_contract_action_against_the_dca_contract_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![Destination {
+                address: env.contract.address,
+                allocation: Decimal::percent(100),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary(&ExecuteMsg::DisburseEscrow {
+                        vault_id: Uint128::one(),
+                    })
+                    .unwrap(),
+                },
+            }],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Cannot invoke provided destination callback against the DCA contract"
+        );
+    }
+
+    #[test]
+    fn with_deposit_to_vault_action_succeeds() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_UOSMO)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_STAKE.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::DepositToVault {
+                    vault_id: Uint128::one(),
+                },
+            }],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::new(2))
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault.destinations,
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::DepositToVault {
+                    vault_id: Uint128::one(),
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn with_deposit_to_vault_action_for_missing_vault_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -844,72 +2441,49 @@ mod create_vault_tests {
         create_pair_handler(
             deps.as_mut(),
             info.clone(),
-            pair.base_denom.clone(),
-            pair.quote_denom.clone(),
+            pair.base_denom,
+            pair.quote_denom,
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        let swap_amount = Uint128::new(100000);
         info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let err = create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
             info.sender.clone(),
             None,
-            vec![],
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::DepositToVault {
+                    vault_id: Uint128::one(),
+                },
+            }],
             DENOM_UOSMO.to_string(),
             None,
             None,
             None,
-            swap_amount,
+            Uint128::new(100000),
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
+            VaultSettings::default(),
         )
-        .unwrap();
-
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
-
-        let config = get_config(deps.as_ref().storage).unwrap();
+        .unwrap_err();
 
-        assert_eq!(
-            vault,
-            Vault {
-                minimum_receive_amount: None,
-                label: None,
-                id: Uint128::new(1),
-                owner: info.sender,
-                destinations: vec![Destination::default()],
-                created_at: env.block.time,
-                status: VaultStatus::Scheduled,
-                time_interval: TimeInterval::Daily,
-                balance: info.funds[0].clone(),
-                slippage_tolerance: config.default_slippage_tolerance,
-                swap_amount,
-                target_denom: DENOM_UOSMO.to_string(),
-                started_at: None,
-                deposited_amount: info.funds[0].clone(),
-                escrow_level: Decimal::zero(),
-                swapped_amount: Coin::new(0, DENOM_STAKE.to_string()),
-                received_amount: Coin::new(0, DENOM_UOSMO.to_string()),
-                escrowed_amount: Coin::new(0, DENOM_UOSMO.to_string()),
-                swap_adjustment_strategy: None,
-                performance_assessment_strategy: None,
-                trigger: Some(TriggerConfiguration::Time {
-                    target_time: Timestamp::from_seconds(env.block.time.plus_seconds(10).seconds()),
-                }),
-            }
-        );
+        assert_eq!(err.to_string(), "dca::state::vaults::VaultData not found");
     }
 
     #[test]
-    fn should_publish_deposit_event() {
+    fn with_invalid_ibc_channel_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -924,18 +2498,30 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
         info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let result = create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
             info.sender.clone(),
             None,
-            vec![],
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::IbcTransfer {
+                    channel_id: "transfer/channel-0".to_string(),
+                    receiver: "cosmos1receiver".to_string(),
+                    memo: None,
+                },
+            }],
             DENOM_UOSMO.to_string(),
             None,
             None,
@@ -945,28 +2531,17 @@ mod create_vault_tests {
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
-        )
-        .unwrap();
-
-        let events =
-            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), None, None, None)
-                .unwrap()
-                .events;
+            VaultSettings::default(),
+        );
 
-        assert!(events.contains(
-            &EventBuilder::new(
-                Uint128::one(),
-                env.block,
-                EventData::DcaVaultFundsDeposited {
-                    amount: info.funds[0].clone()
-                },
-            )
-            .build(1),
-        ))
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error: ibc channel id transfer/channel-0 is invalid"
+        );
     }
 
     #[test]
-    fn for_different_owner_should_succeed() {
+    fn with_ibc_memo_containing_callback_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -981,19 +2556,30 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        let owner = Addr::unchecked(USER);
-        info = mock_info(ADMIN, &[Coin::new(100000, DENOM_STAKE)]);
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let result = create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
-            owner,
+            info.sender.clone(),
             None,
-            vec![],
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::IbcTransfer {
+                    channel_id: "channel-0".to_string(),
+                    receiver: "cosmos1receiver".to_string(),
+                    memo: Some("{\"ibc_callback\":\"other\"}".to_string()),
+                },
+            }],
             DENOM_UOSMO.to_string(),
             None,
             None,
@@ -1003,18 +2589,17 @@ mod create_vault_tests {
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
-        )
-        .unwrap();
-
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
+            VaultSettings::default(),
+        );
 
-        assert_eq!(vault.owner, Addr::unchecked(USER));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error: ibc transfer memo must be a json object without an ibc_callback field"
+        );
     }
 
     #[test]
-    fn with_multiple_destinations_should_succeed() {
+    fn with_ibc_memo_that_is_not_a_json_object_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1029,43 +2614,30 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
         info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        let destinations = vec![
-            Destination {
-                allocation: Decimal::percent(50),
-                address: env.contract.address.clone(),
-                msg: Some(
-                    to_binary(&ExecuteMsg::ZDelegate {
-                        delegator_address: Addr::unchecked("dest-1"),
-                        validator_address: Addr::unchecked(VALIDATOR),
-                    })
-                    .unwrap(),
-                ),
-            },
-            Destination {
-                allocation: Decimal::percent(50),
-                address: env.contract.address.clone(),
-                msg: Some(
-                    to_binary(&ExecuteMsg::ZDelegate {
-                        delegator_address: Addr::unchecked("dest-2"),
-                        validator_address: Addr::unchecked(VALIDATOR),
-                    })
-                    .unwrap(),
-                ),
-            },
-        ];
-
-        create_vault_handler(
+        let result = create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
             info.sender.clone(),
             None,
-            destinations.clone(),
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::IbcTransfer {
+                    channel_id: "channel-0".to_string(),
+                    receiver: "cosmos1receiver".to_string(),
+                    memo: Some("[{\"forward\":{}}]".to_string()),
+                },
+            }],
             DENOM_UOSMO.to_string(),
             None,
             None,
@@ -1075,18 +2647,17 @@ mod create_vault_tests {
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
-        )
-        .unwrap();
-
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
+            VaultSettings::default(),
+        );
 
-        assert_eq!(vault.destinations, destinations);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error: ibc transfer memo must be a json object without an ibc_callback field"
+        );
     }
 
     #[test]
-    fn should_create_swap_adjustment_strategy() {
+    fn with_ibc_transfer_action_succeeds() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1101,18 +2672,30 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
         info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let result = create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
             info.sender.clone(),
             None,
-            vec![],
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::IbcTransfer {
+                    channel_id: "channel-0".to_string(),
+                    receiver: "cosmos1receiver".to_string(),
+                    memo: None,
+                },
+            }],
             DENOM_UOSMO.to_string(),
             None,
             None,
@@ -1120,23 +2703,29 @@ mod create_vault_tests {
             Uint128::new(100000),
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
-            Some(SwapAdjustmentStrategyParams::default()),
-        )
-        .unwrap();
+            None,
+            None,
+            VaultSettings::default(),
+        );
+
+        result.unwrap();
 
         let vault = get_vault_handler(deps.as_ref(), Uint128::one())
             .unwrap()
             .vault;
 
         assert_eq!(
-            vault.swap_adjustment_strategy,
-            Some(SwapAdjustmentStrategy::default())
+            vault.destinations[0].action,
+            DestinationAction::IbcTransfer {
+                channel_id: "channel-0".to_string(),
+                receiver: "cosmos1receiver".to_string(),
+                memo: None,
+            }
         );
     }
 
     #[test]
-    fn should_save_no_performance_assessment_strategy_when_none_provided() {
+    fn with_zero_claim_threshold_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1151,12 +2740,16 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
         info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let err = create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
@@ -1171,47 +2764,52 @@ mod create_vault_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
-            Some(SwapAdjustmentStrategyParams::WeightedScale {
-                base_receive_amount: Uint128::new(100000),
-                multiplier: Decimal::percent(200),
-                increase_only: false,
-            }),
+            None,
+            VaultSettings {
+                accumulation: Some(Accumulation {
+                    claim_threshold: Some(Uint128::zero()),
+                }),
+                ..VaultSettings::default()
+            },
         )
-        .unwrap();
-
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
+        .unwrap_err();
 
-        assert_eq!(vault.performance_assessment_strategy, None);
+        assert_eq!(
+            err.to_string(),
+            "Error: claim_threshold must be greater than 0"
+        );
     }
 
     #[test]
-    fn should_create_performance_assessment_strategy() {
+    fn with_zero_termination_condition_price_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
 
         let pair = Pair::default();
 
         create_pair_handler(
-            deps.as_mut(),
-            info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+        let user_info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let err = create_vault_handler(
             deps.as_mut(),
-            env.clone(),
-            &info,
-            info.sender.clone(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
             None,
             vec![],
             DENOM_UOSMO.to_string(),
@@ -1220,51 +2818,55 @@ mod create_vault_tests {
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
-            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
+            VaultSettings {
+                stop_loss: Some(TerminationCondition {
+                    price: Decimal::zero(),
+                    action: TerminationAction::Refund,
+                }),
+                ..VaultSettings::default()
+            },
         )
-        .unwrap();
-
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
+        .unwrap_err();
 
         assert_eq!(
-            vault.performance_assessment_strategy,
-            Some(PerformanceAssessmentStrategy::CompareToStandardDca {
-                swapped_amount: Coin::new(0, vault.balance.denom),
-                received_amount: Coin::new(0, DENOM_UOSMO),
-            })
+            err.to_string(),
+            "Error: stop_loss and take_profit prices must be greater than 0"
         );
     }
 
     #[test]
-    fn with_large_deposit_should_select_longer_duration_model() {
+    fn with_stop_loss_price_above_take_profit_price_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
 
         let pair = Pair::default();
 
         create_pair_handler(
             deps.as_mut(),
-            info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(1000000000, DENOM_STAKE)]);
+        let user_info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let err = create_vault_handler(
             deps.as_mut(),
-            env.clone(),
-            &info,
-            info.sender.clone(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
             None,
             vec![],
             DENOM_UOSMO.to_string(),
@@ -1273,53 +2875,69 @@ mod create_vault_tests {
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
-            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
+            VaultSettings {
+                stop_loss: Some(TerminationCondition {
+                    price: Decimal::percent(150),
+                    action: TerminationAction::Refund,
+                }),
+                take_profit: Some(TerminationCondition {
+                    price: Decimal::percent(120),
+                    action: TerminationAction::Swap,
+                }),
+                ..VaultSettings::default()
+            },
         )
-        .unwrap();
-
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
+        .unwrap_err();
 
         assert_eq!(
-            vault
-                .swap_adjustment_strategy
-                .map(|strategy| match strategy {
-                    SwapAdjustmentStrategy::RiskWeightedAverage { model_id, .. } => model_id,
-                    _ => panic!("unexpected swap adjustment strategy"),
-                }),
-            Some(90)
+            err.to_string(),
+            "Error: stop_loss price must be less than take_profit price"
         );
     }
 
     #[test]
-    fn with_no_target_time_should_execute_vault() {
+    fn with_termination_conditions_should_save_them_on_the_vault() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
 
         let pair = Pair::default();
 
         create_pair_handler(
             deps.as_mut(),
-            info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+        let user_info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        let response = create_vault_handler(
+        let stop_loss = Some(TerminationCondition {
+            price: Decimal::percent(80),
+            action: TerminationAction::Refund,
+        });
+
+        let take_profit = Some(TerminationCondition {
+            price: Decimal::percent(120),
+            action: TerminationAction::Swap,
+        });
+
+        create_vault_handler(
             deps.as_mut(),
-            env.clone(),
-            &info,
-            info.sender.clone(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
             None,
             vec![],
             DENOM_UOSMO.to_string(),
@@ -1329,43 +2947,50 @@ mod create_vault_tests {
             Uint128::new(100000),
             TimeInterval::Daily,
             None,
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
-            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            VaultSettings {
+                stop_loss: stop_loss.clone(),
+                take_profit: take_profit.clone(),
+                ..VaultSettings::default()
+            },
         )
         .unwrap();
 
-        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
-            contract_addr: env.contract.address.to_string(),
-            funds: vec![],
-            msg: to_binary(&ExecuteMsg::ExecuteTrigger {
-                trigger_id: Uint128::one()
-            })
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
             .unwrap()
-        })));
+            .vault;
+
+        assert_eq!(vault.stop_loss, stop_loss);
+        assert_eq!(vault.take_profit, take_profit);
     }
 
     #[test]
-    fn should_set_appropriate_escrow_level_for_compare_dca_performance_assessment_strategy() {
+    fn with_end_time_in_the_past_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
 
         let pair = Pair::default();
 
         create_pair_handler(
             deps.as_mut(),
-            info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+        let info = mock_info(USER, &[Coin::new(1000000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let err = create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
@@ -1378,46 +3003,48 @@ mod create_vault_tests {
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
-            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
+            VaultSettings {
+                end_time_utc_seconds: Some(Uint64::from(env.block.time.seconds() - 1)),
+                ..VaultSettings::default()
+            },
         )
-        .unwrap();
-
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
-
-        let config = get_config(deps.as_ref().storage).unwrap();
+        .unwrap_err();
 
         assert_eq!(
-            vault.escrow_level,
-            config.risk_weighted_average_escrow_level
+            err.to_string(),
+            "Error: end_time_utc_seconds must be after the vault start time and some time in the future"
         );
     }
 
     #[test]
-    fn should_set_appropriate_escrow_level_for_no_performance_assessment_strategy() {
+    fn with_end_time_before_target_start_time_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
 
         let pair = Pair::default();
 
         create_pair_handler(
             deps.as_mut(),
-            info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+        let info = mock_info(USER, &[Coin::new(1000000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let err = create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
@@ -1430,39 +3057,46 @@ mod create_vault_tests {
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(Uint64::from(env.block.time.plus_seconds(60).seconds())),
             None,
             None,
+            VaultSettings {
+                end_time_utc_seconds: Some(Uint64::from(env.block.time.plus_seconds(30).seconds())),
+                ..VaultSettings::default()
+            },
         )
-        .unwrap();
-
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
+        .unwrap_err();
 
-        assert_eq!(vault.escrow_level, Decimal::zero());
+        assert_eq!(
+            err.to_string(),
+            "Error: end_time_utc_seconds must be after the vault start time and some time in the future"
+        );
     }
 
     #[test]
-    fn invoking_contract_callback_with_unauthorised_msg_fails() {
+    fn with_zero_max_executions_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
 
         let pair = Pair::default();
 
         create_pair_handler(
             deps.as_mut(),
-            info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+        let info = mock_info(USER, &[Coin::new(1000000, DENOM_STAKE)]);
 
         let err = create_vault_handler(
             deps.as_mut(),
@@ -1470,54 +3104,53 @@ mod create_vault_tests {
             &info,
             info.sender.clone(),
             None,
-            vec![Destination {
-                address: env.contract.address,
-                allocation: Decimal::percent(100),
-                msg: Some(
-                    to_binary(&ExecuteMsg::DisburseEscrow {
-                        vault_id: Uint128::one(),
-                    })
-                    .unwrap(),
-                ),
-            }],
+            vec![],
             DENOM_UOSMO.to_string(),
             None,
             None,
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
+            None,
+            VaultSettings {
+                max_executions: Some(0),
+                ..VaultSettings::default()
+            },
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: Cannot invoke provided destination callback against the DCA contract"
+            "Error: max_executions must be greater than 0"
         );
     }
 
     #[test]
-    fn invoking_contract_callback_with_authorised_msg_succeeds() {
+    fn with_end_time_and_max_executions_should_save_them_on_the_vault() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let admin_info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
 
         let pair = Pair::default();
 
         create_pair_handler(
             deps.as_mut(),
-            info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+        let info = mock_info(USER, &[Coin::new(1000000, DENOM_STAKE)]);
 
         create_vault_handler(
             deps.as_mut(),
@@ -1525,26 +3158,23 @@ mod create_vault_tests {
             &info,
             info.sender.clone(),
             None,
-            vec![Destination {
-                address: env.contract.address.clone(),
-                allocation: Decimal::percent(100),
-                msg: Some(
-                    to_binary(&ExecuteMsg::Deposit {
-                        address: Addr::unchecked(USER),
-                        vault_id: Uint128::one(),
-                    })
-                    .unwrap(),
-                ),
-            }],
+            vec![],
             DENOM_UOSMO.to_string(),
             None,
             None,
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
+            None,
+            VaultSettings {
+                end_time_utc_seconds: Some(Uint64::from(
+                    env.block.time.plus_seconds(7 * 24 * 60 * 60).seconds(),
+                )),
+                max_executions: Some(5),
+                ..VaultSettings::default()
+            },
         )
         .unwrap();
 
@@ -1553,18 +3183,12 @@ mod create_vault_tests {
             .vault;
 
         assert_eq!(
-            vault.destinations,
-            vec![Destination {
-                address: env.contract.address,
-                allocation: Decimal::percent(100),
-                msg: Some(
-                    to_binary(&ExecuteMsg::Deposit {
-                        address: Addr::unchecked(USER),
-                        vault_id: Uint128::one(),
-                    })
-                    .unwrap(),
-                ),
-            }]
+            vault.end_time,
+            Some(Timestamp::from_seconds(
+                env.block.time.plus_seconds(7 * 24 * 60 * 60).seconds()
+            ))
         );
+        assert_eq!(vault.max_executions, Some(5));
+        assert_eq!(vault.execution_count, 0);
     }
 }
@@ -0,0 +1,434 @@
+use crate::error::ContractError;
+use crate::helpers::validation::assert_sender_has_role;
+use crate::helpers::vault::cancel_vault;
+use crate::msg::ExecuteMsg;
+use crate::state::pairs::{delete_pair, find_pair};
+use crate::state::triggers::delete_trigger;
+use crate::state::vaults::get_vaults_by_pair;
+use crate::types::pair::Pair;
+use crate::types::role::Role;
+use crate::types::vault::{Vault, VaultStatus};
+use cosmwasm_std::{
+    to_binary, DepsMut, Env, Event, MessageInfo, Response, StdResult, Storage, SubMsg, Uint128,
+    WasmMsg,
+};
+
+pub fn delete_pair_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_denom: String,
+    quote_denom: String,
+    force_cancel_vaults: Option<bool>,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &info.sender, Role::PairManager)?;
+
+    let pair =
+        find_pair(deps.storage, [base_denom.clone(), quote_denom.clone()]).map_err(|_| {
+            ContractError::PairNotFound {
+                base_denom: base_denom.clone(),
+                quote_denom: quote_denom.clone(),
+            }
+        })?;
+
+    let vaults = get_running_vaults_for_pair(deps.storage, &pair)?;
+
+    if !vaults.is_empty() && !force_cancel_vaults.unwrap_or(false) {
+        return Err(ContractError::PairInUse {
+            base_denom: pair.base_denom,
+            quote_denom: pair.quote_denom,
+            vault_count: vaults.len(),
+        });
+    }
+
+    let mut sub_msgs = Vec::<SubMsg>::new();
+
+    for vault in vaults.clone() {
+        sub_msgs.append(&mut cancel_vault(deps.storage, &env, vault)?);
+    }
+
+    // inactive vaults still comparing against a standard dca keep their trigger, but can no
+    // longer execute once the pair is gone, so their escrow is released now instead
+    for vault in get_vaults_by_pair(deps.storage, pair.denoms(), VaultStatus::Inactive)? {
+        if vault.trigger.is_none() {
+            continue;
+        }
+
+        delete_trigger(deps.storage, vault.id)?;
+
+        if vault.escrowed_amount.amount > Uint128::zero() {
+            sub_msgs.push(SubMsg::new(WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id })?,
+                funds: vec![],
+            }));
+        }
+    }
+
+    delete_pair(deps.storage, pair.denoms());
+
+    let cancelled_vault_ids = vaults
+        .iter()
+        .map(|vault| vault.id.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    Ok(Response::new()
+        .add_attribute("delete_pair", "true")
+        .add_attribute("base_denom", pair.base_denom.clone())
+        .add_attribute("quote_denom", pair.quote_denom.clone())
+        .add_event(
+            Event::new("pair_deleted")
+                .add_attribute("sender", info.sender)
+                .add_attribute("base_denom", pair.base_denom)
+                .add_attribute("quote_denom", pair.quote_denom)
+                .add_attribute("route", format!("{:?}", pair.route))
+                .add_attribute("cancelled_vault_ids", cancelled_vault_ids),
+        )
+        .add_submessages(sub_msgs))
+}
+
+fn get_running_vaults_for_pair(storage: &dyn Storage, pair: &Pair) -> StdResult<Vec<Vault>> {
+    let mut vaults = Vec::<Vault>::new();
+
+    for status in [
+        VaultStatus::Scheduled,
+        VaultStatus::Active,
+        VaultStatus::Paused,
+    ] {
+        vaults.extend(get_vaults_by_pair(storage, pair.denoms(), status)?);
+    }
+
+    vaults.sort_by_key(|vault| vault.id);
+
+    Ok(vaults)
+}
+
+#[cfg(test)]
+mod delete_pair_tests {
+    use super::*;
+    use crate::constants::ONE;
+    use crate::types::vault_settings::VaultSettings;
+    use crate::{
+        handlers::{
+            create_vault::create_vault_handler,
+            get_events_by_resource_id::get_events_by_resource_id_handler,
+        },
+        state::vaults::get_vault,
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO, USER},
+        },
+        types::{
+            event::{EventBuilder, EventData},
+            time_interval::TimeInterval,
+            vault::VaultStatus,
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        BankMsg, Coin, Uint128,
+    };
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = delete_pair_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            DENOM_STAKE.to_string(),
+            DENOM_UOSMO.to_string(),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_non_existent_pair_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = delete_pair_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_STAKE.to_string(),
+            DENOM_UOSMO.to_string(),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: pair for stake and uosmo does not exist"
+        );
+    }
+
+    #[test]
+    fn with_active_vault_using_pair_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = delete_pair_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_STAKE.to_string(),
+            DENOM_UOSMO.to_string(),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot delete pair for stake and uosmo while 1 active, scheduled or paused vaults are still using it"
+        );
+    }
+
+    #[test]
+    fn with_only_inactive_vaults_using_pair_deletes_pair() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                ..Vault::default()
+            },
+        );
+
+        delete_pair_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_STAKE.to_string(),
+            DENOM_UOSMO.to_string(),
+            None,
+        )
+        .unwrap();
+
+        assert!(find_pair(deps.as_ref().storage, vault.denoms()).is_err());
+    }
+
+    #[test]
+    fn with_inactive_vault_still_triggering_removes_trigger_and_disburses_escrow() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                escrowed_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                ..Vault::default()
+            },
+        );
+
+        let response = delete_pair_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            DENOM_STAKE.to_string(),
+            DENOM_UOSMO.to_string(),
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert!(vault.trigger.is_some());
+        assert_eq!(updated_vault.trigger, None);
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id }).unwrap(),
+                funds: vec![],
+            })]
+        );
+    }
+
+    #[test]
+    fn with_force_cancel_cancels_vaults_using_pair_and_refunds_owners() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let response = delete_pair_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            DENOM_STAKE.to_string(),
+            DENOM_UOSMO.to_string(),
+            Some(true),
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(updated_vault.status, VaultStatus::Cancelled);
+        assert_eq!(updated_vault.balance.amount, Uint128::zero());
+        assert_eq!(updated_vault.trigger, None);
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![vault.balance.clone()],
+        })));
+        assert!(events.contains(
+            &EventBuilder::new(vault.id, env.block, EventData::DcaVaultCancelled {}).build(1)
+        ));
+        assert!(find_pair(deps.as_ref().storage, vault.denoms()).is_err());
+    }
+
+    #[test]
+    fn with_force_cancel_leaves_vaults_using_other_pairs_running() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let other_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(vault.balance.amount.into(), "uother"),
+                ..Vault::default()
+            },
+        );
+
+        delete_pair_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_STAKE.to_string(),
+            DENOM_UOSMO.to_string(),
+            Some(true),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_vault(deps.as_ref().storage, vault.id).unwrap().status,
+            VaultStatus::Cancelled
+        );
+        assert_eq!(
+            get_vault(deps.as_ref().storage, other_vault.id)
+                .unwrap()
+                .status,
+            VaultStatus::Active
+        );
+    }
+
+    #[test]
+    fn emits_pair_deleted_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let response = delete_pair_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_STAKE.to_string(),
+            DENOM_UOSMO.to_string(),
+            Some(true),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.events,
+            vec![Event::new("pair_deleted")
+                .add_attribute("sender", ADMIN)
+                .add_attribute("base_denom", DENOM_STAKE)
+                .add_attribute("quote_denom", DENOM_UOSMO)
+                .add_attribute("route", "[3]")
+                .add_attribute("cancelled_vault_ids", vault.id.to_string())]
+        );
+    }
+
+    #[test]
+    fn create_vault_rejects_deleted_pair() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                ..Vault::default()
+            },
+        );
+
+        delete_pair_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            DENOM_STAKE.to_string(),
+            DENOM_UOSMO.to_string(),
+            None,
+        )
+        .unwrap();
+
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UOSMO)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_STAKE.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            VaultSettings::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: swapping uosmo to stake not supported"
+        );
+    }
+}
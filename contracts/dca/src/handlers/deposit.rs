@@ -2,7 +2,7 @@ use crate::error::ContractError;
 use crate::helpers::time::get_next_target_time;
 use crate::helpers::validation::{
     assert_contract_is_not_paused, assert_deposited_denom_matches_send_denom,
-    assert_exactly_one_asset, assert_vault_is_not_cancelled,
+    assert_exactly_one_asset, assert_pair_exists_for_denoms, assert_vault_is_not_cancelled,
 };
 use crate::helpers::vault::get_risk_weighted_average_model_id;
 use crate::state::events::create_event;
@@ -31,12 +31,7 @@ pub fn deposit_handler(
     let vault_was_inactive = vault.is_inactive();
 
     if address != vault.owner {
-        return Err(ContractError::CustomError {
-            val: format!(
-                "provided an incorrect owner address for vault id {}",
-                vault_id
-            ),
-        });
+        return Err(ContractError::IncorrectVaultOwner { vault_id });
     }
 
     assert_vault_is_not_cancelled(&vault)?;
@@ -44,11 +39,16 @@ pub fn deposit_handler(
         info.funds[0].denom.clone(),
         vault.balance.denom.clone(),
     )?;
+    assert_pair_exists_for_denoms(
+        deps.as_ref(),
+        vault.get_swap_denom(),
+        vault.target_denom.clone(),
+    )?;
 
     vault.balance.amount += info.funds[0].amount;
     vault.deposited_amount.amount += info.funds[0].amount;
 
-    if !vault.is_scheduled() {
+    if !vault.is_scheduled() && !vault.is_paused() {
         vault.status = VaultStatus::Active
     }
 
@@ -118,6 +118,7 @@ mod dposit_tests {
     use crate::handlers::get_vault::get_vault_handler;
     use crate::helpers::coin::{add, subtract};
     use crate::state::config::{get_config, update_config};
+    use crate::state::pairs::delete_pair;
     use crate::tests::helpers::{instantiate_contract, setup_vault};
     use crate::tests::mocks::{ADMIN, DENOM_STAKE, DENOM_UOSMO, USER};
     use crate::types::config::Config;
@@ -212,6 +213,34 @@ mod dposit_tests {
         assert_eq!(updated_vault.status, VaultStatus::Active);
     }
 
+    #[test]
+    fn with_deleted_pair_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let deposit_amount = Coin::new(TEN.into(), DENOM_UOSMO);
+        let info = mock_info(ADMIN, &[deposit_amount]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                ..Vault::default()
+            },
+        );
+
+        delete_pair(deps.as_mut().storage, vault.denoms());
+
+        let err = deposit_handler(deps.as_mut(), env, info, vault.owner, vault.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: swapping uosmo to stake not supported"
+        );
+    }
+
     #[test]
     fn leaves_scheduled_vault_scheduled() {
         let mut deps = mock_dependencies();
@@ -238,6 +267,33 @@ mod dposit_tests {
         assert_eq!(updated_vault.status, VaultStatus::Scheduled);
     }
 
+    #[test]
+    fn leaves_paused_vault_paused() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let deposit_amount = Coin::new(TEN.into(), DENOM_UOSMO);
+        let info = mock_info(ADMIN, &[deposit_amount]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        deposit_handler(deps.as_mut(), env, info, vault.owner, vault.id).unwrap();
+
+        let updated_vault = get_vault_handler(deps.as_ref(), vault.id).unwrap().vault;
+
+        assert_eq!(updated_vault.status, VaultStatus::Paused);
+        assert_eq!(updated_vault.trigger, None);
+    }
+
     #[test]
     fn leaves_active_vault_active() {
         let mut deps = mock_dependencies();
@@ -19,7 +19,7 @@ use crate::{
         vault::Vault,
     },
 };
-use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, StdError, Uint128};
 
 pub fn disburse_escrow_handler(
     deps: DepsMut,
@@ -52,15 +52,20 @@ pub fn disburse_escrow_handler(
 
     if let Some(due_date) = due_date {
         if env.block.time < due_date {
-            return Err(ContractError::CustomError {
-                val: "Escrow is not available to be disbursed yet".to_string(),
-            });
+            return Err(ContractError::EscrowNotDue { vault_id: vault.id });
         }
     }
 
-    let pair = find_pair(deps.storage, vault.denoms())?;
-    let current_price = query_belief_price(&deps.as_ref(), env, &pair, vault.get_swap_denom())?;
-    let performance_fee = get_performance_fee(&vault, current_price)?;
+    // vaults for deleted pairs cannot be priced, so their escrow is returned without a fee
+    let performance_fee = match find_pair(deps.storage, vault.denoms()) {
+        Ok(pair) => {
+            let current_price =
+                query_belief_price(&deps.as_ref(), env, &pair, vault.get_swap_denom())?;
+            get_performance_fee(&vault, current_price)?
+        }
+        Err(StdError::NotFound { .. }) => Coin::new(0, vault.target_denom.clone()),
+        Err(error) => return Err(error.into()),
+    };
     let amount_to_disburse = subtract(&vault.escrowed_amount, &performance_fee)?;
 
     let vault = Vault {
@@ -89,6 +94,7 @@ pub fn disburse_escrow_handler(
     Ok(response
         .add_submessages(get_disbursement_messages(
             deps.storage,
+            env,
             &vault,
             amount_to_disburse.amount,
         )?)
@@ -110,6 +116,7 @@ mod disburse_escrow_tests {
         state::{
             config::get_config,
             disburse_escrow_tasks::{get_disburse_escrow_tasks, save_disburse_escrow_task},
+            pairs::delete_pair,
             vaults::get_vault,
         },
         tests::{
@@ -298,6 +305,55 @@ mod disburse_escrow_tests {
         })));
     }
 
+    #[test]
+    fn when_pair_has_been_deleted_returns_entire_escrow_without_fee() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                destinations: vec![Destination::default()],
+                swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                escrowed_amount: Coin::new((ONE * Decimal::percent(5)).into(), DENOM_STAKE),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
+                        received_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        delete_pair(deps.as_mut().storage, vault.denoms());
+
+        let response = disburse_escrow_handler(deps.as_mut(), &env, info, vault.id).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::reply_always(
+            BankMsg::Send {
+                to_address: vault.destinations[0].address.to_string(),
+                amount: vec![vault.escrowed_amount]
+            },
+            AFTER_FAILED_AUTOMATION_REPLY_ID
+        )));
+        assert_eq!(
+            get_vault(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .escrowed_amount
+                .amount,
+            Uint128::zero()
+        );
+    }
+
     #[test]
     fn publishes_escrow_disbursed_event() {
         let mut deps = calc_mock_dependencies();
@@ -5,12 +5,15 @@ use crate::helpers::fees::{get_automation_fee_rate, get_fee_messages, get_swap_f
 use crate::helpers::math::checked_mul;
 use crate::msg::ExecuteMsg;
 use crate::state::cache::{SWAP_CACHE, VAULT_CACHE};
+use crate::state::config::get_config;
 use crate::state::events::create_event;
-use crate::state::triggers::delete_trigger;
+use crate::state::triggers::{delete_trigger, save_trigger};
 use crate::state::vaults::{get_vault, update_vault};
 use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
+use crate::types::termination_condition::TerminationReason;
+use crate::types::trigger::{Trigger, TriggerConfiguration};
 use crate::types::vault::VaultStatus;
-use cosmwasm_std::{to_binary, SubMsg, SubMsgResult, Uint128, WasmMsg};
+use cosmwasm_std::{to_binary, BankMsg, Decimal, SubMsg, SubMsgResult, Uint128, WasmMsg};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{Attribute, Coin, DepsMut, Env, Reply, Response};
 
@@ -19,16 +22,18 @@ pub fn disburse_funds_handler(
     env: &Env,
     reply: Reply,
 ) -> Result<Response, ContractError> {
-    let vault_id = VAULT_CACHE.load(deps.storage)?;
-    let mut vault = get_vault(deps.storage, vault_id)?;
+    let swap_cache = SWAP_CACHE.load(deps.storage)?;
+    SWAP_CACHE.remove(deps.storage);
+
+    let mut vault = get_vault(deps.storage, swap_cache.vault_id)?;
+
+    VAULT_CACHE.save(deps.storage, &vault.id)?;
 
     let mut attributes = Vec::<Attribute>::new();
     let mut sub_msgs = Vec::<SubMsg>::new();
 
     match reply.result {
         SubMsgResult::Ok(_) => {
-            let swap_cache = SWAP_CACHE.load(deps.storage)?;
-
             let swap_denom_balance = &deps
                 .querier
                 .query_balance(&env.contract.address, vault.get_swap_denom())?;
@@ -54,12 +59,39 @@ pub fn disburse_funds_handler(
             let total_fee = swap_fee + automation_fee;
             let mut total_after_total_fee = coin_received.amount - total_fee;
 
+            let keeper_reward_rate = swap_cache
+                .keeper
+                .clone()
+                .and(get_config(deps.storage)?.keeper_reward_percent)
+                .unwrap_or(Decimal::zero());
+
+            let keeper_swap_fee_reward = checked_mul(swap_fee, keeper_reward_rate)?;
+            let keeper_automation_fee_reward = checked_mul(automation_fee, keeper_reward_rate)?;
+            let keeper_reward = keeper_swap_fee_reward + keeper_automation_fee_reward;
+
             sub_msgs.append(&mut get_fee_messages(
                 deps.as_ref(),
-                vec![swap_fee, automation_fee],
+                vec![
+                    swap_fee - keeper_swap_fee_reward,
+                    automation_fee - keeper_automation_fee_reward,
+                ],
                 coin_received.denom.clone(),
             )?);
 
+            if let Some(keeper) = swap_cache
+                .keeper
+                .clone()
+                .filter(|_| !keeper_reward.is_zero())
+            {
+                sub_msgs.push(SubMsg::new(BankMsg::Send {
+                    to_address: keeper.to_string(),
+                    amount: vec![Coin::new(keeper_reward.into(), coin_received.denom.clone())],
+                }));
+
+                attributes.push(Attribute::new("keeper", keeper));
+                attributes.push(Attribute::new("keeper_reward", keeper_reward.to_string()));
+            }
+
             vault.balance.amount -= coin_sent.amount;
             vault.swapped_amount = add_to(vault.swapped_amount, coin_sent.amount);
             vault.received_amount = add_to(vault.received_amount, total_after_total_fee);
@@ -68,15 +100,56 @@ pub fn disburse_funds_handler(
             total_after_total_fee -= amount_to_escrow;
 
             vault.escrowed_amount = add_to(vault.escrowed_amount, amount_to_escrow);
+            vault.execution_count += 1;
+            vault.retry_count = 0;
 
             if vault.balance.amount.is_zero() {
                 vault.status = VaultStatus::Inactive;
             }
 
+            if vault.is_active() && vault.max_executions_reached() {
+                create_event(
+                    deps.storage,
+                    EventBuilder::new(
+                        vault.id,
+                        env.block.clone(),
+                        EventData::DcaVaultTerminated {
+                            reason: TerminationReason::MaxExecutionsReached {
+                                executions: vault.execution_count,
+                            },
+                        },
+                    ),
+                )?;
+
+                sub_msgs.push(SubMsg::new(BankMsg::Send {
+                    to_address: vault.owner.to_string(),
+                    amount: vec![vault.balance.clone()],
+                }));
+
+                vault.balance.amount = Uint128::zero();
+                vault.status = VaultStatus::Inactive;
+            }
+
+            let mut amount_to_disburse = total_after_total_fee;
+
+            if let Some(accumulation) = vault.accumulation.clone() {
+                vault.claimable_amount = add_to(vault.claimable_amount, total_after_total_fee);
+                amount_to_disburse = Uint128::zero();
+
+                if accumulation
+                    .claim_threshold
+                    .is_some_and(|claim_threshold| vault.claimable_amount.amount >= claim_threshold)
+                {
+                    amount_to_disburse = vault.claimable_amount.amount;
+                    vault.claimable_amount.amount = Uint128::zero();
+                }
+            }
+
             update_vault(deps.storage, vault.clone())?;
 
             sub_msgs.append(
-                &mut get_disbursement_messages(deps.storage, &vault, total_after_total_fee)?.into(),
+                &mut get_disbursement_messages(deps.storage, env, &vault, amount_to_disburse)?
+                    .into(),
             );
 
             create_event(
@@ -92,6 +165,24 @@ pub fn disburse_funds_handler(
                 ),
             )?;
 
+            if vault.accumulation.is_some() && !amount_to_disburse.is_zero() {
+                let amount_claimed =
+                    Coin::new(amount_to_disburse.into(), vault.target_denom.clone());
+
+                create_event(
+                    deps.storage,
+                    EventBuilder::new(
+                        vault.id,
+                        env.block.clone(),
+                        EventData::DcaVaultFundsClaimed {
+                            amount: amount_claimed.clone(),
+                        },
+                    ),
+                )?;
+
+                attributes.push(Attribute::new("claimed_amount", amount_claimed.to_string()));
+            }
+
             attributes.push(Attribute::new("swapped_amount", coin_sent.to_string()));
             attributes.push(Attribute::new("received_amount", coin_received.to_string()));
             attributes.push(Attribute::new("fee_amount", total_fee.to_string()));
@@ -112,6 +203,57 @@ pub fn disburse_funds_handler(
                 "execution_skipped",
                 "slippage_tolerance_exceeded",
             ));
+
+            // only vaults exiting on a termination condition swap while inactive
+            if vault.is_inactive() && !vault.balance.amount.is_zero() {
+                sub_msgs.push(SubMsg::new(BankMsg::Send {
+                    to_address: vault.owner.to_string(),
+                    amount: vec![vault.balance.clone()],
+                }));
+
+                attributes.push(Attribute::new("refunded_amount", vault.balance.to_string()));
+
+                vault.balance.amount = Uint128::zero();
+
+                update_vault(deps.storage, vault.clone())?;
+            } else if let (Some(retry_policy), Some(TriggerConfiguration::Time { target_time })) = (
+                get_config(deps.storage)?.retry_policy,
+                vault.trigger.clone(),
+            ) {
+                let retry_time = env.block.time.plus_seconds(retry_policy.backoff_seconds);
+
+                if vault.retry_count < retry_policy.max_retries && retry_time < target_time {
+                    vault.retry_count += 1;
+
+                    save_trigger(
+                        deps.storage,
+                        Trigger {
+                            vault_id: vault.id,
+                            configuration: TriggerConfiguration::Time {
+                                target_time: retry_time,
+                            },
+                        },
+                    )?;
+
+                    create_event(
+                        deps.storage,
+                        EventBuilder::new(
+                            vault.id,
+                            env.block.to_owned(),
+                            EventData::DcaVaultExecutionRetryScheduled {
+                                retry_count: vault.retry_count,
+                                target_time: retry_time,
+                            },
+                        ),
+                    )?;
+
+                    attributes.push(Attribute::new("retry_count", vault.retry_count.to_string()));
+                } else {
+                    vault.retry_count = 0;
+                }
+
+                update_vault(deps.storage, vault.clone())?;
+            }
         }
     }
 
@@ -142,7 +284,7 @@ mod disburse_funds_tests {
         helpers::vault::get_swap_amount,
         state::{
             cache::{SwapCache, SWAP_CACHE},
-            config::{create_custom_fee, get_config},
+            config::{create_custom_fee, get_config, update_config},
             swap_adjustments::update_swap_adjustment,
             vaults::get_vault,
         },
@@ -151,24 +293,85 @@ mod disburse_funds_tests {
                 instantiate_contract, instantiate_contract_with_multiple_fee_collectors,
                 setup_vault,
             },
-            mocks::{ADMIN, DENOM_STAKE, DENOM_UOSMO},
+            mocks::{ADMIN, DENOM_STAKE, DENOM_UOSMO, KEEPER},
         },
         types::{
+            accumulation::Accumulation,
+            config::Config,
             destination::Destination,
             event::{Event, EventBuilder, EventData, ExecutionSkippedReason},
             fee_collector::FeeCollector,
             performance_assessment_strategy::PerformanceAssessmentStrategy,
             position_type::PositionType,
+            retry_policy::RetryPolicy,
             swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy},
             vault::{Vault, VaultStatus},
         },
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse, SubMsgResult, Uint128,
+        Addr, BankMsg, Coin, CosmosMsg, Decimal, Reply, SubMsg, SubMsgResponse, SubMsgResult,
+        Uint128,
     };
     use std::{cmp::min, str::FromStr};
 
+    #[test]
+    fn updates_the_vault_the_swap_cache_belongs_to() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let other_vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(vault.swap_amount.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+        let updated_other_vault = get_vault(&deps.storage, other_vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.balance.amount,
+            vault.balance.amount - vault.swap_amount
+        );
+        assert_eq!(updated_other_vault.balance, other_vault.balance);
+        assert_eq!(VAULT_CACHE.load(&deps.storage).unwrap(), vault.id);
+        assert!(SWAP_CACHE.may_load(&deps.storage).unwrap().is_none());
+    }
+
     #[test]
     fn with_succcesful_swap_returns_funds_to_destination() {
         let mut deps = mock_dependencies();
@@ -190,8 +393,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -218,7 +423,7 @@ mod disburse_funds_tests {
 
         let automation_fee = get_config(&deps.storage).unwrap().automation_fee_percent;
 
-        let automation_fees = vault.destinations.iter().filter(|d| d.msg.is_some()).fold(
+        let automation_fees = vault.destinations.iter().filter(|d| d.is_automated()).fold(
             Coin::new(0, vault.target_denom.clone()),
             |mut accum, destination| {
                 let allocation_amount =
@@ -254,8 +459,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -315,8 +522,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -343,7 +552,7 @@ mod disburse_funds_tests {
         let swap_fee = config.default_swap_fee_percent * receive_amount;
         let total_after_swap_fee = receive_amount - swap_fee;
 
-        let automation_fee = vault.destinations.iter().filter(|d| d.msg.is_some()).fold(
+        let automation_fee = vault.destinations.iter().filter(|d| d.is_automated()).fold(
             Uint128::zero(),
             |acc, destination| {
                 let allocation_amount =
@@ -390,8 +599,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -441,8 +652,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -485,6 +698,139 @@ mod disburse_funds_tests {
         );
     }
 
+    #[test]
+    fn with_accumulation_keeps_received_amount_in_vault() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination::default()],
+                accumulation: Some(Accumulation {
+                    claim_threshold: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(ONE.into(), vault.target_denom.clone())],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.claimable_amount,
+            updated_vault.received_amount
+        );
+        assert!(!response.messages.iter().any(|msg| msg.msg
+            == CosmosMsg::Bank(BankMsg::Send {
+                to_address: vault.destinations[0].address.to_string(),
+                amount: vec![updated_vault.received_amount.clone()],
+            })));
+    }
+
+    #[test]
+    fn with_accumulation_threshold_reached_disburses_claimable_amount() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination::default()],
+                accumulation: Some(Accumulation {
+                    claim_threshold: Some(Uint128::one()),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(ONE.into(), vault.target_denom.clone())],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.claimable_amount,
+            Coin::new(0, vault.target_denom.clone())
+        );
+        assert!(response.messages.contains(&SubMsg::reply_always(
+            BankMsg::Send {
+                to_address: vault.destinations[0].address.to_string(),
+                amount: vec![updated_vault.received_amount.clone()],
+            },
+            AFTER_FAILED_AUTOMATION_REPLY_ID
+        )));
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultFundsClaimed {
+                amount: updated_vault.received_amount.clone(),
+            }));
+    }
+
     #[test]
     fn with_succcesful_swap_adjusts_received_amount_stat() {
         let mut deps = mock_dependencies();
@@ -498,8 +844,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -530,7 +878,7 @@ mod disburse_funds_tests {
         vault
             .destinations
             .iter()
-            .filter(|d| d.msg.is_some())
+            .filter(|d| d.is_automated())
             .for_each(|destination| {
                 let allocation_amount =
                     checked_mul(receive_amount - fee, destination.allocation).unwrap();
@@ -565,8 +913,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -663,8 +1013,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -767,8 +1119,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -845,6 +1199,7 @@ mod disburse_funds_tests {
     fn with_failed_swap_and_insufficient_funds_does_not_reduce_vault_balance() {
         let mut deps = mock_dependencies();
         let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
         let balance = Coin::new(TWO_MICRONS.into(), DENOM_UOSMO);
 
@@ -857,6 +1212,18 @@ mod disburse_funds_tests {
             },
         );
 
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
         let reply = Reply {
             id: AFTER_SWAP_REPLY_ID,
             result: SubMsgResult::Err("Generic failure".to_string()),
@@ -878,6 +1245,18 @@ mod disburse_funds_tests {
 
         let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
 
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
         let reply = Reply {
             id: AFTER_SWAP_REPLY_ID,
             result: SubMsgResult::Err("failed for slippage".to_string()),
@@ -905,9 +1284,22 @@ mod disburse_funds_tests {
     fn with_failed_swap_leaves_vault_active() {
         let mut deps = mock_dependencies();
         let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
         let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
 
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
         let reply = Reply {
             id: AFTER_SWAP_REPLY_ID,
             result: SubMsgResult::Err("failed for slippage".to_string()),
@@ -921,54 +1313,113 @@ mod disburse_funds_tests {
     }
 
     #[test]
-    fn with_failed_swap_does_not_reduce_vault_balance() {
+    fn with_failed_exit_swap_refunds_remaining_balance_to_owner() {
         let mut deps = mock_dependencies();
         let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
 
         let reply = Reply {
             id: AFTER_SWAP_REPLY_ID,
             result: SubMsgResult::Err("failed for slippage".to_string()),
         };
 
-        disburse_funds_handler(deps.as_mut(), &env, reply).unwrap();
+        let response = disburse_funds_handler(deps.as_mut(), &env, reply).unwrap();
 
-        let vault = get_vault(&deps.storage, vault.id).unwrap();
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
 
-        assert_eq!(vault.balance, Coin::new(TEN.into(), vault.get_swap_denom()));
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![vault.balance.clone()],
+        })));
+        assert_eq!(updated_vault.balance, Coin::new(0, vault.get_swap_denom()));
+        assert_eq!(updated_vault.trigger, None);
     }
 
     #[test]
-    fn with_custom_fee_for_base_denom_takes_custom_fee() {
+    fn with_failed_swap_does_not_reduce_vault_balance() {
         let mut deps = mock_dependencies();
         let env = mock_env();
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
         let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
 
-        let custom_fee_percent = Decimal::percent(20);
-
-        create_custom_fee(
-            &mut deps.storage,
-            vault.get_swap_denom(),
-            custom_fee_percent,
-        )
-        .unwrap();
-
-        let receive_amount = Uint128::new(234312312);
-
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
 
-        deps.querier.update_balance(
+        let reply = Reply {
+            id: AFTER_SWAP_REPLY_ID,
+            result: SubMsgResult::Err("failed for slippage".to_string()),
+        };
+
+        disburse_funds_handler(deps.as_mut(), &env, reply).unwrap();
+
+        let vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(vault.balance, Coin::new(TEN.into(), vault.get_swap_denom()));
+    }
+
+    #[test]
+    fn with_custom_fee_for_base_denom_takes_custom_fee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let custom_fee_percent = Decimal::percent(20);
+
+        create_custom_fee(
+            &mut deps.storage,
+            vault.get_swap_denom(),
+            custom_fee_percent,
+        )
+        .unwrap();
+
+        let receive_amount = Uint128::new(234312312);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
             "cosmos2contract",
             vec![Coin::new(receive_amount.into(), vault.target_denom.clone())],
         );
@@ -1018,8 +1469,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -1082,8 +1535,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -1135,8 +1590,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -1185,8 +1642,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -1243,8 +1702,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -1300,8 +1761,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -1360,8 +1823,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -1420,8 +1885,10 @@ mod disburse_funds_tests {
             .save(
                 deps.as_mut().storage,
                 &SwapCache {
+                    vault_id: vault.id,
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
                 },
             )
             .unwrap();
@@ -1447,4 +1914,615 @@ mod disburse_funds_tests {
         let vault = get_vault(&deps.storage, vault.id).unwrap();
         assert!(vault.trigger.is_some());
     }
+
+    #[test]
+    fn with_succcesful_swap_increments_execution_count() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(vault.swap_amount.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+        assert_eq!(updated_vault.execution_count, vault.execution_count + 1);
+    }
+
+    #[test]
+    fn with_failed_swap_does_not_increment_execution_count() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Err("Generic error".to_string()),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+        assert_eq!(updated_vault.execution_count, vault.execution_count);
+    }
+
+    #[test]
+    fn with_succcesful_final_execution_refunds_remaining_balance_and_deletes_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                max_executions: Some(3),
+                execution_count: 2,
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(vault.swap_amount.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![Coin::new(
+                (vault.balance.amount - vault.swap_amount).into(),
+                vault.get_swap_denom()
+            )],
+        })));
+        assert!(events.contains(&Event {
+            id: 1,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultTerminated {
+                reason: TerminationReason::MaxExecutionsReached { executions: 3 },
+            },
+        }));
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.balance.amount, Uint128::zero());
+        assert!(updated_vault.trigger.is_none());
+    }
+
+    #[test]
+    fn with_failed_swap_and_retry_policy_schedules_retry_after_backoff() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                retry_policy: Some(RetryPolicy {
+                    max_retries: 2,
+                    backoff_seconds: 60 * 60,
+                }),
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let next_target_time = env.block.time.plus_seconds(24 * 60 * 60);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: next_target_time,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Err("failed for slippage".to_string()),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(updated_vault.retry_count, 1);
+        assert_eq!(
+            updated_vault.trigger,
+            Some(TriggerConfiguration::Time {
+                target_time: env.block.time.plus_seconds(60 * 60),
+            })
+        );
+        assert!(events.contains(
+            &EventBuilder::new(
+                vault.id,
+                env.block.clone(),
+                EventData::DcaVaultExecutionRetryScheduled {
+                    retry_count: 1,
+                    target_time: env.block.time.plus_seconds(60 * 60),
+                },
+            )
+            .build(2),
+        ));
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("retry_count", "1")));
+    }
+
+    #[test]
+    fn with_failed_swap_and_max_retries_reached_keeps_regular_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                retry_policy: Some(RetryPolicy {
+                    max_retries: 2,
+                    backoff_seconds: 60 * 60,
+                }),
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let next_target_time = env.block.time.plus_seconds(24 * 60 * 60);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                retry_count: 2,
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: next_target_time,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Err("failed for slippage".to_string()),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.retry_count, 0);
+        assert_eq!(updated_vault.trigger, vault.trigger);
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "retry_count"));
+    }
+
+    #[test]
+    fn with_failed_swap_and_backoff_after_next_trigger_keeps_regular_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                retry_policy: Some(RetryPolicy {
+                    max_retries: 2,
+                    backoff_seconds: 2 * 24 * 60 * 60,
+                }),
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let next_target_time = env.block.time.plus_seconds(24 * 60 * 60);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: next_target_time,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Err("failed for slippage".to_string()),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.retry_count, 0);
+        assert_eq!(updated_vault.trigger, vault.trigger);
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "retry_count"));
+    }
+
+    #[test]
+    fn with_failed_swap_and_no_retry_policy_keeps_regular_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                retry_policy: None,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let next_target_time = env.block.time.plus_seconds(24 * 60 * 60);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: next_target_time,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Err("failed for slippage".to_string()),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.retry_count, 0);
+        assert_eq!(updated_vault.trigger, vault.trigger);
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "retry_count"));
+    }
+
+    #[test]
+    fn with_succcesful_swap_resets_retry_count() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                retry_count: 2,
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: None,
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(vault.swap_amount.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+        assert_eq!(updated_vault.retry_count, 0);
+    }
+
+    #[test]
+    fn with_succcesful_swap_and_keeper_reward_pays_keeper_a_share_of_the_fee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                keeper_reward_percent: Some(Decimal::percent(10)),
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let receive_amount = Uint128::new(234312312);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: Some(Addr::unchecked(KEEPER)),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(receive_amount.into(), vault.target_denom.clone())],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let config = get_config(&deps.storage).unwrap();
+        let swap_fee = config.default_swap_fee_percent * receive_amount;
+        let keeper_reward = swap_fee * Decimal::percent(10);
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: KEEPER.to_string(),
+            amount: vec![Coin::new(keeper_reward.into(), vault.target_denom.clone())]
+        })));
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: config.fee_collectors[0].address.to_string(),
+            amount: vec![Coin::new(
+                (swap_fee - keeper_reward).into(),
+                vault.target_denom
+            )]
+        })));
+    }
+
+    #[test]
+    fn with_succcesful_swap_and_no_keeper_reward_pays_entire_fee_to_fee_collector() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                keeper_reward_percent: None,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let receive_amount = Uint128::new(234312312);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    vault_id: vault.id,
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                    keeper: Some(Addr::unchecked(KEEPER)),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(receive_amount.into(), vault.target_denom.clone())],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let config = get_config(&deps.storage).unwrap();
+        let swap_fee = config.default_swap_fee_percent * receive_amount;
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: config.fee_collectors[0].address.to_string(),
+            amount: vec![Coin::new(swap_fee.into(), vault.target_denom)]
+        })));
+        assert!(!response.messages.iter().any(|message| matches!(
+            &message.msg,
+            CosmosMsg::Bank(BankMsg::Send { to_address, .. }) if to_address == KEEPER
+        )));
+    }
 }
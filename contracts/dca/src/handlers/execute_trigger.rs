@@ -1,31 +1,42 @@
 use crate::constants::AFTER_SWAP_REPLY_ID;
 use crate::error::ContractError;
+use crate::helpers::execution::{
+    get_execution_decision, get_swap_decision, ExecutionDecision, SwapDecision,
+};
 use crate::helpers::price::query_belief_price;
 use crate::helpers::swaps::create_swap_message;
 use crate::helpers::time::get_next_target_time;
-use crate::helpers::validation::{assert_contract_is_not_paused, assert_target_time_is_in_past};
-use crate::helpers::vault::{get_swap_amount, simulate_standard_dca_execution};
+use crate::helpers::validation::assert_contract_is_not_paused;
+use crate::helpers::vault::simulate_standard_dca_execution;
 use crate::msg::ExecuteMsg;
-use crate::state::cache::{SwapCache, SWAP_CACHE, VAULT_CACHE};
+use crate::state::cache::{SwapCache, KEEPER_CACHE, SWAP_CACHE, VAULT_CACHE};
 use crate::state::events::create_event;
 use crate::state::pairs::find_pair;
 use crate::state::triggers::{delete_trigger, save_trigger};
 use crate::state::vaults::{get_vault, update_vault};
 use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
 use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
+use crate::types::termination_condition::TerminationAction;
 use crate::types::trigger::{Trigger, TriggerConfiguration};
 use crate::types::vault::{Vault, VaultStatus};
-use cosmwasm_std::{to_binary, Decimal, ReplyOn, SubMsg, WasmMsg};
+use cosmwasm_std::{to_binary, BankMsg, ReplyOn, SubMsg, WasmMsg};
 #[cfg(not(feature = "library"))]
-use cosmwasm_std::{DepsMut, Env, Response, Uint128};
+use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, Uint128};
 
 pub fn execute_trigger_handler(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     trigger_id: Uint128,
 ) -> Result<Response, ContractError> {
     assert_contract_is_not_paused(deps.storage)?;
 
+    let keeper = if info.sender == env.contract.address {
+        KEEPER_CACHE.may_load(deps.storage)?
+    } else {
+        Some(info.sender)
+    };
+
     let mut vault = get_vault(deps.storage, trigger_id)?;
 
     let mut response = Response::new()
@@ -34,36 +45,40 @@ pub fn execute_trigger_handler(
 
     delete_trigger(deps.storage, vault.id)?;
 
-    if vault.is_cancelled() {
-        return Err(ContractError::CustomError {
-            val: format!(
-                "vault with id {} is cancelled, and is not available for execution",
-                vault.id
-            ),
-        });
-    }
+    let pair = find_pair(deps.storage, vault.denoms())?;
+
+    let belief_price = query_belief_price(&deps.as_ref(), &env, &pair, vault.get_swap_denom())?;
+
+    let decision = get_execution_decision(&env, &vault, &pair, belief_price)?;
+
+    if let ExecutionDecision::OutsideExecutionWindow { next_window_time } = decision {
+        let configuration = match vault.trigger.clone() {
+            Some(price_trigger @ TriggerConfiguration::Price { .. }) => price_trigger,
+            _ => TriggerConfiguration::Time {
+                target_time: next_window_time,
+            },
+        };
+
+        save_trigger(
+            deps.storage,
+            Trigger {
+                vault_id: vault.id,
+                configuration,
+            },
+        )?;
 
-    if vault.trigger.is_none() {
-        return Err(ContractError::CustomError {
-            val: format!(
-                "vault with id {} has no trigger attached, and is not available for execution",
-                vault.id
+        create_event(
+            deps.storage,
+            EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultExecutionSkipped {
+                    reason: ExecutionSkippedReason::OutsideExecutionWindow,
+                },
             ),
-        });
-    }
+        )?;
 
-    match vault.trigger {
-        Some(TriggerConfiguration::Time { target_time }) => {
-            assert_target_time_is_in_past(env.block.time, target_time)?;
-        }
-        _ => {
-            return Err(ContractError::CustomError {
-                val: format!(
-                    "vault with id {} has no time trigger attached, and is not available for execution",
-                    vault.id
-                ),
-            });
-        }
+        return Ok(response.add_attribute("execution_skipped", "outside_execution_window"));
     }
 
     if vault.is_scheduled() {
@@ -76,10 +91,6 @@ pub fn execute_trigger_handler(
 
     update_vault(deps.storage, vault.clone())?;
 
-    let pair = find_pair(deps.storage, vault.denoms())?;
-
-    let belief_price = query_belief_price(&deps.as_ref(), &env, &pair, vault.get_swap_denom())?;
-
     create_event(
         deps.storage,
         EventBuilder::new(
@@ -107,6 +118,54 @@ pub fn execute_trigger_handler(
         )?;
     }
 
+    let mut swap_remaining_balance = false;
+
+    if let ExecutionDecision::Terminate { reason, action } = decision {
+        create_event(
+            deps.storage,
+            EventBuilder::new(
+                vault.id,
+                env.block.to_owned(),
+                EventData::DcaVaultTerminated {
+                    reason: reason.clone(),
+                },
+            ),
+        )?;
+
+        response = response.add_attribute("vault_terminated", format!("{:?}", reason));
+
+        match action {
+            TerminationAction::Refund => {
+                if vault.balance.amount > Uint128::zero() {
+                    response = response.add_message(BankMsg::Send {
+                        to_address: vault.owner.to_string(),
+                        amount: vec![vault.balance.clone()],
+                    });
+                }
+
+                vault = update_vault(
+                    deps.storage,
+                    Vault {
+                        status: VaultStatus::Inactive,
+                        balance: Coin::new(0, vault.get_swap_denom()),
+                        ..vault
+                    },
+                )?;
+            }
+            TerminationAction::Swap => {
+                swap_remaining_balance = true;
+
+                vault = update_vault(
+                    deps.storage,
+                    Vault {
+                        status: VaultStatus::Inactive,
+                        ..vault
+                    },
+                )?;
+            }
+        }
+    }
+
     let should_execute_again = vault.is_active()
         || vault.performance_assessment_strategy.clone().map_or(
             false,
@@ -129,7 +188,7 @@ pub fn execute_trigger_handler(
                 },
             },
         )?;
-    } else {
+    } else if !swap_remaining_balance {
         if vault.should_not_continue() && vault.escrowed_amount.amount > Uint128::zero() {
             response = response.add_submessage(SubMsg::new(WasmMsg::Execute {
                 contract_addr: env.contract.address.to_string(),
@@ -141,42 +200,38 @@ pub fn execute_trigger_handler(
         return Ok(response.add_attribute("execution_skipped", "vault_should_not_continue"));
     }
 
-    if vault.is_inactive() {
+    if vault.is_inactive() && !swap_remaining_balance {
         return Ok(response.add_attribute("execution_skipped", "vault_is_inactive"));
     }
 
-    let adjusted_swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault)?;
-
-    if adjusted_swap_amount.amount.is_zero() {
-        create_event(
-            deps.storage,
-            EventBuilder::new(
-                vault.id,
-                env.block,
-                EventData::DcaVaultExecutionSkipped {
-                    reason: ExecutionSkippedReason::SwapAmountAdjustedToZero,
-                },
-            ),
-        )?;
-
-        return Ok(response.add_attribute("execution_skipped", "swap_amount_adjusted_to_zero"));
-    }
-
-    if vault.price_threshold_exceeded(belief_price)? {
-        create_event(
-            deps.storage,
-            EventBuilder::new(
-                vault.id,
-                env.block,
-                EventData::DcaVaultExecutionSkipped {
-                    reason: ExecutionSkippedReason::PriceThresholdExceeded {
-                        price: belief_price,
-                    },
-                },
-            ),
-        )?;
+    let (swap_amount, minimum_receive_amount) = match get_swap_decision(
+        &deps.as_ref(),
+        &env,
+        &vault,
+        belief_price,
+        swap_remaining_balance,
+    )? {
+        SwapDecision::Skip { reason } => {
+            let attribute = match reason {
+                ExecutionSkippedReason::SwapAmountAdjustedToZero => "swap_amount_adjusted_to_zero",
+                _ => "price_threshold_exceeded",
+            };
+
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    vault.id,
+                    env.block,
+                    EventData::DcaVaultExecutionSkipped { reason },
+                ),
+            )?;
 
-        return Ok(response.add_attribute("execution_skipped", "price_threshold_exceeded"));
+            return Ok(response.add_attribute("execution_skipped", attribute));
+        }
+        SwapDecision::Swap {
+            swap_amount,
+            minimum_receive_amount,
+        } => (swap_amount, minimum_receive_amount),
     };
 
     VAULT_CACHE.save(deps.storage, &vault.id)?;
@@ -184,29 +239,25 @@ pub fn execute_trigger_handler(
     SWAP_CACHE.save(
         deps.storage,
         &SwapCache {
+            vault_id: vault.id,
             swap_denom_balance: deps
                 .querier
                 .query_balance(&env.contract.address, vault.get_swap_denom())?,
             receive_denom_balance: deps
                 .querier
                 .query_balance(&env.contract.address, vault.target_denom)?,
+            keeper,
         },
     )?;
 
-    let adjusted_minimum_receive_amount =
-        vault.minimum_receive_amount.map(|minimum_receive_amount| {
-            Decimal::from_ratio(adjusted_swap_amount.amount, vault.swap_amount)
-                * minimum_receive_amount
-        });
-
     Ok(response.add_submessage(create_swap_message(
         &deps.querier,
         &env,
         &pair,
-        adjusted_swap_amount,
+        swap_amount,
         vault.slippage_tolerance,
         belief_price,
-        adjusted_minimum_receive_amount,
+        minimum_receive_amount,
         Some(AFTER_SWAP_REPLY_ID),
         Some(ReplyOn::Always),
     )?))
@@ -225,16 +276,23 @@ mod execute_trigger_tests {
     use crate::state::triggers::delete_trigger;
     use crate::state::vaults::get_vault;
     use crate::tests::helpers::{instantiate_contract, setup_vault};
-    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO, KEEPER};
     use crate::types::config::Config;
     use crate::types::event::{Event, EventData, ExecutionSkippedReason};
+    use crate::types::execution_window::ExecutionWindow;
     use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategy;
     use crate::types::position_type::PositionType;
     use crate::types::swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy};
-    use crate::types::trigger::TriggerConfiguration;
+    use crate::types::termination_condition::{
+        TerminationAction, TerminationCondition, TerminationReason,
+    };
+    use crate::types::time_interval::DayOfWeek;
+    use crate::types::trigger::{PriceDirection, TriggerConfiguration};
     use crate::types::vault::{Vault, VaultStatus};
     use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{to_binary, Coin, Decimal, ReplyOn, StdError, SubMsg, Uint128, WasmMsg};
+    use cosmwasm_std::{
+        to_binary, Addr, Coin, Decimal, ReplyOn, StdError, SubMsg, Timestamp, Uint128, WasmMsg,
+    };
     use osmosis_std::types::osmosis::poolmanager::v1beta1::{
         EstimateSwapExactAmountInResponse, MsgSwapExactAmountIn, SwapAmountInRoute,
     };
@@ -260,7 +318,8 @@ mod execute_trigger_tests {
         )
         .unwrap();
 
-        let err = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap_err();
+        let err = execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id)
+            .unwrap_err();
 
         assert_eq!(err.to_string(), "Error: contract is paused");
     }
@@ -282,7 +341,8 @@ mod execute_trigger_tests {
             },
         );
 
-        let err = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap_err();
+        let err = execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id)
+            .unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -307,7 +367,7 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap_err();
+        execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap_err();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -334,7 +394,8 @@ mod execute_trigger_tests {
 
         delete_trigger(deps.as_mut().storage, vault.id).unwrap();
 
-        let err = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap_err();
+        let err = execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id)
+            .unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -354,7 +415,8 @@ mod execute_trigger_tests {
 
         env.block.time = env.block.time.minus_seconds(10);
 
-        let err = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap_err();
+        let err = execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id)
+            .unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -362,6 +424,79 @@ mod execute_trigger_tests {
         );
     }
 
+    #[test]
+    fn when_target_price_is_not_reached_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(50),
+                    direction: PriceDirection::Below,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let err = execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: trigger target price 0.5 has not yet been reached (current price {})",
+                Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn when_target_price_is_reached_should_replace_price_trigger_with_time_trigger() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(50),
+                    direction: PriceDirection::Above,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Active);
+        assert_eq!(
+            updated_vault.trigger,
+            Some(TriggerConfiguration::Time {
+                target_time: Timestamp::from_seconds(env.block.time.seconds() + 24 * 60 * 60)
+            })
+        );
+        assert!(response
+            .messages
+            .iter()
+            .any(|message| message.id == AFTER_SWAP_REPLY_ID));
+    }
+
     #[test]
     fn should_make_scheduled_vault_active() {
         let mut deps = calc_mock_dependencies();
@@ -379,7 +514,7 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -404,7 +539,8 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -422,7 +558,8 @@ mod execute_trigger_tests {
 
         let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
             .unwrap()
@@ -461,7 +598,7 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -524,7 +661,7 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -564,7 +701,8 @@ mod execute_trigger_tests {
             _ => Err(StdError::generic_err("message not customised")),
         });
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
             .unwrap()
@@ -611,7 +749,8 @@ mod execute_trigger_tests {
             _ => Err(StdError::generic_err("message not customised")),
         });
 
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+        let response =
+            execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
 
         assert!(response.messages.is_empty());
     }
@@ -654,7 +793,9 @@ mod execute_trigger_tests {
                 .unwrap();
             });
 
-        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
 
         let token_out_min_amount = vault.swap_amount
             * swap_adjustment
@@ -712,7 +853,7 @@ mod execute_trigger_tests {
             _ => Err(StdError::generic_err("message not supported")),
         });
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -743,7 +884,8 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
             .unwrap()
@@ -791,7 +933,8 @@ mod execute_trigger_tests {
             _ => Err(StdError::generic_err("message not supported")),
         });
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
             .unwrap()
@@ -829,7 +972,7 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -903,7 +1046,9 @@ mod execute_trigger_tests {
             _ => Err(StdError::generic_err("message not supported")),
         });
 
-        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
 
         assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
             contract_addr: env.contract.address.to_string(),
@@ -940,7 +1085,8 @@ mod execute_trigger_tests {
             },
         );
 
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+        let response =
+            execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
 
         assert!(response.messages.is_empty());
     }
@@ -955,16 +1101,19 @@ mod execute_trigger_tests {
 
         let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
         let old_target_time = match vault.trigger.unwrap() {
             TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("expected a time trigger"),
         };
 
         let new_target_time = match updated_vault.trigger.unwrap() {
             TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("expected a time trigger"),
         };
 
         assert_eq!(old_target_time.seconds(), env.block.time.seconds());
@@ -991,16 +1140,19 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
         let old_target_time = match vault.trigger.unwrap() {
             TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("expected a time trigger"),
         };
 
         let new_target_time = match updated_vault.trigger.unwrap() {
             TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("expected a time trigger"),
         };
 
         assert_eq!(old_target_time.seconds(), env.block.time.seconds());
@@ -1027,7 +1179,7 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -1071,16 +1223,19 @@ mod execute_trigger_tests {
             _ => Err(StdError::generic_err("message not supported")),
         });
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
         let old_target_time = match vault.trigger.unwrap() {
             TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("expected a time trigger"),
         };
 
         let new_target_time = match updated_vault.trigger.unwrap() {
             TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("expected a time trigger"),
         };
 
         assert_eq!(old_target_time.seconds(), env.block.time.seconds());
@@ -1117,7 +1272,7 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -1135,7 +1290,9 @@ mod execute_trigger_tests {
 
         let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
 
-        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
 
         let token_out_min_amount = vault.swap_amount
             * (Decimal::one() - vault.slippage_tolerance)
@@ -1176,7 +1333,9 @@ mod execute_trigger_tests {
             },
         );
 
-        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
 
         let token_out_min_amount = vault.balance.amount
             * (Decimal::one() - vault.slippage_tolerance)
@@ -1218,7 +1377,9 @@ mod execute_trigger_tests {
 
         let belief_price = Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap();
 
-        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
 
         let token_out_min_amount = get_swap_amount(&deps.as_ref(), &env, &vault)
             .unwrap()
@@ -1265,7 +1426,8 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
             .unwrap()
@@ -1302,16 +1464,19 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
         let old_target_time = match vault.trigger.unwrap() {
             TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("expected a time trigger"),
         };
 
         let new_target_time = match updated_vault.trigger.unwrap() {
             TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("expected a time trigger"),
         };
 
         assert_eq!(old_target_time.seconds(), env.block.time.seconds());
@@ -1339,7 +1504,9 @@ mod execute_trigger_tests {
             },
         );
 
-        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
 
         let token_out_min_amount = vault.swap_amount
             * (Decimal::one() - vault.slippage_tolerance)
@@ -1361,4 +1528,582 @@ mod execute_trigger_tests {
             reply_on: ReplyOn::Always,
         }))
     }
+
+    #[test]
+    fn when_stop_loss_is_reached_should_publish_vault_terminated_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                stop_loss: Some(TerminationCondition {
+                    price: Decimal::percent(110),
+                    action: TerminationAction::Refund,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultTerminated {
+                reason: TerminationReason::StopLoss {
+                    price: Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap()
+                }
+            }
+        }));
+    }
+
+    #[test]
+    fn when_stop_loss_is_reached_with_refund_action_should_refund_balance_and_make_vault_inactive()
+    {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                stop_loss: Some(TerminationCondition {
+                    price: Decimal::percent(110),
+                    action: TerminationAction::Refund,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![vault.balance.clone()],
+            })]
+        );
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.balance, Coin::new(0, vault.get_swap_denom()));
+        assert_eq!(updated_vault.trigger, None);
+    }
+
+    #[test]
+    fn when_take_profit_is_reached_with_swap_action_should_swap_entire_balance() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                minimum_receive_amount: Some(TEN + TEN),
+                take_profit: Some(TerminationCondition {
+                    price: Decimal::percent(90),
+                    action: TerminationAction::Swap,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
+
+        let token_out_min_amount = Decimal::from_ratio(vault.balance.amount, vault.swap_amount)
+            * vault.minimum_receive_amount.unwrap();
+
+        assert!(response.messages.contains(&SubMsg {
+            id: AFTER_SWAP_REPLY_ID,
+            msg: MsgSwapExactAmountIn {
+                sender: env.contract.address.to_string(),
+                token_in: Some(vault.balance.clone().into()),
+                token_out_min_amount: token_out_min_amount.to_string(),
+                routes: vec![SwapAmountInRoute {
+                    pool_id: 3,
+                    token_out_denom: vault.target_denom,
+                }],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: ReplyOn::Always,
+        }))
+    }
+
+    #[test]
+    fn when_take_profit_is_reached_with_swap_action_should_make_vault_inactive() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                take_profit: Some(TerminationCondition {
+                    price: Decimal::percent(90),
+                    action: TerminationAction::Swap,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert!(response
+            .messages
+            .iter()
+            .any(|message| message.id == AFTER_SWAP_REPLY_ID));
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.trigger, None);
+    }
+
+    #[test]
+    fn when_termination_conditions_are_not_reached_should_create_regular_swap_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                stop_loss: Some(TerminationCondition {
+                    price: Decimal::percent(50),
+                    action: TerminationAction::Refund,
+                }),
+                take_profit: Some(TerminationCondition {
+                    price: Decimal::percent(150),
+                    action: TerminationAction::Swap,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert!(response
+            .messages
+            .iter()
+            .any(|message| message.id == AFTER_SWAP_REPLY_ID));
+        assert_eq!(updated_vault.status, VaultStatus::Active);
+        assert_eq!(updated_vault.balance, vault.balance);
+    }
+
+    #[test]
+    fn when_end_time_is_reached_should_refund_balance_and_make_vault_inactive() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                end_time: Some(env.block.time),
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![vault.balance.clone()],
+            })]
+        );
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultTerminated {
+                reason: TerminationReason::EndTimeReached {
+                    end_time: env.block.time
+                }
+            }
+        }));
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.balance, Coin::new(0, vault.get_swap_denom()));
+        assert_eq!(updated_vault.trigger, None);
+    }
+
+    #[test]
+    fn when_max_executions_is_reached_should_refund_balance_and_make_vault_inactive() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                max_executions: Some(2),
+                execution_count: 2,
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![vault.balance.clone()],
+            })]
+        );
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.balance, Coin::new(0, vault.get_swap_denom()));
+        assert_eq!(updated_vault.trigger, None);
+    }
+
+    #[test]
+    fn when_end_time_is_reached_with_escrowed_funds_should_disburse_escrow() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                end_time: Some(env.block.time),
+                escrowed_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id }).unwrap(),
+            funds: vec![],
+        })));
+    }
+
+    #[test]
+    fn when_end_time_is_not_reached_should_create_regular_swap_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                end_time: Some(env.block.time.plus_seconds(60)),
+                max_executions: Some(2),
+                execution_count: 1,
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap();
+
+        assert!(response
+            .messages
+            .iter()
+            .any(|message| message.id == AFTER_SWAP_REPLY_ID));
+    }
+
+    #[test]
+    fn when_outside_execution_window_should_publish_execution_skipped_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                execution_window: Some(ExecutionWindow {
+                    start_hour: 8,
+                    end_hour: 20,
+                    excluded_days: vec![],
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(response.messages.is_empty());
+        assert!(events.contains(&Event {
+            id: 1,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::OutsideExecutionWindow
+            }
+        }));
+    }
+
+    #[test]
+    fn when_outside_execution_window_should_reschedule_to_start_of_window() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                execution_window: Some(ExecutionWindow {
+                    start_hour: 8,
+                    end_hour: 20,
+                    excluded_days: vec![],
+                }),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.trigger,
+            Some(TriggerConfiguration::Time {
+                target_time: Timestamp::from_seconds(1571817600) // 2019-10-23T08:00:00Z
+            })
+        );
+        assert_eq!(updated_vault.balance, vault.balance);
+    }
+
+    #[test]
+    fn when_on_excluded_day_should_reschedule_to_next_allowed_day() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                execution_window: Some(ExecutionWindow {
+                    start_hour: 0,
+                    end_hour: 24,
+                    excluded_days: vec![DayOfWeek::Wednesday],
+                }),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.trigger,
+            Some(TriggerConfiguration::Time {
+                target_time: Timestamp::from_seconds(1571875200) // 2019-10-24T00:00:00Z
+            })
+        );
+    }
+
+    #[test]
+    fn when_outside_execution_window_should_keep_price_trigger() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let price_trigger = TriggerConfiguration::Price {
+            target_price: Decimal::percent(200),
+            direction: PriceDirection::Below,
+        };
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                trigger: Some(price_trigger.clone()),
+                execution_window: Some(ExecutionWindow {
+                    start_hour: 8,
+                    end_hour: 20,
+                    excluded_days: vec![],
+                }),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.trigger, Some(price_trigger));
+        assert_eq!(updated_vault.status, VaultStatus::Scheduled);
+    }
+
+    #[test]
+    fn when_inside_execution_window_spanning_midnight_should_swap() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                execution_window: Some(ExecutionWindow {
+                    start_hour: 22,
+                    end_hour: 6,
+                    excluded_days: vec![DayOfWeek::Saturday, DayOfWeek::Sunday],
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response =
+            execute_trigger_handler(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]), vault.id)
+                .unwrap();
+
+        assert!(response
+            .messages
+            .iter()
+            .any(|message| message.id == AFTER_SWAP_REPLY_ID));
+    }
+
+    #[test]
+    fn should_cache_sender_as_keeper() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        execute_trigger_handler(deps.as_mut(), env, mock_info(KEEPER, &[]), vault.id).unwrap();
+
+        let swap_cache = SWAP_CACHE.load(deps.as_ref().storage).unwrap();
+
+        assert_eq!(swap_cache.keeper, Some(Addr::unchecked(KEEPER)));
+    }
+
+    #[test]
+    fn when_sent_by_the_contract_should_cache_execute_triggers_sender_as_keeper() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        KEEPER_CACHE
+            .save(deps.as_mut().storage, &Addr::unchecked(KEEPER))
+            .unwrap();
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(env.contract.address.as_ref(), &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        let swap_cache = SWAP_CACHE.load(deps.as_ref().storage).unwrap();
+
+        assert_eq!(swap_cache.keeper, Some(Addr::unchecked(KEEPER)));
+    }
+
+    #[test]
+    fn when_sent_by_the_contract_outside_execute_triggers_should_cache_no_keeper() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(env.contract.address.as_ref(), &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        let swap_cache = SWAP_CACHE.load(deps.as_ref().storage).unwrap();
+
+        assert_eq!(swap_cache.keeper, None);
+    }
 }
@@ -0,0 +1,281 @@
+use crate::{
+    constants::AFTER_EXECUTE_TRIGGER_REPLY_ID,
+    error::ContractError,
+    helpers::validation::{
+        assert_contract_is_not_paused, assert_trigger_ids_limit_is_not_breached,
+    },
+    msg::ExecuteMsg,
+    state::cache::{EXECUTE_TRIGGERS_CACHE, KEEPER_CACHE},
+};
+use cosmwasm_std::{
+    to_binary, DepsMut, Env, MessageInfo, Reply, Response, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use std::collections::VecDeque;
+
+pub fn execute_triggers_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    trigger_ids: Vec<Uint128>,
+) -> Result<Response, ContractError> {
+    assert_contract_is_not_paused(deps.storage)?;
+    assert_trigger_ids_limit_is_not_breached(deps.storage, &trigger_ids)?;
+
+    EXECUTE_TRIGGERS_CACHE.save(
+        deps.storage,
+        &trigger_ids.iter().copied().collect::<VecDeque<Uint128>>(),
+    )?;
+
+    KEEPER_CACHE.save(deps.storage, &info.sender)?;
+
+    let sub_msgs = trigger_ids
+        .iter()
+        .map(|trigger_id| {
+            Ok(SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_binary(&ExecuteMsg::ExecuteTrigger {
+                        trigger_id: *trigger_id,
+                    })?,
+                    funds: vec![],
+                },
+                AFTER_EXECUTE_TRIGGER_REPLY_ID,
+            ))
+        })
+        .collect::<Result<Vec<SubMsg>, ContractError>>()?;
+
+    Ok(Response::new()
+        .add_attribute("execute_triggers", "true")
+        .add_attribute("trigger_count", trigger_ids.len().to_string())
+        .add_submessages(sub_msgs))
+}
+
+pub fn log_execute_trigger_result(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
+    let mut trigger_ids = EXECUTE_TRIGGERS_CACHE.load(deps.storage)?;
+
+    let trigger_id = trigger_ids
+        .pop_front()
+        .ok_or(ContractError::NoPendingTriggerId {})?;
+
+    if trigger_ids.is_empty() {
+        EXECUTE_TRIGGERS_CACHE.remove(deps.storage);
+        KEEPER_CACHE.remove(deps.storage);
+    } else {
+        EXECUTE_TRIGGERS_CACHE.save(deps.storage, &trigger_ids)?;
+    }
+
+    let response = Response::new().add_attribute("trigger_id", trigger_id);
+
+    Ok(match reply.result {
+        SubMsgResult::Ok(_) => response.add_attribute("execute_trigger_result", "success"),
+        SubMsgResult::Err(error) => response
+            .add_attribute("execute_trigger_result", "failure")
+            .add_attribute("execute_trigger_error", error),
+    })
+}
+
+#[cfg(test)]
+mod execute_triggers_tests {
+    use super::*;
+    use crate::{
+        state::config::{get_config, update_config},
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{calc_mock_dependencies, ADMIN, KEEPER},
+        },
+        types::config::Config,
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Addr, Attribute, SubMsgResponse,
+    };
+
+    #[test]
+    fn when_contract_is_paused_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                paused: true,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let err = execute_triggers_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vec![Uint128::one()],
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: contract is paused");
+    }
+
+    #[test]
+    fn with_no_trigger_ids_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = execute_triggers_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vec![])
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: at least one trigger id must be provided"
+        );
+    }
+
+    #[test]
+    fn with_more_trigger_ids_than_the_page_limit_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let limit = get_config(deps.as_ref().storage)
+            .unwrap()
+            .default_page_limit;
+
+        let err = execute_triggers_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            (0..=limit).map(|id| Uint128::new(id.into())).collect(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: no more than {} trigger ids can be provided", limit)
+        );
+    }
+
+    #[test]
+    fn should_execute_each_trigger_in_an_isolated_sub_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let trigger_ids = vec![Uint128::new(1), Uint128::new(2)];
+
+        let response = execute_triggers_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            trigger_ids.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            trigger_ids
+                .iter()
+                .map(|trigger_id| SubMsg::reply_always(
+                    WasmMsg::Execute {
+                        contract_addr: env.contract.address.to_string(),
+                        msg: to_binary(&ExecuteMsg::ExecuteTrigger {
+                            trigger_id: *trigger_id
+                        })
+                        .unwrap(),
+                        funds: vec![],
+                    },
+                    AFTER_EXECUTE_TRIGGER_REPLY_ID
+                ))
+                .collect::<Vec<SubMsg>>()
+        );
+    }
+
+    #[test]
+    fn should_log_each_trigger_result_against_its_trigger_id() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        execute_triggers_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vec![Uint128::new(1), Uint128::new(2)],
+        )
+        .unwrap();
+
+        let first_response = log_execute_trigger_result(
+            deps.as_mut(),
+            Reply {
+                id: AFTER_EXECUTE_TRIGGER_REPLY_ID,
+                result: SubMsgResult::Err("trigger execution time has not yet elapsed".to_string()),
+            },
+        )
+        .unwrap();
+
+        let second_response = log_execute_trigger_result(
+            deps.as_mut(),
+            Reply {
+                id: AFTER_EXECUTE_TRIGGER_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            first_response.attributes,
+            vec![
+                Attribute::new("trigger_id", "1"),
+                Attribute::new("execute_trigger_result", "failure"),
+                Attribute::new(
+                    "execute_trigger_error",
+                    "trigger execution time has not yet elapsed"
+                ),
+            ]
+        );
+        assert_eq!(
+            second_response.attributes,
+            vec![
+                Attribute::new("trigger_id", "2"),
+                Attribute::new("execute_trigger_result", "success"),
+            ]
+        );
+        assert!(EXECUTE_TRIGGERS_CACHE
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+        assert!(KEEPER_CACHE
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn should_cache_sender_as_keeper() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        execute_triggers_handler(
+            deps.as_mut(),
+            env,
+            mock_info(KEEPER, &[]),
+            vec![Uint128::new(1)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            KEEPER_CACHE.load(deps.as_ref().storage).unwrap(),
+            Addr::unchecked(KEEPER)
+        );
+    }
+}
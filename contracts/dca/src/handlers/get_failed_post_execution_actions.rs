@@ -0,0 +1,14 @@
+use crate::{
+    msg::FailedPostExecutionActionsResponse,
+    state::post_execution_actions::get_failed_post_execution_actions,
+};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_failed_post_execution_actions_handler(
+    deps: Deps,
+    vault_id: Uint128,
+) -> StdResult<FailedPostExecutionActionsResponse> {
+    Ok(FailedPostExecutionActionsResponse {
+        actions: get_failed_post_execution_actions(deps.storage, vault_id)?,
+    })
+}
@@ -44,6 +44,10 @@ mod get_pairs_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route.clone(),
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -0,0 +1,224 @@
+use crate::{
+    helpers::{price::query_belief_price, validation::assert_page_limit_is_valid},
+    msg::PriceTriggerIdsResponse,
+    state::{pairs::find_pair, triggers::get_price_triggers, vaults::get_vault},
+    types::trigger::{Trigger, TriggerConfiguration},
+};
+use cosmwasm_std::{Deps, Env, StdResult, Uint128};
+
+pub fn get_price_trigger_ids_handler(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u128>,
+    limit: Option<u16>,
+) -> StdResult<PriceTriggerIdsResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    let triggers = get_price_triggers(deps.storage, start_after.map(Uint128::from), limit)?;
+
+    let mut trigger_ids = Vec::<Uint128>::new();
+
+    for trigger in triggers.iter() {
+        // a vault whose price cannot be checked, e.g. because its pair was deleted, is reported
+        // as not reached so that it does not hide the other due triggers on the page
+        if target_price_is_reached(deps, &env, trigger).unwrap_or(false) {
+            trigger_ids.push(trigger.vault_id);
+        }
+    }
+
+    Ok(PriceTriggerIdsResponse {
+        trigger_ids,
+        last_checked_trigger_id: triggers.last().map(|trigger| trigger.vault_id),
+    })
+}
+
+fn target_price_is_reached(deps: Deps, env: &Env, trigger: &Trigger) -> StdResult<bool> {
+    match &trigger.configuration {
+        TriggerConfiguration::Price {
+            target_price,
+            direction,
+        } => {
+            let vault = get_vault(deps.storage, trigger.vault_id)?;
+            let pair = find_pair(deps.storage, vault.denoms())?;
+            let belief_price = query_belief_price(&deps, env, &pair, vault.get_swap_denom())?;
+
+            Ok(direction.is_reached(*target_price, belief_price))
+        }
+        TriggerConfiguration::Time { .. } => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod get_price_trigger_ids_tests {
+    use super::get_price_trigger_ids_handler;
+    use crate::{
+        state::pairs::delete_pair,
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{calc_mock_dependencies, ADMIN},
+        },
+        types::{
+            trigger::{PriceDirection, TriggerConfiguration},
+            vault::{Vault, VaultStatus},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Decimal, Timestamp, Uint128,
+    };
+
+    #[test]
+    fn returns_vault_ids_with_reached_target_prices() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let reached_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    direction: PriceDirection::Below,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    direction: PriceDirection::Above,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_price_trigger_ids_handler(deps.as_ref(), env, None, None).unwrap();
+
+        assert_eq!(response.trigger_ids, vec![reached_vault.id]);
+    }
+
+    #[test]
+    fn skips_vaults_whose_price_cannot_be_checked() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let broken_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                target_denom: "uatom".to_string(),
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    direction: PriceDirection::Below,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        delete_pair(deps.as_mut().storage, broken_vault.denoms());
+
+        let reached_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    direction: PriceDirection::Below,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_price_trigger_ids_handler(deps.as_ref(), env, None, None).unwrap();
+
+        assert_eq!(response.trigger_ids, vec![reached_vault.id]);
+        assert_eq!(response.last_checked_trigger_id, Some(reached_vault.id));
+    }
+
+    #[test]
+    fn does_not_return_vault_ids_with_time_triggers() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: Timestamp::from_seconds(0),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_price_trigger_ids_handler(deps.as_ref(), env, None, None).unwrap();
+
+        assert!(response.trigger_ids.is_empty());
+    }
+
+    #[test]
+    fn pages_through_price_triggers() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vaults = (0..31)
+            .map(|i| {
+                setup_vault(
+                    deps.as_mut(),
+                    env.clone(),
+                    Vault {
+                        status: VaultStatus::Scheduled,
+                        trigger: Some(TriggerConfiguration::Price {
+                            target_price: Decimal::percent(200),
+                            direction: if i == 0 {
+                                PriceDirection::Above
+                            } else {
+                                PriceDirection::Below
+                            },
+                        }),
+                        ..Vault::default()
+                    },
+                )
+            })
+            .collect::<Vec<Vault>>();
+
+        let first_page =
+            get_price_trigger_ids_handler(deps.as_ref(), env.clone(), None, Some(30)).unwrap();
+
+        assert_eq!(
+            first_page.trigger_ids,
+            vaults[1..30]
+                .iter()
+                .map(|vault| vault.id)
+                .collect::<Vec<Uint128>>()
+        );
+        assert_eq!(first_page.last_checked_trigger_id, Some(vaults[29].id));
+
+        let second_page = get_price_trigger_ids_handler(
+            deps.as_ref(),
+            env,
+            first_page.last_checked_trigger_id.map(u128::from),
+            Some(30),
+        )
+        .unwrap();
+
+        assert_eq!(second_page.trigger_ids, vec![vaults[30].id]);
+        assert_eq!(second_page.last_checked_trigger_id, Some(vaults[30].id));
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{
+    helpers::{
+        time::{get_next_target_time, get_total_execution_duration},
+        validation::assert_page_limit_is_valid,
+    },
+    msg::VaultScheduleResponse,
+    state::{config::get_config, vaults::get_vault},
+    types::{
+        performance_assessment_strategy::PerformanceAssessmentStrategy,
+        trigger::TriggerConfiguration,
+    },
+};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+use std::cmp::{max, min};
+
+pub fn get_vault_schedule_handler(
+    deps: Deps,
+    vault_id: Uint128,
+    limit: Option<u16>,
+) -> StdResult<VaultScheduleResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    let limit = limit.unwrap_or(get_config(deps.storage)?.default_page_limit);
+
+    let vault = get_vault(deps.storage, vault_id)?;
+
+    let remaining_balance = match vault.performance_assessment_strategy.clone() {
+        Some(PerformanceAssessmentStrategy::CompareToStandardDca { swapped_amount, .. }) => max(
+            vault
+                .deposited_amount
+                .amount
+                .saturating_sub(swapped_amount.amount),
+            vault.balance.amount,
+        ),
+        _ => vault.balance.amount,
+    };
+
+    let mut remaining_swaps = remaining_balance
+        .checked_add(vault.swap_amount - Uint128::one())?
+        .checked_div(vault.swap_amount)?;
+
+    if let Some(max_executions) = vault.max_executions {
+        remaining_swaps = min(
+            remaining_swaps,
+            Uint128::from(max_executions.saturating_sub(vault.execution_count)),
+        );
+    }
+
+    let first_execution_time = match vault.trigger {
+        Some(TriggerConfiguration::Time { target_time }) => Some(target_time),
+        _ => None,
+    };
+
+    let mut completes_at_end_time = false;
+
+    if let (Some(first_execution_time), Some(end_time)) = (first_execution_time, vault.end_time) {
+        let started_at = vault.started_at.unwrap_or(first_execution_time);
+
+        let mut execution_time = first_execution_time;
+        let mut swaps_before_end_time = Uint128::zero();
+
+        while execution_time < end_time && swaps_before_end_time < remaining_swaps {
+            swaps_before_end_time += Uint128::one();
+            execution_time =
+                get_next_target_time(execution_time, started_at, vault.time_interval.clone());
+        }
+
+        completes_at_end_time = swaps_before_end_time < remaining_swaps;
+        remaining_swaps = swaps_before_end_time;
+    }
+
+    let first_execution_time = match first_execution_time {
+        Some(first_execution_time) if !remaining_swaps.is_zero() => first_execution_time,
+        _ => {
+            return Ok(VaultScheduleResponse {
+                next_execution_times: vec![],
+                expected_completion_date: None,
+                remaining_swaps,
+            })
+        }
+    };
+
+    let started_at = vault.started_at.unwrap_or(first_execution_time);
+
+    let mut next_execution_times = vec![first_execution_time];
+
+    while next_execution_times.len() < min(limit as u128, remaining_swaps.u128()) as usize {
+        next_execution_times.push(get_next_target_time(
+            *next_execution_times.last().unwrap(),
+            started_at,
+            vault.time_interval.clone(),
+        ));
+    }
+
+    let expected_completion_date = first_execution_time.plus_seconds(
+        get_total_execution_duration(
+            first_execution_time,
+            remaining_swaps.u128() - 1,
+            &vault.time_interval,
+        )
+        .num_seconds()
+        .try_into()
+        .expect("expected duration should be >= 0 seconds"),
+    );
+
+    Ok(VaultScheduleResponse {
+        next_execution_times,
+        expected_completion_date: Some(match vault.end_time {
+            Some(end_time) if completes_at_end_time => end_time,
+            _ => expected_completion_date,
+        }),
+        remaining_swaps,
+    })
+}
+
+#[cfg(test)]
+mod get_vault_schedule_tests {
+    use super::get_vault_schedule_handler;
+    use crate::{
+        constants::{ONE, TEN},
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO},
+        },
+        types::{
+            performance_assessment_strategy::PerformanceAssessmentStrategy,
+            time_interval::TimeInterval,
+            trigger::{PriceDirection, TriggerConfiguration},
+            vault::Vault,
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Coin, Decimal, Timestamp, Uint128,
+    };
+
+    #[test]
+    fn with_limit_too_small_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        let err = get_vault_schedule_handler(deps.as_ref(), vault.id, Some(10)).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: limit cannot be less than 30."
+        );
+    }
+
+    #[test]
+    fn returns_daily_execution_times_and_completion_date() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let now = Timestamp::from_seconds(env.block.time.seconds());
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                started_at: Some(now),
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: now.plus_seconds(24 * 60 * 60),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        assert_eq!(response.remaining_swaps, Uint128::new(10));
+        assert_eq!(
+            response.next_execution_times,
+            (1..=10)
+                .map(|day| now.plus_seconds(day * 24 * 60 * 60))
+                .collect::<Vec<Timestamp>>()
+        );
+        assert_eq!(
+            response.expected_completion_date,
+            Some(now.plus_seconds(10 * 24 * 60 * 60))
+        );
+    }
+
+    #[test]
+    fn counts_partial_swap_as_remaining_swap() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new((TEN + ONE / Uint128::new(2)).into(), DENOM_UOSMO),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        assert_eq!(response.remaining_swaps, Uint128::new(11));
+        assert_eq!(response.next_execution_times.len(), 11);
+    }
+
+    #[test]
+    fn returns_no_more_execution_times_than_the_limit() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let now = Timestamp::from_seconds(env.block.time.seconds());
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new((ONE * Uint128::new(100)).into(), DENOM_UOSMO),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, Some(30)).unwrap();
+
+        assert_eq!(response.remaining_swaps, Uint128::new(100));
+        assert_eq!(response.next_execution_times.len(), 30);
+        assert_eq!(
+            response.expected_completion_date,
+            Some(now.plus_seconds(99 * 24 * 60 * 60))
+        );
+    }
+
+    #[test]
+    fn follows_uneven_monthly_periods() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let started_at = Timestamp::from_seconds(1672531200); // 2023-01-01T00:00:00Z
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new((ONE * Uint128::new(3)).into(), DENOM_UOSMO),
+                time_interval: TimeInterval::Monthly,
+                started_at: Some(started_at),
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: Timestamp::from_seconds(1675209600), // 2023-02-01T00:00:00Z
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        let expected_execution_times = vec![
+            Timestamp::from_seconds(1675209600), // 2023-02-01T00:00:00Z
+            Timestamp::from_seconds(1677628800), // 2023-03-01T00:00:00Z
+            Timestamp::from_seconds(1680307200), // 2023-04-01T00:00:00Z
+        ];
+
+        assert_eq!(response.next_execution_times, expected_execution_times);
+        assert_eq!(
+            response.expected_completion_date,
+            Some(Timestamp::from_seconds(1680307200))
+        );
+    }
+
+    #[test]
+    fn includes_standard_dca_balance_in_remaining_swaps() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new((ONE + ONE).into(), DENOM_UOSMO),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new((TEN - ONE - ONE - ONE).into(), DENOM_UOSMO),
+                        received_amount: Coin::new((TEN - ONE - ONE - ONE).into(), DENOM_STAKE),
+                    },
+                ),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        assert_eq!(response.remaining_swaps, Uint128::new(3));
+    }
+
+    #[test]
+    fn with_price_trigger_returns_no_execution_times() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    direction: PriceDirection::Below,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        assert_eq!(response.remaining_swaps, Uint128::new(10));
+        assert!(response.next_execution_times.is_empty());
+        assert_eq!(response.expected_completion_date, None);
+    }
+
+    #[test]
+    fn with_empty_vault_returns_no_execution_times() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                balance: Coin::new(0, DENOM_UOSMO),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        assert_eq!(response.remaining_swaps, Uint128::zero());
+        assert!(response.next_execution_times.is_empty());
+        assert_eq!(response.expected_completion_date, None);
+    }
+
+    #[test]
+    fn limits_remaining_swaps_to_remaining_max_executions() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                max_executions: Some(5),
+                execution_count: 2,
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        assert_eq!(response.remaining_swaps, Uint128::new(3));
+        assert_eq!(response.next_execution_times.len(), 3);
+    }
+
+    #[test]
+    fn excludes_execution_times_after_end_time() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let now = Timestamp::from_seconds(env.block.time.seconds());
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let end_time = now.plus_seconds(3 * 24 * 60 * 60);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                started_at: Some(now),
+                end_time: Some(end_time),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        assert_eq!(
+            response.next_execution_times,
+            (0..3)
+                .map(|day| now.plus_seconds(day * 24 * 60 * 60))
+                .collect::<Vec<Timestamp>>()
+        );
+        assert_eq!(response.expected_completion_date, Some(end_time));
+    }
+
+    #[test]
+    fn limits_remaining_swaps_to_swaps_before_end_time() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let now = Timestamp::from_seconds(env.block.time.seconds());
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                started_at: Some(now),
+                end_time: Some(now.plus_seconds(3 * 24 * 60 * 60)),
+                max_executions: Some(5),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        assert_eq!(response.remaining_swaps, Uint128::new(3));
+    }
+
+    #[test]
+    fn limits_remaining_swaps_to_balance_before_end_time_and_max_executions() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let now = Timestamp::from_seconds(env.block.time.seconds());
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                started_at: Some(now),
+                balance: Coin::new((ONE + ONE).into(), DENOM_UOSMO),
+                end_time: Some(now.plus_seconds(3 * 24 * 60 * 60)),
+                max_executions: Some(5),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_schedule_handler(deps.as_ref(), vault.id, None).unwrap();
+
+        assert_eq!(response.remaining_swaps, Uint128::new(2));
+        assert_eq!(
+            response.expected_completion_date,
+            Some(now.plus_seconds(24 * 60 * 60))
+        );
+    }
+}
@@ -3,11 +3,20 @@ use crate::{
     state::{
         cache::{POST_EXECUTION_ACTION_CACHE, VAULT_CACHE},
         events::create_event,
+        ibc_transfers::{save_ibc_transfer, IbcTransfer},
+        post_execution_actions::add_failed_post_execution_action,
         vaults::get_vault,
     },
-    types::event::{EventBuilder, EventData},
+    types::{
+        event::{EventBuilder, EventData},
+        ibc_transfer::{MsgTransfer, MsgTransferResponse},
+        post_execution_action::FailedPostExecutionAction,
+    },
+};
+use cosmwasm_std::{
+    BankMsg, CosmosMsg, DepsMut, Env, Reply, Response, StdError, StdResult, SubMsg, SubMsgResult,
 };
-use cosmwasm_std::{BankMsg, DepsMut, Env, Reply, Response, SubMsg, SubMsgResult};
+use prost::Message;
 
 pub fn handle_failed_automation_handler(
     deps: DepsMut,
@@ -24,6 +33,37 @@ pub fn handle_failed_automation_handler(
     let destination_num = vault.destinations.len() - cache.len();
 
     Ok(match reply.result {
+        SubMsgResult::Ok(_) if is_ibc_transfer(&entry.msg) => {
+            let channel_id = get_ibc_transfer_channel_id(&entry.msg)?;
+
+            let sequence = match MsgTransferResponse::try_from(reply.result) {
+                Ok(response) => response.sequence,
+                Err(err) => {
+                    // the transfer has already left the contract, so refunding or retrying it
+                    // here would pay out the funds twice
+                    return Ok(Response::new()
+                        .add_attribute(format!("destination_msg_{}", destination_num), "untracked")
+                        .add_attribute("ibc_channel_id", channel_id)
+                        .add_attribute("ibc_transfer_error", err.to_string()));
+                }
+            };
+
+            save_ibc_transfer(
+                deps.storage,
+                &channel_id,
+                sequence,
+                &IbcTransfer {
+                    vault_id,
+                    msg: entry.msg,
+                    funds: entry.funds,
+                },
+            )?;
+
+            Response::new()
+                .add_attribute(format!("destination_msg_{}", destination_num), "pending")
+                .add_attribute("ibc_channel_id", channel_id)
+                .add_attribute("ibc_sequence", sequence.to_string())
+        }
         SubMsgResult::Ok(_) => Response::new()
             .add_attribute(format!("destination_msg_{}", destination_num), "succeeded"),
         SubMsgResult::Err(_) => {
@@ -33,22 +73,53 @@ pub fn handle_failed_automation_handler(
                     vault_id,
                     env.block,
                     EventData::DcaVaultPostExecutionActionFailed {
-                        msg: entry.msg,
+                        msg: entry.msg.clone(),
                         funds: entry.funds.clone(),
                     },
                 ),
             )?;
 
-            Response::new()
-                .add_attribute(format!("destination_msg_{}", destination_num), "failed")
-                .add_submessage(SubMsg::new(BankMsg::Send {
+            let response = Response::new()
+                .add_attribute(format!("destination_msg_{}", destination_num), "failed");
+
+            if is_ibc_transfer(&entry.msg) {
+                return Ok(response.add_submessage(SubMsg::new(BankMsg::Send {
                     to_address: vault.owner.to_string(),
                     amount: entry.funds,
-                }))
+                })));
+            }
+
+            add_failed_post_execution_action(
+                deps.storage,
+                vault_id,
+                FailedPostExecutionAction {
+                    address: entry.destination.address,
+                    action: entry.destination.action,
+                    amount: entry.funds[0].clone(),
+                    attempts: 1,
+                },
+            )?;
+
+            response.add_attribute("post_execution_action_queued", "true")
         }
     })
 }
 
+fn is_ibc_transfer(msg: &SubMsg) -> bool {
+    matches!(&msg.msg, CosmosMsg::Stargate { type_url, .. } if type_url == MsgTransfer::TYPE_URL)
+}
+
+fn get_ibc_transfer_channel_id(msg: &SubMsg) -> StdResult<String> {
+    match &msg.msg {
+        CosmosMsg::Stargate { value, .. } => MsgTransfer::decode(value.as_slice())
+            .map(|transfer| transfer.source_channel)
+            .map_err(|e| StdError::parse_err("MsgTransfer", e)),
+        _ => Err(StdError::generic_err(
+            "post execution action is not an ibc transfer",
+        )),
+    }
+}
+
 #[cfg(test)]
 mod handle_failed_automation_handler_tests {
     use super::handle_failed_automation_handler;
@@ -56,21 +127,25 @@ mod handle_failed_automation_handler_tests {
         constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
         handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
         helpers::disbursement::get_disbursement_messages,
-        state::cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
+        state::{
+            cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
+            post_execution_actions::get_failed_post_execution_actions,
+        },
         tests::{
             helpers::{instantiate_contract, setup_vault},
             mocks::ADMIN,
         },
         types::{
-            destination::Destination,
+            destination::{Destination, DestinationAction},
             event::{EventBuilder, EventData},
+            post_execution_action::FailedPostExecutionAction,
             vault::Vault,
         },
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        to_binary, Addr, BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse, SubMsgResult,
-        WasmMsg,
+        to_binary, Addr, Attribute, BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse,
+        SubMsgResult, WasmMsg,
     };
     use std::collections::VecDeque;
 
@@ -83,19 +158,14 @@ mod handle_failed_automation_handler_tests {
             Destination {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
-                msg: None,
+                action: DestinationAction::Send {},
             },
             Destination {
                 address: Addr::unchecked("contract"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary("test").unwrap(),
+                },
             },
         ];
 
@@ -108,7 +178,7 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         handle_failed_automation_handler(
             deps.as_mut(),
@@ -133,7 +203,7 @@ mod handle_failed_automation_handler_tests {
                 msg: SubMsg::reply_always(
                     WasmMsg::Execute {
                         contract_addr: destinations[1].address.to_string(),
-                        msg: destinations[1].msg.clone().unwrap(),
+                        msg: to_binary("test").unwrap(),
                         funds: vec![Coin::new(
                             (vault.swap_amount * destinations[1].allocation).into(),
                             vault.target_denom.clone()
@@ -145,6 +215,7 @@ mod handle_failed_automation_handler_tests {
                     (vault.swap_amount * destinations[1].allocation).into(),
                     vault.target_denom
                 )],
+                destination: destinations[1].clone(),
             }])
         );
     }
@@ -158,19 +229,14 @@ mod handle_failed_automation_handler_tests {
             Destination {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
-                msg: None,
+                action: DestinationAction::Send {},
             },
             Destination {
                 address: Addr::unchecked("contract"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary("test").unwrap(),
+                },
             },
         ];
 
@@ -183,7 +249,7 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         let response = handle_failed_automation_handler(
             deps.as_mut(),
@@ -210,19 +276,14 @@ mod handle_failed_automation_handler_tests {
             Destination {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
-                msg: None,
+                action: DestinationAction::Send {},
             },
             Destination {
                 address: Addr::unchecked("contract"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary("test").unwrap(),
+                },
             },
         ];
 
@@ -235,7 +296,7 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         handle_failed_automation_handler(
             deps.as_mut(),
@@ -257,7 +318,7 @@ mod handle_failed_automation_handler_tests {
                 msg: SubMsg::reply_always(
                     WasmMsg::Execute {
                         contract_addr: destinations[1].address.to_string(),
-                        msg: destinations[1].msg.clone().unwrap(),
+                        msg: to_binary("test").unwrap(),
                         funds: vec![Coin::new(
                             (vault.swap_amount * destinations[1].allocation).into(),
                             vault.target_denom.clone()
@@ -269,6 +330,7 @@ mod handle_failed_automation_handler_tests {
                     (vault.swap_amount * destinations[1].allocation).into(),
                     vault.target_denom
                 )],
+                destination: destinations[1].clone(),
             }])
         );
     }
@@ -284,26 +346,16 @@ mod handle_failed_automation_handler_tests {
             Destination {
                 address: Addr::unchecked("contract1"),
                 allocation: Decimal::percent(30),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract2".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary("test").unwrap(),
+                },
             },
             Destination {
                 address: Addr::unchecked("contract2"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract2".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary("test").unwrap(),
+                },
             },
         ];
 
@@ -316,7 +368,7 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         handle_failed_automation_handler(
             deps.as_mut(),
@@ -341,7 +393,7 @@ mod handle_failed_automation_handler_tests {
                     msg: SubMsg::reply_always(
                         WasmMsg::Execute {
                             contract_addr: destinations[0].address.to_string(),
-                            msg: destinations[0].msg.clone().unwrap(),
+                            msg: to_binary("test").unwrap(),
                             funds: vec![Coin::new(
                                 (vault.swap_amount * destinations[0].allocation).into(),
                                 vault.target_denom.clone()
@@ -360,7 +412,7 @@ mod handle_failed_automation_handler_tests {
     }
 
     #[test]
-    fn sends_funds_to_vault_owner_on_failure() {
+    fn adds_failed_action_to_retry_queue_on_failure() {
         let mut deps = mock_dependencies();
         let env = mock_env();
 
@@ -370,26 +422,16 @@ mod handle_failed_automation_handler_tests {
             Destination {
                 address: Addr::unchecked("contract1"),
                 allocation: Decimal::percent(30),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract2".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary("test").unwrap(),
+                },
             },
             Destination {
                 address: Addr::unchecked("contract2"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract2".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary("test").unwrap(),
+                },
             },
         ];
 
@@ -402,7 +444,58 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
+
+        let response = handle_failed_automation_handler(
+            deps.as_mut(),
+            env,
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Err("error".to_string()),
+            },
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            get_failed_post_execution_actions(deps.as_ref().storage, vault.id).unwrap(),
+            vec![FailedPostExecutionAction {
+                address: destinations[0].address.clone(),
+                action: destinations[0].action.clone(),
+                amount: Coin::new(
+                    (vault.swap_amount * destinations[0].allocation).into(),
+                    vault.target_denom
+                ),
+                attempts: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn refunds_failed_ibc_transfers_to_vault_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    address: Addr::unchecked("owner"),
+                    allocation: Decimal::percent(100),
+                    action: DestinationAction::IbcTransfer {
+                        channel_id: "channel-0".to_string(),
+                        receiver: "cosmos1receiver".to_string(),
+                        memo: None,
+                    },
+                }],
+                ..Vault::default()
+            },
+        );
+
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         let response = handle_failed_automation_handler(
             deps.as_mut(),
@@ -418,11 +511,63 @@ mod handle_failed_automation_handler_tests {
             response.messages,
             vec![SubMsg::new(BankMsg::Send {
                 to_address: vault.owner.to_string(),
-                amount: vec![Coin::new(
-                    (vault.swap_amount * destinations[0].allocation).into(),
-                    vault.target_denom
-                )],
+                amount: vec![Coin::new(vault.swap_amount.into(), vault.target_denom)],
             })]
         );
+        assert!(
+            get_failed_post_execution_actions(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn with_undecodable_ibc_transfer_response_logs_untracked_transfer() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    address: Addr::unchecked("owner"),
+                    allocation: Decimal::percent(100),
+                    action: DestinationAction::IbcTransfer {
+                        channel_id: "channel-0".to_string(),
+                        receiver: "cosmos1receiver".to_string(),
+                        memo: None,
+                    },
+                }],
+                ..Vault::default()
+            },
+        );
+
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
+
+        let response = handle_failed_automation_handler(
+            deps.as_mut(),
+            env,
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("destination_msg_1", "untracked")));
+        assert!(
+            get_failed_post_execution_actions(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .is_empty()
+        );
     }
 }
@@ -0,0 +1,252 @@
+use crate::contract::execute;
+use crate::error::ContractError;
+use crate::helpers::validation::{
+    assert_ibc_hook_msg_is_supported, assert_sender_is_ibc_hook_sender,
+};
+use crate::msg::ExecuteMsg;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn ibc_hook_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+    original_sender: String,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    assert_sender_is_ibc_hook_sender(&env, &info.sender, &channel_id, &original_sender)?;
+    assert_ibc_hook_msg_is_supported(&msg)?;
+
+    Ok(execute(deps, env, info, msg)?
+        .add_attribute("ibc_hook_channel_id", channel_id)
+        .add_attribute("ibc_hook_original_sender", original_sender))
+}
+
+#[cfg(test)]
+mod ibc_hook_tests {
+    use super::ibc_hook_handler;
+    use crate::{
+        constants::{ONE, TEN},
+        error::ContractError,
+        handlers::{create_pair::create_pair_handler, get_vault::get_vault_handler},
+        helpers::ibc_hooks::get_ibc_hook_sender,
+        msg::ExecuteMsg,
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO, USER},
+        },
+        types::{pair::Pair, time_interval::TimeInterval, vault::Vault},
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Addr, Coin, Env, MessageInfo, Uint128,
+    };
+
+    const CHANNEL_ID: &str = "channel-0";
+    const ORIGINAL_SENDER: &str = "cosmos1sender";
+
+    fn hook_env() -> Env {
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked("osmo1contract");
+        env
+    }
+
+    fn hook_info(env: &Env, funds: &[Coin]) -> MessageInfo {
+        let sender =
+            get_ibc_hook_sender(&env.contract.address, CHANNEL_ID, ORIGINAL_SENDER).unwrap();
+        mock_info(sender.as_str(), funds)
+    }
+
+    fn create_vault_msg(env: &Env, owner: Option<Addr>) -> ExecuteMsg {
+        ExecuteMsg::CreateVault {
+            owner,
+            label: None,
+            destinations: None,
+            target_denom: DENOM_UOSMO.to_string(),
+            position_type: None,
+            slippage_tolerance: None,
+            minimum_receive_amount: None,
+            swap_amount: ONE,
+            time_interval: TimeInterval::Daily,
+            target_start_time_utc_seconds: Some(env.block.time.plus_seconds(10).seconds().into()),
+            performance_assessment_strategy: None,
+            swap_adjustment_strategy: None,
+            target_price: None,
+            stop_loss: None,
+            take_profit: None,
+            execution_window: None,
+            end_time_utc_seconds: None,
+            max_executions: None,
+            accumulation: None,
+        }
+    }
+
+    #[test]
+    fn creates_vault_owned_by_memo_owner_with_hook_funds() {
+        let mut deps = calc_mock_dependencies();
+        let env = hook_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let funds = Coin::new(TEN.into(), DENOM_STAKE);
+
+        let response = ibc_hook_handler(
+            deps.as_mut(),
+            env.clone(),
+            hook_info(&env, std::slice::from_ref(&funds)),
+            CHANNEL_ID.to_string(),
+            ORIGINAL_SENDER.to_string(),
+            create_vault_msg(&env, Some(Addr::unchecked(USER))),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.owner, Addr::unchecked(USER));
+        assert_eq!(vault.destinations[0].address, Addr::unchecked(USER));
+        assert_eq!(vault.balance, funds);
+        assert!(response
+            .attributes
+            .iter()
+            .any(|a| a.key == "ibc_hook_original_sender" && a.value == ORIGINAL_SENDER));
+    }
+
+    #[test]
+    fn deposits_hook_funds_into_memo_owner_vault() {
+        let mut deps = calc_mock_dependencies();
+        let env = hook_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let funds = Coin::new(TEN.into(), vault.balance.denom.clone());
+
+        ibc_hook_handler(
+            deps.as_mut(),
+            env.clone(),
+            hook_info(&env, std::slice::from_ref(&funds)),
+            CHANNEL_ID.to_string(),
+            ORIGINAL_SENDER.to_string(),
+            ExecuteMsg::Deposit {
+                address: vault.owner.clone(),
+                vault_id: vault.id,
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault_handler(deps.as_ref(), vault.id).unwrap().vault;
+
+        assert_eq!(
+            updated_vault.balance.amount,
+            vault.balance.amount + funds.amount
+        );
+    }
+
+    #[test]
+    fn deposit_with_incorrect_memo_owner_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = hook_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = ibc_hook_handler(
+            deps.as_mut(),
+            env.clone(),
+            hook_info(&env, &[Coin::new(TEN.into(), vault.balance.denom.clone())]),
+            CHANNEL_ID.to_string(),
+            ORIGINAL_SENDER.to_string(),
+            ExecuteMsg::Deposit {
+                address: Addr::unchecked("not-owner"),
+                vault_id: vault.id,
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::IncorrectVaultOwner { .. }));
+    }
+
+    #[test]
+    fn with_sender_not_derived_from_memo_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = hook_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = ibc_hook_handler(
+            deps.as_mut(),
+            env.clone(),
+            hook_info(&env, &[Coin::new(TEN.into(), DENOM_STAKE)]),
+            "channel-1".to_string(),
+            ORIGINAL_SENDER.to_string(),
+            create_vault_msg(&env, Some(Addr::unchecked(USER))),
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn create_vault_without_owner_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = hook_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = ibc_hook_handler(
+            deps.as_mut(),
+            env.clone(),
+            hook_info(&env, &[Coin::new(TEN.into(), DENOM_STAKE)]),
+            CHANNEL_ID.to_string(),
+            ORIGINAL_SENDER.to_string(),
+            create_vault_msg(&env, None),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: vaults created via ibc hooks must provide an owner"
+        );
+    }
+
+    #[test]
+    fn with_unsupported_msg_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = hook_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = ibc_hook_handler(
+            deps.as_mut(),
+            env.clone(),
+            hook_info(&env, &[]),
+            CHANNEL_ID.to_string(),
+            ORIGINAL_SENDER.to_string(),
+            ExecuteMsg::CancelVault { vault_id: vault.id },
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::UnsupportedIbcHookMsg {}));
+    }
+}
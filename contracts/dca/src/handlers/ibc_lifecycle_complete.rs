@@ -0,0 +1,293 @@
+use crate::{
+    error::ContractError,
+    state::{
+        events::create_event,
+        ibc_transfers::{get_ibc_transfer, remove_ibc_transfer},
+        vaults::get_vault,
+    },
+    types::{
+        event::{EventBuilder, EventData},
+        ibc_transfer::IbcLifecycleComplete,
+    },
+};
+use cosmwasm_std::{BankMsg, DepsMut, Env, Response, SubMsg};
+
+pub fn ibc_lifecycle_complete_handler(
+    deps: DepsMut,
+    env: Env,
+    result: IbcLifecycleComplete,
+) -> Result<Response, ContractError> {
+    let (channel_id, sequence, status) = match result {
+        IbcLifecycleComplete::IbcAck {
+            channel,
+            sequence,
+            success: true,
+            ..
+        } => (channel, sequence, "succeeded"),
+        IbcLifecycleComplete::IbcAck {
+            channel, sequence, ..
+        } => (channel, sequence, "failed"),
+        IbcLifecycleComplete::IbcTimeout { channel, sequence } => (channel, sequence, "timed_out"),
+    };
+
+    let transfer = get_ibc_transfer(deps.storage, &channel_id, sequence)?.ok_or_else(|| {
+        ContractError::IbcTransferNotFound {
+            channel_id: channel_id.clone(),
+            sequence,
+        }
+    })?;
+
+    remove_ibc_transfer(deps.storage, &channel_id, sequence);
+
+    let response = Response::new()
+        .add_attribute("vault_id", transfer.vault_id)
+        .add_attribute("ibc_channel_id", channel_id)
+        .add_attribute("ibc_sequence", sequence.to_string())
+        .add_attribute("ibc_transfer", status);
+
+    if status == "succeeded" {
+        return Ok(response);
+    }
+
+    let vault = get_vault(deps.storage, transfer.vault_id)?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            vault.id,
+            env.block,
+            EventData::DcaVaultPostExecutionActionFailed {
+                msg: transfer.msg,
+                funds: transfer.funds.clone(),
+            },
+        ),
+    )?;
+
+    Ok(response.add_submessage(SubMsg::new(BankMsg::Send {
+        to_address: vault.owner.to_string(),
+        amount: transfer.funds,
+    })))
+}
+
+#[cfg(test)]
+mod ibc_lifecycle_complete_handler_tests {
+    use super::ibc_lifecycle_complete_handler;
+    use crate::{
+        constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
+        error::ContractError,
+        handlers::{
+            get_events_by_resource_id::get_events_by_resource_id_handler,
+            handle_failed_automation::handle_failed_automation_handler,
+        },
+        helpers::disbursement::get_disbursement_messages,
+        state::ibc_transfers::get_ibc_transfer,
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::ADMIN,
+        },
+        types::{
+            destination::{Destination, DestinationAction},
+            event::{EventBuilder, EventData},
+            ibc_transfer::{IbcLifecycleComplete, MsgTransferResponse},
+            vault::Vault,
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+        Addr, BankMsg, Binary, Coin, Decimal, Env, OwnedDeps, Reply, SubMsg, SubMsgResponse,
+        SubMsgResult,
+    };
+    use prost::Message;
+
+    const CHANNEL_ID: &str = "channel-0";
+    const SEQUENCE: u64 = 7;
+
+    fn setup_pending_transfer(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: &Env,
+    ) -> (Vault, SubMsg) {
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    allocation: Decimal::percent(100),
+                    address: Addr::unchecked("owner"),
+                    action: DestinationAction::IbcTransfer {
+                        channel_id: CHANNEL_ID.to_string(),
+                        receiver: "cosmos1receiver".to_string(),
+                        memo: None,
+                    },
+                }],
+                ..Vault::default()
+            },
+        );
+
+        let messages =
+            get_disbursement_messages(deps.as_mut().storage, env, &vault, vault.swap_amount)
+                .unwrap();
+
+        handle_failed_automation_handler(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: Some(Binary(
+                        MsgTransferResponse { sequence: SEQUENCE }.encode_to_vec(),
+                    )),
+                }),
+            },
+        )
+        .unwrap();
+
+        (vault, messages[0].clone())
+    }
+
+    #[test]
+    fn removes_pending_transfer_on_successful_ack() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        setup_pending_transfer(&mut deps, &env);
+
+        let response = ibc_lifecycle_complete_handler(
+            deps.as_mut(),
+            env,
+            IbcLifecycleComplete::IbcAck {
+                channel: CHANNEL_ID.to_string(),
+                sequence: SEQUENCE,
+                ack: "".to_string(),
+                success: true,
+            },
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+        assert!(
+            get_ibc_transfer(deps.as_ref().storage, CHANNEL_ID, SEQUENCE)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn refunds_vault_owner_on_failed_ack() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let (vault, _) = setup_pending_transfer(&mut deps, &env);
+
+        let response = ibc_lifecycle_complete_handler(
+            deps.as_mut(),
+            env,
+            IbcLifecycleComplete::IbcAck {
+                channel: CHANNEL_ID.to_string(),
+                sequence: SEQUENCE,
+                ack: "error".to_string(),
+                success: false,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![Coin::new(vault.swap_amount.into(), vault.target_denom)],
+            })]
+        );
+        assert!(
+            get_ibc_transfer(deps.as_ref().storage, CHANNEL_ID, SEQUENCE)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn refunds_vault_owner_on_timeout() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let (vault, _) = setup_pending_transfer(&mut deps, &env);
+
+        let response = ibc_lifecycle_complete_handler(
+            deps.as_mut(),
+            env,
+            IbcLifecycleComplete::IbcTimeout {
+                channel: CHANNEL_ID.to_string(),
+                sequence: SEQUENCE,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![Coin::new(vault.swap_amount.into(), vault.target_denom)],
+            })]
+        );
+    }
+
+    #[test]
+    fn creates_post_execution_action_failed_event_on_timeout() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let (vault, msg) = setup_pending_transfer(&mut deps, &env);
+
+        ibc_lifecycle_complete_handler(
+            deps.as_mut(),
+            env.clone(),
+            IbcLifecycleComplete::IbcTimeout {
+                channel: CHANNEL_ID.to_string(),
+                sequence: SEQUENCE,
+            },
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            events[0],
+            EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultPostExecutionActionFailed {
+                    msg,
+                    funds: vec![Coin::new(vault.swap_amount.into(), vault.target_denom)],
+                },
+            )
+            .build(1)
+        );
+    }
+
+    #[test]
+    fn with_unknown_transfer_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        setup_pending_transfer(&mut deps, &env);
+
+        let err = ibc_lifecycle_complete_handler(
+            deps.as_mut(),
+            env,
+            IbcLifecycleComplete::IbcTimeout {
+                channel: CHANNEL_ID.to_string(),
+                sequence: SEQUENCE + 1,
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ContractError::IbcTransferNotFound { sequence, .. } if sequence == SEQUENCE + 1
+        ));
+    }
+}
@@ -4,7 +4,8 @@ use crate::{
     helpers::validation::{
         assert_addresses_are_valid, assert_fee_collector_addresses_are_valid,
         assert_fee_collector_allocations_add_up_to_one, assert_fee_level_is_valid,
-        assert_no_more_than_10_fee_collectors, assert_page_limit_is_valid,
+        assert_keeper_reward_percent_is_valid, assert_no_more_than_10_fee_collectors,
+        assert_page_limit_is_valid, assert_retry_policy_is_valid,
         assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent,
         assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_twap_period_is_valid,
     },
@@ -24,6 +25,12 @@ pub fn instantiate_handler(deps: DepsMut, msg: InstantiateMsg) -> Result<Respons
     assert_page_limit_is_valid(Some(msg.default_page_limit))?;
     assert_slippage_tolerance_is_less_than_or_equal_to_one(msg.default_slippage_tolerance)?;
     assert_twap_period_is_valid(msg.twap_period)?;
+    if let Some(retry_policy) = &msg.retry_policy {
+        assert_retry_policy_is_valid(retry_policy)?;
+    }
+    if let Some(keeper_reward_percent) = msg.keeper_reward_percent {
+        assert_keeper_reward_percent_is_valid(keeper_reward_percent)?;
+    }
     assert_addresses_are_valid(deps.as_ref(), &msg.executors, "executor")?;
     assert_no_more_than_10_fee_collectors(&msg.fee_collectors)?;
     assert_fee_collector_addresses_are_valid(deps.as_ref(), &msg.fee_collectors)?;
@@ -46,6 +53,12 @@ pub fn instantiate_handler(deps: DepsMut, msg: InstantiateMsg) -> Result<Respons
             risk_weighted_average_escrow_level: msg.risk_weighted_average_escrow_level,
             twap_period: msg.twap_period,
             default_slippage_tolerance: msg.default_slippage_tolerance,
+            retry_policy: msg.retry_policy.clone(),
+            keeper_reward_percent: msg.keeper_reward_percent,
+            pending_admin: None,
+            pair_managers: vec![],
+            fee_managers: vec![],
+            pausers: vec![],
         },
     )?;
 
@@ -89,6 +102,8 @@ mod instantiate_tests {
             risk_weighted_average_escrow_level: Decimal::from_str("0.05").unwrap(),
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
+            retry_policy: None,
+            keeper_reward_percent: None,
         };
 
         let result = instantiate(deps.as_mut(), env, info, instantiate_message).unwrap();
@@ -123,6 +138,8 @@ mod instantiate_tests {
             risk_weighted_average_escrow_level: Decimal::from_str("0.05").unwrap(),
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
+            retry_policy: None,
+            keeper_reward_percent: None,
         };
 
         let result = instantiate(deps.as_mut(), env, info, instantiate_message).unwrap_err();
@@ -154,6 +171,8 @@ mod instantiate_tests {
             risk_weighted_average_escrow_level: Decimal::from_str("0.05").unwrap(),
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
+            retry_policy: None,
+            keeper_reward_percent: None,
         };
 
         let result = instantiate(deps.as_mut(), env, info, instantiate_message).unwrap_err();
@@ -182,6 +201,8 @@ mod instantiate_tests {
             risk_weighted_average_escrow_level: Decimal::from_str("0.05").unwrap(),
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
+            retry_policy: None,
+            keeper_reward_percent: None,
         };
 
         let result = instantiate(deps.as_mut(), env, info, instantiate_message).unwrap_err();
@@ -1,7 +1,9 @@
 use crate::{
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
     error::ContractError,
+    migrations::{parse_version, pending_migrations, MIGRATIONS},
     msg::MigrateMsg,
+    state::migrations::get_migration_cursor,
 };
 use cosmwasm_std::{DepsMut, Response, StdError};
 use cw2::{get_contract_version, set_contract_version};
@@ -13,14 +15,152 @@ pub fn migrate_handler(deps: DepsMut, msg: MigrateMsg) -> Result<Response, Contr
         return Err(StdError::generic_err("Can only upgrade from same type").into());
     }
 
-    #[allow(clippy::cmp_owned)]
-    if contract_version.version > CONTRACT_VERSION.to_string() {
+    let from_version = parse_version(&contract_version.version)?;
+    let to_version = parse_version(CONTRACT_VERSION)?;
+
+    if from_version > to_version {
         return Err(StdError::generic_err("Cannot upgrade from a newer version").into());
     }
 
+    let mut response = Response::new()
+        .add_attribute("migrate", "true")
+        .add_attribute("from_version", from_version.to_string())
+        .add_attribute("to_version", to_version.to_string());
+
+    let mut migrated_version = from_version.clone();
+
+    for (version, step) in pending_migrations(&MIGRATIONS, &from_version, &to_version)? {
+        response = response
+            .add_attribute("migration", version.to_string())
+            .add_attributes(step(deps.storage, &msg)?);
+
+        // a step that leaves a cursor behind has more to migrate, so the version is only advanced
+        // to the last completed step and the step resumes from the cursor on the next migration
+        if get_migration_cursor(deps.storage)?.is_some() {
+            set_contract_version(deps.storage, CONTRACT_NAME, migrated_version.to_string())?;
+
+            return Ok(response
+                .add_attribute("migration_in_progress", version.to_string())
+                .add_attribute("msg", format!("{:#?}", msg)));
+        }
+
+        migrated_version = version;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    Ok(Response::new()
-        .add_attribute("migrate", "true")
-        .add_attribute("msg", format!("{:#?}", msg)))
+    Ok(response.add_attribute("msg", format!("{:#?}", msg)))
+}
+
+#[cfg(test)]
+mod migrate_tests {
+    use super::*;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Attribute;
+
+    fn migrate_msg() -> MigrateMsg {
+        MigrateMsg {
+            fix_position_types: None,
+            remove_orphaned_triggers: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn with_different_contract_name_fails() {
+        let mut deps = mock_dependencies();
+
+        set_contract_version(deps.as_mut().storage, "crates.io:other", "1.0.0").unwrap();
+
+        let err = migrate_handler(deps.as_mut(), migrate_msg()).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: Can only upgrade from same type"
+        );
+    }
+
+    #[test]
+    fn with_newer_version_fails() {
+        let mut deps = mock_dependencies();
+
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.10.0").unwrap();
+
+        let err = migrate_handler(deps.as_mut(), migrate_msg()).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: Cannot upgrade from a newer version"
+        );
+    }
+
+    #[test]
+    fn runs_pending_migrations_and_updates_version() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.0.5").unwrap();
+
+        let response = migrate_handler(deps.as_mut(), migrate_msg()).unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("migration", "1.1.0")));
+        assert_eq!(
+            get_contract_version(deps.as_ref().storage).unwrap().version,
+            CONTRACT_VERSION
+        );
+    }
+
+    #[test]
+    fn with_current_version_runs_no_migrations() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let response = migrate_handler(deps.as_mut(), migrate_msg()).unwrap();
+
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "migration"));
+    }
+
+    #[test]
+    fn with_incomplete_migration_step_keeps_version_until_step_completes() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_vault(deps.as_mut(), env, Vault::default());
+
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.0.5").unwrap();
+
+        let msg = MigrateMsg {
+            limit: Some(1),
+            ..migrate_msg()
+        };
+
+        let response = migrate_handler(deps.as_mut(), msg.clone()).unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("migration_in_progress", "1.1.0")));
+        assert_eq!(
+            get_contract_version(deps.as_ref().storage).unwrap().version,
+            "1.0.5"
+        );
+
+        migrate_handler(deps.as_mut(), msg).unwrap();
+
+        assert_eq!(
+            get_contract_version(deps.as_ref().storage).unwrap().version,
+            CONTRACT_VERSION
+        );
+    }
 }
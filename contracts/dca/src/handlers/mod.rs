@@ -1,31 +1,49 @@
+pub mod accept_admin;
+pub mod accept_vault_ownership;
 pub mod cancel_vault;
+pub mod claim_vault;
 pub mod create_custom_swap_fee;
 pub mod create_pair;
 pub mod create_pairs;
 pub mod create_vault;
+pub mod delete_pair;
 pub mod deposit;
 pub mod disburse_escrow;
 pub mod disburse_funds;
 pub mod execute_trigger;
-pub mod fix_position_type;
+pub mod execute_triggers;
 pub mod get_config;
 pub mod get_custom_swap_fees;
 pub mod get_disburse_escrow_tasks;
 pub mod get_events;
 pub mod get_events_by_resource_id;
+pub mod get_failed_post_execution_actions;
 pub mod get_pairs;
+pub mod get_price_trigger_ids;
 pub mod get_swap_adjustment;
 pub mod get_time_trigger_ids;
 pub mod get_vault;
 pub mod get_vault_performance;
+pub mod get_vault_schedule;
 pub mod get_vaults;
 pub mod get_vaults_by_address;
 pub mod handle_failed_automation;
+pub mod ibc_hook;
+pub mod ibc_lifecycle_complete;
 pub mod instantiate;
 pub mod migrate;
+pub mod pause_contract;
+pub mod pause_vault;
 pub mod remove_custom_swap_fee;
+pub mod resume_contract;
+pub mod resume_vault;
+pub mod retry_post_execution_action;
+pub mod simulate_execution;
+pub mod transfer_admin;
+pub mod transfer_vault_ownership;
 pub mod update_config;
 pub mod update_swap_adjustment_handler;
 pub mod update_vault;
+pub mod withdraw_from_vault;
 pub mod z_delegate;
 pub mod z_provide_liquidity;
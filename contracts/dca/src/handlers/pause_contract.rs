@@ -0,0 +1,83 @@
+use crate::error::ContractError;
+use crate::helpers::validation::assert_sender_has_role;
+use crate::state::config::{get_config, update_config};
+use crate::types::role::Role;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn pause_contract_handler(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &info.sender, Role::Pauser)?;
+
+    let mut config = get_config(deps.storage)?;
+
+    config.paused = true;
+
+    update_config(deps.storage, config)?;
+
+    Ok(Response::new()
+        .add_attribute("pause_contract", "true")
+        .add_attribute("sender", info.sender))
+}
+
+#[cfg(test)]
+mod pause_contract_tests {
+    use super::*;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::config::Config;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    const PAUSER: &str = "pauser";
+
+    #[test]
+    fn with_sender_without_pauser_role_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = pause_contract_handler(deps.as_mut(), mock_info(USER, &[])).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_pauser_sender_sets_paused_to_true() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                paused: false,
+                pausers: vec![Addr::unchecked(PAUSER)],
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        pause_contract_handler(deps.as_mut(), mock_info(PAUSER, &[])).unwrap();
+
+        assert!(get_config(deps.as_ref().storage).unwrap().paused);
+    }
+
+    #[test]
+    fn with_admin_sender_sets_paused_to_true() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                paused: false,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        pause_contract_handler(deps.as_mut(), mock_info(ADMIN, &[])).unwrap();
+
+        assert!(get_config(deps.as_ref().storage).unwrap().paused);
+    }
+}
@@ -1,5 +1,6 @@
-use crate::helpers::validation::assert_sender_is_admin;
+use crate::helpers::validation::assert_sender_has_role;
 use crate::state::config::get_custom_fee;
+use crate::types::role::Role;
 use crate::{error::ContractError, state::config::remove_custom_fee};
 use cosmwasm_std::DepsMut;
 #[cfg(not(feature = "library"))]
@@ -10,14 +11,12 @@ pub fn remove_custom_swap_fee_handler(
     info: MessageInfo,
     denom: String,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_sender_has_role(deps.storage, &info.sender, Role::FeeManager)?;
 
     let fee = get_custom_fee(deps.storage, denom.clone())?;
 
     if fee.is_none() {
-        return Err(ContractError::CustomError {
-            val: format!("Custom fee for {} does not exist", denom),
-        });
+        return Err(ContractError::CustomFeeNotFound { denom });
     }
 
     remove_custom_fee(deps.storage, denom.clone());
@@ -81,6 +80,10 @@ mod remove_custom_swap_fee_tests {
                 base_denom: denom.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                weighted_routes: None,
+                minimum_swap_amounts: None,
+                twap_period: None,
+                default_slippage_tolerance: None,
             },
         )
         .unwrap();
@@ -0,0 +1,193 @@
+use crate::error::ContractError;
+use crate::helpers::time::get_next_target_time;
+use crate::helpers::validation::{assert_vault_can_be_resumed, asset_sender_is_vault_owner};
+use crate::state::events::create_event;
+use crate::state::triggers::save_trigger;
+use crate::state::vaults::{get_vault, update_vault};
+use crate::types::event::{EventBuilder, EventData};
+use crate::types::trigger::{Trigger, TriggerConfiguration};
+use crate::types::vault::VaultStatus;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn resume_vault_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+) -> Result<Response, ContractError> {
+    let mut vault = get_vault(deps.storage, vault_id)?;
+
+    asset_sender_is_vault_owner(vault.owner.clone(), info.sender)?;
+    assert_vault_can_be_resumed(&vault)?;
+
+    vault.status = VaultStatus::Active;
+
+    update_vault(deps.storage, vault.clone())?;
+
+    save_trigger(
+        deps.storage,
+        Trigger {
+            vault_id: vault.id,
+            configuration: TriggerConfiguration::Time {
+                target_time: get_next_target_time(
+                    env.block.time,
+                    vault.started_at.unwrap_or(env.block.time),
+                    vault.time_interval.clone(),
+                ),
+            },
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(vault.id, env.block, EventData::DcaVaultResumed {}),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("resume_vault", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("owner", vault.owner))
+}
+
+#[cfg(test)]
+mod resume_vault_tests {
+    use super::*;
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::state::triggers::get_trigger;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, DENOM_UOSMO, USER};
+    use crate::types::time_interval::TimeInterval;
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Coin;
+
+    #[test]
+    fn with_non_owner_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        let err =
+            resume_vault_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_active_vault_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err =
+            resume_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: only paused vaults can be resumed");
+    }
+
+    #[test]
+    fn with_empty_balance_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                balance: Coin::new(0, DENOM_UOSMO),
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        let err =
+            resume_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: vault {} has no balance left to resume", vault.id)
+        );
+    }
+
+    #[test]
+    fn resumes_vault_and_reschedules_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let started_at = env.block.time.minus_seconds(60 * 60 * 36);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                started_at: Some(started_at),
+                time_interval: TimeInterval::Daily,
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        resume_vault_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Active);
+        assert_eq!(
+            get_trigger(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .unwrap()
+                .configuration,
+            TriggerConfiguration::Time {
+                target_time: get_next_target_time(env.block.time, started_at, TimeInterval::Daily),
+            }
+        );
+    }
+
+    #[test]
+    fn publishes_vault_resumed_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        resume_vault_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), vault.id).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(vault.id, env.block, EventData::DcaVaultResumed {}).build(1)
+        ));
+    }
+}
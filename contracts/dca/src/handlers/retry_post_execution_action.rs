@@ -0,0 +1,393 @@
+use crate::{
+    constants::{AFTER_RETRY_POST_EXECUTION_ACTION_REPLY_ID, MAX_POST_EXECUTION_ACTION_ATTEMPTS},
+    error::ContractError,
+    helpers::{
+        disbursement::get_destination_message,
+        validation::{assert_contract_is_not_paused, assert_sender_is_executor},
+    },
+    state::{
+        cache::{RetryPostExecutionActionCache, RETRY_POST_EXECUTION_ACTION_CACHE},
+        events::create_event,
+        post_execution_actions::{
+            pop_failed_post_execution_action, requeue_failed_post_execution_action,
+        },
+        vaults::get_vault,
+    },
+    types::{
+        event::{EventBuilder, EventData},
+        post_execution_action::FailedPostExecutionAction,
+    },
+};
+use cosmwasm_std::{
+    BankMsg, DepsMut, Env, MessageInfo, Reply, Response, StdResult, SubMsg, SubMsgResult, Uint128,
+};
+
+pub fn retry_post_execution_action_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+) -> Result<Response, ContractError> {
+    assert_contract_is_not_paused(deps.storage)?;
+    assert_sender_is_executor(deps.storage, &env, &info.sender)?;
+
+    let action = pop_failed_post_execution_action(deps.storage, vault_id)?
+        .ok_or(ContractError::NoFailedPostExecutionActions { vault_id })?;
+
+    RETRY_POST_EXECUTION_ACTION_CACHE.save(
+        deps.storage,
+        &RetryPostExecutionActionCache {
+            vault_id,
+            action: action.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("retry_post_execution_action", "true")
+        .add_attribute("vault_id", vault_id)
+        .add_attribute("attempt", (action.attempts + 1).to_string())
+        .add_submessage(get_retry_message(&env, action)?))
+}
+
+pub fn log_retry_post_execution_action_result(
+    deps: DepsMut,
+    env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let RetryPostExecutionActionCache {
+        vault_id,
+        mut action,
+    } = RETRY_POST_EXECUTION_ACTION_CACHE.load(deps.storage)?;
+
+    RETRY_POST_EXECUTION_ACTION_CACHE.remove(deps.storage);
+
+    let response = Response::new().add_attribute("vault_id", vault_id);
+
+    match reply.result {
+        SubMsgResult::Ok(_) => Ok(response.add_attribute("post_execution_action", "succeeded")),
+        SubMsgResult::Err(_) => {
+            action.attempts += 1;
+
+            if action.attempts < MAX_POST_EXECUTION_ACTION_ATTEMPTS {
+                let attempts = action.attempts;
+                requeue_failed_post_execution_action(deps.storage, vault_id, action)?;
+
+                return Ok(response
+                    .add_attribute("post_execution_action", "failed")
+                    .add_attribute("attempts", attempts.to_string()));
+            }
+
+            let vault = get_vault(deps.storage, vault_id)?;
+
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    vault.id,
+                    env.block.clone(),
+                    EventData::DcaVaultPostExecutionActionFailed {
+                        msg: get_retry_message(&env, action.clone())?,
+                        funds: vec![action.amount.clone()],
+                    },
+                ),
+            )?;
+
+            Ok(response
+                .add_attribute("post_execution_action", "refunded")
+                .add_submessage(SubMsg::new(BankMsg::Send {
+                    to_address: vault.owner.to_string(),
+                    amount: vec![action.amount],
+                })))
+        }
+    }
+}
+
+fn get_retry_message(env: &Env, action: FailedPostExecutionAction) -> StdResult<SubMsg> {
+    Ok(SubMsg::reply_always(
+        get_destination_message(env, &action.address, action.action, action.amount)?,
+        AFTER_RETRY_POST_EXECUTION_ACTION_REPLY_ID,
+    ))
+}
+
+#[cfg(test)]
+mod retry_post_execution_action_tests {
+    use super::{log_retry_post_execution_action_result, retry_post_execution_action_handler};
+    use crate::{
+        constants::{
+            AFTER_RETRY_POST_EXECUTION_ACTION_REPLY_ID, MAX_POST_EXECUTION_ACTION_ATTEMPTS, ONE,
+        },
+        error::ContractError,
+        handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
+        state::post_execution_actions::{
+            add_failed_post_execution_action, get_failed_post_execution_actions,
+        },
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, USER},
+        },
+        types::{
+            destination::DestinationAction,
+            event::{EventBuilder, EventData},
+            post_execution_action::FailedPostExecutionAction,
+            vault::Vault,
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        to_binary, Addr, BankMsg, Coin, Reply, SubMsg, SubMsgResponse, SubMsgResult, WasmMsg,
+    };
+
+    fn failed_action(vault: &Vault, attempts: u8) -> FailedPostExecutionAction {
+        FailedPostExecutionAction {
+            address: Addr::unchecked("contract"),
+            action: DestinationAction::ExecuteContract {
+                msg: to_binary("test").unwrap(),
+            },
+            amount: Coin::new(ONE.into(), vault.target_denom.clone()),
+            attempts,
+        }
+    }
+
+    fn retry_message(vault: &Vault) -> SubMsg {
+        SubMsg::reply_always(
+            WasmMsg::Execute {
+                contract_addr: "contract".to_string(),
+                msg: to_binary("test").unwrap(),
+                funds: vec![Coin::new(ONE.into(), vault.target_denom.clone())],
+            },
+            AFTER_RETRY_POST_EXECUTION_ACTION_REPLY_ID,
+        )
+    }
+
+    fn failed_reply() -> Reply {
+        Reply {
+            id: AFTER_RETRY_POST_EXECUTION_ACTION_REPLY_ID,
+            result: SubMsgResult::Err("error".to_string()),
+        }
+    }
+
+    #[test]
+    fn with_non_executor_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        add_failed_post_execution_action(deps.as_mut().storage, vault.id, failed_action(&vault, 1))
+            .unwrap();
+
+        let err =
+            retry_post_execution_action_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id)
+                .unwrap_err();
+
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn with_no_failed_actions_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = retry_post_execution_action_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: vault {} has no failed post execution actions to retry",
+                vault.id
+            )
+        );
+    }
+
+    #[test]
+    fn resends_oldest_failed_action() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        add_failed_post_execution_action(deps.as_mut().storage, vault.id, failed_action(&vault, 1))
+            .unwrap();
+        add_failed_post_execution_action(deps.as_mut().storage, vault.id, failed_action(&vault, 2))
+            .unwrap();
+
+        let response = retry_post_execution_action_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        assert_eq!(response.messages, vec![retry_message(&vault)]);
+        assert_eq!(
+            get_failed_post_execution_actions(deps.as_ref().storage, vault.id).unwrap(),
+            vec![failed_action(&vault, 2)]
+        );
+    }
+
+    #[test]
+    fn removes_action_on_success() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        add_failed_post_execution_action(deps.as_mut().storage, vault.id, failed_action(&vault, 1))
+            .unwrap();
+
+        retry_post_execution_action_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        let response = log_retry_post_execution_action_result(
+            deps.as_mut(),
+            env,
+            Reply {
+                id: AFTER_RETRY_POST_EXECUTION_ACTION_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+        assert!(
+            get_failed_post_execution_actions(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn requeues_action_on_failure_below_max_attempts() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        add_failed_post_execution_action(deps.as_mut().storage, vault.id, failed_action(&vault, 1))
+            .unwrap();
+        add_failed_post_execution_action(deps.as_mut().storage, vault.id, failed_action(&vault, 2))
+            .unwrap();
+
+        retry_post_execution_action_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        let response =
+            log_retry_post_execution_action_result(deps.as_mut(), env, failed_reply()).unwrap();
+
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            get_failed_post_execution_actions(deps.as_ref().storage, vault.id).unwrap(),
+            vec![failed_action(&vault, 2), failed_action(&vault, 2)]
+        );
+    }
+
+    #[test]
+    fn refunds_vault_owner_on_failure_at_max_attempts() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let action = failed_action(&vault, MAX_POST_EXECUTION_ACTION_ATTEMPTS - 1);
+
+        add_failed_post_execution_action(deps.as_mut().storage, vault.id, action.clone()).unwrap();
+
+        retry_post_execution_action_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        let response =
+            log_retry_post_execution_action_result(deps.as_mut(), env, failed_reply()).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![action.amount],
+            })]
+        );
+        assert!(
+            get_failed_post_execution_actions(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn creates_post_execution_action_failed_event_on_failure_at_max_attempts() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let action = failed_action(&vault, MAX_POST_EXECUTION_ACTION_ATTEMPTS - 1);
+
+        add_failed_post_execution_action(deps.as_mut().storage, vault.id, action.clone()).unwrap();
+
+        retry_post_execution_action_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        log_retry_post_execution_action_result(deps.as_mut(), env.clone(), failed_reply()).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            events.last().unwrap(),
+            &EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultPostExecutionActionFailed {
+                    msg: retry_message(&vault),
+                    funds: vec![action.amount],
+                },
+            )
+            .build(events.last().unwrap().id)
+        );
+    }
+}
@@ -0,0 +1,525 @@
+use crate::{
+    helpers::{
+        execution::{get_execution_decision, get_swap_decision, ExecutionDecision, SwapDecision},
+        fees::{get_automation_fee_rate, get_swap_fee_rate},
+        price::{calculate_slippage, query_belief_price, query_price},
+    },
+    msg::SimulateExecutionResponse,
+    state::{pairs::find_pair, vaults::get_vault},
+    types::{event::ExecutionSkippedReason, termination_condition::TerminationAction},
+};
+use cosmwasm_std::{Coin, Decimal, Deps, Env, StdError, StdResult, Uint128};
+
+pub fn simulate_execution_handler(
+    deps: Deps,
+    env: Env,
+    vault_id: Uint128,
+) -> StdResult<SimulateExecutionResponse> {
+    let vault = get_vault(deps.storage, vault_id)?;
+
+    let pair = find_pair(deps.storage, vault.denoms())?;
+
+    let belief_price = query_belief_price(&deps, &env, &pair, vault.get_swap_denom())?;
+
+    let skipped = |reason: ExecutionSkippedReason| SimulateExecutionResponse {
+        sent: Coin::new(0, vault.get_swap_denom()),
+        received: Coin::new(0, vault.target_denom.clone()),
+        fee: Coin::new(0, vault.target_denom.clone()),
+        skipped_reason: Some(reason),
+        termination_reason: None,
+    };
+
+    let (swap_entire_balance, termination_reason) =
+        match get_execution_decision(&env, &vault, &pair, belief_price)? {
+            ExecutionDecision::OutsideExecutionWindow { .. } => {
+                return Ok(skipped(ExecutionSkippedReason::OutsideExecutionWindow));
+            }
+            ExecutionDecision::Terminate {
+                reason,
+                action: TerminationAction::Refund,
+            } => {
+                return Ok(SimulateExecutionResponse {
+                    sent: Coin::new(0, vault.get_swap_denom()),
+                    received: Coin::new(0, vault.target_denom.clone()),
+                    fee: Coin::new(0, vault.target_denom),
+                    skipped_reason: None,
+                    termination_reason: Some(reason),
+                });
+            }
+            ExecutionDecision::Terminate {
+                reason,
+                action: TerminationAction::Swap,
+            } => (true, Some(reason)),
+            ExecutionDecision::Swap => (false, None),
+        };
+
+    if vault.is_inactive() {
+        return Err(StdError::generic_err(format!(
+            "vault with id {} is inactive, and will not swap when executed",
+            vault.id
+        )));
+    }
+
+    let (swap_amount, minimum_receive_amount) =
+        match get_swap_decision(&deps, &env, &vault, belief_price, swap_entire_balance)? {
+            SwapDecision::Skip { reason } => return Ok(skipped(reason)),
+            SwapDecision::Swap {
+                swap_amount,
+                minimum_receive_amount,
+            } => (swap_amount, minimum_receive_amount),
+        };
+
+    let actual_price = query_price(&deps.querier, &env, &pair, &swap_amount)?;
+
+    if calculate_slippage(actual_price, belief_price) > vault.slippage_tolerance {
+        return Ok(skipped(ExecutionSkippedReason::SlippageToleranceExceeded));
+    }
+
+    let received_amount = swap_amount.amount * (Decimal::one() / actual_price);
+
+    if minimum_receive_amount
+        .is_some_and(|minimum_receive_amount| received_amount < minimum_receive_amount)
+    {
+        return Ok(skipped(ExecutionSkippedReason::SlippageToleranceExceeded));
+    }
+
+    let swap_fee_rate = get_swap_fee_rate(
+        deps.storage,
+        vault.get_swap_denom(),
+        vault.target_denom.clone(),
+        &vault.swap_adjustment_strategy,
+    )?;
+    let automation_fee_rate = get_automation_fee_rate(deps.storage, &vault)?;
+
+    let swap_fee = received_amount * swap_fee_rate;
+    let automation_fee = (received_amount - swap_fee) * automation_fee_rate;
+
+    Ok(SimulateExecutionResponse {
+        sent: swap_amount,
+        received: Coin::new(received_amount.into(), vault.target_denom.clone()),
+        fee: Coin::new((swap_fee + automation_fee).into(), vault.target_denom),
+        skipped_reason: None,
+        termination_reason,
+    })
+}
+
+#[cfg(test)]
+mod simulate_execution_tests {
+    use super::simulate_execution_handler;
+    use crate::{
+        constants::{ONE, SWAP_FEE_RATE, TEN},
+        helpers::fees::{get_automation_fee_rate, get_swap_fee_rate},
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO},
+        },
+        types::{
+            event::ExecutionSkippedReason,
+            execution_window::ExecutionWindow,
+            swap_adjustment_strategy::SwapAdjustmentStrategy,
+            termination_condition::{TerminationAction, TerminationCondition, TerminationReason},
+            trigger::TriggerConfiguration,
+            vault::{Vault, VaultStatus},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        to_binary, Coin, Decimal, StdError, Uint128,
+    };
+    use osmosis_std::types::osmosis::poolmanager::v1beta1::EstimateSwapExactAmountInResponse;
+    use std::str::FromStr;
+
+    #[test]
+    fn with_cancelled_vault_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Cancelled,
+                ..Vault::default()
+            },
+        );
+
+        let err = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Generic error: vault with id {} is cancelled, and is not available for execution",
+                vault.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_no_trigger_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        let err = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Generic error: vault with id {} has no trigger attached, and is not available for execution",
+                vault.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_inactive_vault_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                ..Vault::default()
+            },
+        );
+
+        let err = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Generic error: vault with id {} is inactive, and will not swap when executed",
+                vault.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_time_trigger_not_yet_due_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: env.block.time.plus_seconds(60),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let err = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: trigger execution time has not yet elapsed"
+        );
+    }
+
+    #[test]
+    fn with_stop_loss_reached_with_refund_action_returns_termination_reason() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                stop_loss: Some(TerminationCondition {
+                    price: Decimal::percent(110),
+                    action: TerminationAction::Refund,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap();
+
+        assert_eq!(response.sent, Coin::new(0, DENOM_UOSMO));
+        assert_eq!(response.received, Coin::new(0, DENOM_STAKE));
+        assert_eq!(response.skipped_reason, None);
+        assert_eq!(
+            response.termination_reason,
+            Some(TerminationReason::StopLoss {
+                price: Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn with_stop_loss_reached_with_swap_action_sends_entire_balance() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn" => {
+                to_binary(&EstimateSwapExactAmountInResponse {
+                    token_out_amount: TEN.to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("invoke fallback")),
+        });
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                stop_loss: Some(TerminationCondition {
+                    price: Decimal::percent(110),
+                    action: TerminationAction::Swap,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap();
+
+        assert_eq!(response.sent, Coin::new(TEN.into(), DENOM_UOSMO));
+        assert_eq!(response.received, Coin::new(TEN.into(), DENOM_STAKE));
+        assert!(matches!(
+            response.termination_reason,
+            Some(TerminationReason::StopLoss { .. })
+        ));
+    }
+
+    #[test]
+    fn with_end_time_reached_returns_termination_reason() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                end_time: Some(env.block.time),
+                ..Vault::default()
+            },
+        );
+
+        let response = simulate_execution_handler(deps.as_ref(), env.clone(), vault.id).unwrap();
+
+        assert_eq!(response.sent, Coin::new(0, DENOM_UOSMO));
+        assert_eq!(
+            response.termination_reason,
+            Some(TerminationReason::EndTimeReached {
+                end_time: env.block.time
+            })
+        );
+    }
+
+    #[test]
+    fn with_max_executions_reached_returns_termination_reason() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                max_executions: Some(3),
+                execution_count: 3,
+                ..Vault::default()
+            },
+        );
+
+        let response = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap();
+
+        assert_eq!(response.sent, Coin::new(0, DENOM_UOSMO));
+        assert_eq!(
+            response.termination_reason,
+            Some(TerminationReason::MaxExecutionsReached { executions: 3 })
+        );
+    }
+
+    #[test]
+    fn with_swap_amount_adjusted_to_zero_returns_skipped_reason() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE + ONE,
+                    multiplier: Decimal::one(),
+                    increase_only: false,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap();
+
+        assert_eq!(response.sent, Coin::new(0, DENOM_UOSMO));
+        assert_eq!(
+            response.skipped_reason,
+            Some(ExecutionSkippedReason::SwapAmountAdjustedToZero)
+        );
+    }
+
+    #[test]
+    fn with_execution_window_excluding_current_time_returns_skipped_reason() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                execution_window: Some(ExecutionWindow {
+                    start_hour: 8,
+                    end_hour: 20,
+                    excluded_days: vec![],
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap();
+
+        assert_eq!(
+            response.skipped_reason,
+            Some(ExecutionSkippedReason::OutsideExecutionWindow)
+        );
+    }
+
+    #[test]
+    fn with_price_threshold_exceeded_returns_skipped_reason() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                minimum_receive_amount: Some(ONE + ONE),
+                ..Vault::default()
+            },
+        );
+
+        let response = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap();
+
+        assert!(matches!(
+            response.skipped_reason,
+            Some(ExecutionSkippedReason::PriceThresholdExceeded { .. })
+        ));
+        assert_eq!(response.received, Coin::new(0, DENOM_STAKE));
+    }
+
+    #[test]
+    fn with_slippage_tolerance_exceeded_returns_skipped_reason() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn" => {
+                to_binary(&EstimateSwapExactAmountInResponse {
+                    token_out_amount: (ONE * Decimal::percent(90)).to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("invoke fallback")),
+        });
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let response = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap();
+
+        assert_eq!(
+            response.skipped_reason,
+            Some(ExecutionSkippedReason::SlippageToleranceExceeded)
+        );
+    }
+
+    #[test]
+    fn returns_expected_sent_received_and_fee_amounts() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let response = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap();
+
+        let swap_fee_rate = get_swap_fee_rate(
+            deps.as_ref().storage,
+            vault.get_swap_denom(),
+            vault.target_denom.clone(),
+            &vault.swap_adjustment_strategy,
+        )
+        .unwrap();
+        let automation_fee_rate = get_automation_fee_rate(deps.as_ref().storage, &vault).unwrap();
+
+        let swap_fee = ONE * swap_fee_rate;
+        let automation_fee = (ONE - swap_fee) * automation_fee_rate;
+
+        assert_eq!(response.skipped_reason, None);
+        assert_eq!(response.sent, Coin::new(ONE.into(), DENOM_UOSMO));
+        assert_eq!(response.received, Coin::new(ONE.into(), DENOM_STAKE));
+        assert_eq!(
+            response.fee,
+            Coin::new((swap_fee + automation_fee).into(), DENOM_STAKE)
+        );
+    }
+
+    #[test]
+    fn with_balance_less_than_swap_amount_sends_remaining_balance() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let balance = Uint128::new(ONE.u128() / 2);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(balance.into(), DENOM_UOSMO),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                ..Vault::default()
+            },
+        );
+
+        let response = simulate_execution_handler(deps.as_ref(), env, vault.id).unwrap();
+
+        assert_eq!(response.sent, Coin::new(balance.into(), DENOM_UOSMO));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::error::ContractError;
+use crate::helpers::validation::{assert_new_admin_is_valid, assert_sender_is_admin};
+use crate::state::config::{get_config, update_config};
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response};
+
+pub fn transfer_admin_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_admin: Addr,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+    deps.api.addr_validate(new_admin.as_str())?;
+
+    let mut config = get_config(deps.storage)?;
+
+    assert_new_admin_is_valid(&config.admin, &new_admin)?;
+
+    config.pending_admin = Some(new_admin.clone());
+
+    update_config(deps.storage, config.clone())?;
+
+    Ok(Response::new()
+        .add_attribute("transfer_admin", "true")
+        .add_attribute("admin", config.admin)
+        .add_attribute("pending_admin", new_admin))
+}
+
+#[cfg(test)]
+mod transfer_admin_tests {
+    use super::*;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    const NEW_ADMIN: &str = "new-admin";
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = transfer_admin_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            Addr::unchecked(NEW_ADMIN),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_current_admin_as_new_admin_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err =
+            transfer_admin_handler(deps.as_mut(), mock_info(ADMIN, &[]), Addr::unchecked(ADMIN))
+                .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: new admin must be different to the current admin"
+        );
+    }
+
+    #[test]
+    fn sets_pending_admin_without_changing_admin() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        transfer_admin_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(NEW_ADMIN),
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.admin, Addr::unchecked(ADMIN));
+        assert_eq!(config.pending_admin, Some(Addr::unchecked(NEW_ADMIN)));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::error::ContractError;
+use crate::helpers::validation::{
+    assert_new_vault_owner_is_valid, assert_vault_is_not_cancelled, asset_sender_is_vault_owner,
+};
+use crate::state::vaults::{get_vault, update_vault};
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response, Uint128};
+
+pub fn transfer_vault_ownership_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    vault_id: Uint128,
+    new_owner: Addr,
+) -> Result<Response, ContractError> {
+    let mut vault = get_vault(deps.storage, vault_id)?;
+
+    asset_sender_is_vault_owner(vault.owner.clone(), info.sender)?;
+    assert_vault_is_not_cancelled(&vault)?;
+    deps.api.addr_validate(new_owner.as_str())?;
+    assert_new_vault_owner_is_valid(vault.owner.clone(), new_owner.clone())?;
+
+    vault.pending_owner = Some(new_owner.clone());
+
+    update_vault(deps.storage, vault.clone())?;
+
+    Ok(Response::new()
+        .add_attribute("transfer_vault_ownership", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("owner", vault.owner)
+        .add_attribute("pending_owner", new_owner))
+}
+
+#[cfg(test)]
+mod transfer_vault_ownership_tests {
+    use super::*;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::vault::{Vault, VaultStatus};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    const NEW_OWNER: &str = "new-owner";
+
+    #[test]
+    fn with_non_owner_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        let err = transfer_vault_ownership_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            Addr::unchecked(NEW_OWNER),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_cancelled_vault_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                status: VaultStatus::Cancelled,
+                ..Vault::default()
+            },
+        );
+
+        let err = transfer_vault_ownership_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            vault.id,
+            Addr::unchecked(NEW_OWNER),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: vault is already cancelled");
+    }
+
+    #[test]
+    fn with_current_owner_as_new_owner_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        let err = transfer_vault_ownership_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            vault.id,
+            Addr::unchecked(USER),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: new owner must be different to the current vault owner"
+        );
+    }
+
+    #[test]
+    fn sets_pending_owner_without_changing_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        transfer_vault_ownership_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            vault.id,
+            Addr::unchecked(NEW_OWNER),
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.owner, vault.owner);
+        assert_eq!(
+            updated_vault.pending_owner,
+            Some(Addr::unchecked(NEW_OWNER))
+        );
+    }
+}
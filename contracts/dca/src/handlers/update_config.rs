@@ -3,13 +3,14 @@ use crate::{
     helpers::validation::{
         assert_addresses_are_valid, assert_fee_collector_addresses_are_valid,
         assert_fee_collector_allocations_add_up_to_one, assert_fee_level_is_valid,
-        assert_no_more_than_10_fee_collectors, assert_page_limit_is_valid,
+        assert_keeper_reward_percent_is_valid, assert_no_more_than_10_fee_collectors,
+        assert_page_limit_is_valid, assert_retry_policy_is_valid,
         assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent,
         assert_sender_is_admin, assert_slippage_tolerance_is_less_than_or_equal_to_one,
         assert_twap_period_is_valid,
     },
     state::config::{get_config, update_config},
-    types::{config::Config, fee_collector::FeeCollector},
+    types::{config::Config, fee_collector::FeeCollector, retry_policy::RetryPolicy},
 };
 use cosmwasm_std::{Addr, Decimal, DepsMut, MessageInfo, Response};
 
@@ -26,6 +27,11 @@ pub fn update_config_handler(
     risk_weighted_average_escrow_level: Option<Decimal>,
     twap_period: Option<u64>,
     default_slippage_tolerance: Option<Decimal>,
+    retry_policy: Option<RetryPolicy>,
+    keeper_reward_percent: Option<Decimal>,
+    pair_managers: Option<Vec<Addr>>,
+    fee_managers: Option<Vec<Addr>>,
+    pausers: Option<Vec<Addr>>,
 ) -> Result<Response, ContractError> {
     assert_sender_is_admin(deps.storage, info.sender)?;
     let existing_config = get_config(deps.storage)?;
@@ -47,6 +53,12 @@ pub fn update_config_handler(
         twap_period: twap_period.unwrap_or(existing_config.twap_period),
         default_slippage_tolerance: default_slippage_tolerance
             .unwrap_or(existing_config.default_slippage_tolerance),
+        retry_policy: retry_policy.or(existing_config.retry_policy),
+        keeper_reward_percent: keeper_reward_percent.or(existing_config.keeper_reward_percent),
+        pending_admin: existing_config.pending_admin,
+        pair_managers: pair_managers.unwrap_or(existing_config.pair_managers),
+        fee_managers: fee_managers.unwrap_or(existing_config.fee_managers),
+        pausers: pausers.unwrap_or(existing_config.pausers),
     };
 
     assert_fee_level_is_valid(&config.default_swap_fee_percent)?;
@@ -55,7 +67,16 @@ pub fn update_config_handler(
     assert_page_limit_is_valid(Some(config.default_page_limit))?;
     assert_slippage_tolerance_is_less_than_or_equal_to_one(config.default_slippage_tolerance)?;
     assert_twap_period_is_valid(config.twap_period)?;
+    if let Some(retry_policy) = &config.retry_policy {
+        assert_retry_policy_is_valid(retry_policy)?;
+    }
+    if let Some(keeper_reward_percent) = config.keeper_reward_percent {
+        assert_keeper_reward_percent_is_valid(keeper_reward_percent)?;
+    }
     assert_addresses_are_valid(deps.as_ref(), &config.executors, "executor")?;
+    assert_addresses_are_valid(deps.as_ref(), &config.pair_managers, "pair manager")?;
+    assert_addresses_are_valid(deps.as_ref(), &config.fee_managers, "fee manager")?;
+    assert_addresses_are_valid(deps.as_ref(), &config.pausers, "pauser")?;
     assert_no_more_than_10_fee_collectors(&config.fee_collectors)?;
     assert_fee_collector_addresses_are_valid(deps.as_ref(), &config.fee_collectors)?;
     assert_fee_collector_allocations_add_up_to_one(&config.fee_collectors)?;
@@ -105,6 +126,11 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -123,15 +149,17 @@ mod update_config_tests {
 
         instantiate_contract(deps.as_mut(), mock_env(), info.clone());
 
-        let executors = Some(vec![
-            Addr::unchecked("executor-1"),
-            Addr::unchecked("executor-2"),
-        ]);
+        let executors = vec![Addr::unchecked("executor-1"), Addr::unchecked("executor-2")];
 
         update_config_handler(
             deps.as_mut(),
             info,
-            executors.clone(),
+            Some(executors.clone()),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -146,7 +174,7 @@ mod update_config_tests {
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
-        assert_eq!(config.executors, executors.unwrap());
+        assert_eq!(config.executors, executors);
     }
 
     #[test]
@@ -169,6 +197,11 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -197,6 +230,11 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -223,6 +261,11 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -251,6 +294,11 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -279,6 +327,11 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -297,7 +350,7 @@ mod update_config_tests {
 
         instantiate_contract(deps.as_mut(), mock_env(), info.clone());
 
-        let fee_collectors = Some(vec![
+        let fee_collectors = vec![
             FeeCollector {
                 address: ADMIN.to_string(),
                 allocation: Decimal::from_str("0.9").unwrap(),
@@ -306,13 +359,18 @@ mod update_config_tests {
                 address: ADMIN.to_string(),
                 allocation: Decimal::from_str("0.1").unwrap(),
             },
-        ]);
+        ];
 
         update_config_handler(
             deps.as_mut(),
             info,
             None,
-            fee_collectors.clone(),
+            Some(fee_collectors.clone()),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -326,7 +384,7 @@ mod update_config_tests {
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
-        assert_eq!(config.fee_collectors, fee_collectors.unwrap());
+        assert_eq!(config.fee_collectors, fee_collectors);
     }
 
     #[test]
@@ -358,6 +416,11 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -387,6 +450,11 @@ mod update_config_tests {
             Some(Decimal::percent(19)),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -418,6 +486,11 @@ mod update_config_tests {
             Some(Decimal::percent(150)),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -447,6 +520,11 @@ mod update_config_tests {
             None,
             None,
             Some(Decimal::percent(150)),
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -456,6 +534,150 @@ mod update_config_tests {
         )
     }
 
+    #[test]
+    fn with_retry_policy_backoff_less_than_60_seconds_should_fail() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let err = update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(RetryPolicy {
+                max_retries: 3,
+                backoff_seconds: 59,
+            }),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: retry policy backoff must be at least 60 seconds"
+        )
+    }
+
+    #[test]
+    fn with_retry_policy_should_save_it_to_config() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let retry_policy = RetryPolicy {
+            max_retries: 3,
+            backoff_seconds: 60 * 60,
+        };
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(retry_policy.clone()),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.retry_policy, Some(retry_policy));
+    }
+
+    #[test]
+    fn with_role_addresses_should_save_them_to_config() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![Addr::unchecked("pair-manager")]),
+            Some(vec![Addr::unchecked("fee-manager")]),
+            Some(vec![Addr::unchecked("pauser")]),
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.pair_managers, vec![Addr::unchecked("pair-manager")]);
+        assert_eq!(config.fee_managers, vec![Addr::unchecked("fee-manager")]);
+        assert_eq!(config.pausers, vec![Addr::unchecked("pauser")]);
+    }
+
+    #[test]
+    fn with_keeper_reward_percent_more_than_100_percent_should_fail() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let err = update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(101)),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: keeper_reward_percent must be less than or equal to 100%, and expressed as a ratio out of 1 (i.e. use 0.1 to represent a reward of 10% of the fee)"
+        )
+    }
+
     #[test]
     fn with_more_than_10_fee_collectors_should_fail() {
         let mut deps = mock_dependencies();
@@ -482,6 +704,11 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -511,6 +738,11 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
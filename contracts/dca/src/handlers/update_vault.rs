@@ -1,24 +1,27 @@
 use crate::{
     error::ContractError,
     helpers::validation::{
-        assert_destination_allocations_add_up_to_one,
+        assert_destination_actions_are_valid, assert_destination_allocations_add_up_to_one,
         assert_destination_callback_addresses_are_valid, assert_destinations_limit_is_not_breached,
         assert_label_is_no_longer_than_100_characters, assert_no_destination_allocations_are_zero,
-        assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_time_interval_is_valid,
+        assert_slippage_tolerance_is_less_than_or_equal_to_one,
+        assert_termination_conditions_are_valid, assert_time_interval_is_valid,
         assert_vault_is_not_cancelled, assert_weighted_scale_multiplier_is_no_more_than_10,
         asset_sender_is_vault_owner,
     },
     state::vaults::{get_vault, update_vault},
     types::{
-        destination::Destination,
+        destination::{Destination, DestinationAction},
         swap_adjustment_strategy::{SwapAdjustmentStrategy, SwapAdjustmentStrategyParams},
+        termination_condition::TerminationCondition,
         time_interval::TimeInterval,
     },
 };
-use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Response, Uint128};
+use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response, Uint128};
 
 pub fn update_vault_handler(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     vault_id: Uint128,
     label: Option<String>,
@@ -27,6 +30,10 @@ pub fn update_vault_handler(
     minimum_receive_amount: Option<Uint128>,
     time_interval: Option<TimeInterval>,
     swap_adjustment_strategy: Option<SwapAdjustmentStrategyParams>,
+    stop_loss: Option<TerminationCondition>,
+    take_profit: Option<TerminationCondition>,
+    clear_stop_loss: Option<bool>,
+    clear_take_profit: Option<bool>,
 ) -> Result<Response, ContractError> {
     let mut vault = get_vault(deps.storage, vault_id)?;
 
@@ -50,12 +57,18 @@ pub fn update_vault_handler(
             destinations.push(Destination {
                 allocation: Decimal::percent(100),
                 address: vault.owner.clone(),
-                msg: None,
+                action: DestinationAction::Send {},
             });
         }
 
         assert_destinations_limit_is_not_breached(&destinations)?;
         assert_destination_callback_addresses_are_valid(deps.as_ref(), &destinations)?;
+        assert_destination_actions_are_valid(
+            deps.storage,
+            &destinations,
+            &env.contract.address,
+            &vault.target_denom,
+        )?;
         assert_no_destination_allocations_are_zero(&destinations)?;
         assert_destination_allocations_add_up_to_one(&destinations)?;
 
@@ -95,25 +108,37 @@ pub fn update_vault_handler(
                 })
             }
             _ => {
-                return Err(ContractError::CustomError {
-                    val: format!(
-                        "cannot update swap adjustment strategy from {:?} to {:?}",
-                        vault.swap_adjustment_strategy, swap_adjustment_strategy
-                    ),
+                return Err(ContractError::InvalidSwapAdjustmentStrategyUpdate {
+                    from: format!("{:?}", vault.swap_adjustment_strategy),
+                    to: format!("{:?}", swap_adjustment_strategy),
                 })
             }
         },
         Some(swap_adjustment_strategy) => {
-            return Err(ContractError::CustomError {
-                val: format!(
-                    "cannot update swap adjustment strategy from {:?} to {:?}",
-                    vault.swap_adjustment_strategy, swap_adjustment_strategy
-                ),
+            return Err(ContractError::InvalidSwapAdjustmentStrategyUpdate {
+                from: format!("{:?}", vault.swap_adjustment_strategy),
+                to: format!("{:?}", swap_adjustment_strategy),
             })
         }
         _ => {}
     }
 
+    let clear_stop_loss = clear_stop_loss.unwrap_or(false);
+    let clear_take_profit = clear_take_profit.unwrap_or(false);
+
+    if stop_loss.is_some() || take_profit.is_some() || clear_stop_loss || clear_take_profit {
+        let stop_loss = stop_loss.or(vault.stop_loss.clone().filter(|_| !clear_stop_loss));
+        let take_profit = take_profit.or(vault.take_profit.clone().filter(|_| !clear_take_profit));
+
+        assert_termination_conditions_are_valid(&stop_loss, &take_profit)?;
+
+        vault.stop_loss = stop_loss.clone();
+        vault.take_profit = take_profit.clone();
+        response = response
+            .add_attribute("stop_loss", format!("{:?}", stop_loss))
+            .add_attribute("take_profit", format!("{:?}", take_profit));
+    }
+
     update_vault(deps.storage, vault)?;
     Ok(response)
 }
@@ -128,18 +153,19 @@ mod update_vault_tests {
             mocks::{ADMIN, USER},
         },
         types::{
-            destination::Destination,
+            destination::{Destination, DestinationAction},
             position_type::PositionType,
             swap_adjustment_strategy::{
                 BaseDenom, SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
             },
+            termination_condition::{TerminationAction, TerminationCondition},
             time_interval::TimeInterval,
             vault::{Vault, VaultStatus},
         },
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, Decimal, Uint128,
+        to_binary, Addr, Decimal, Uint128,
     };
 
     #[test]
@@ -152,6 +178,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -160,6 +187,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -179,6 +210,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -187,6 +219,10 @@ mod update_vault_tests {
             None,
             Some(TimeInterval::Custom { seconds: 12 }),
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -208,6 +244,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             label.clone(),
@@ -216,6 +253,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -244,6 +285,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             label.clone(),
@@ -252,6 +294,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -275,6 +321,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             label.clone(),
@@ -283,6 +330,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -299,13 +350,14 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
-                msg: None,
+                action: DestinationAction::Send {},
             };
             11
         ];
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -314,6 +366,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -323,6 +379,119 @@ mod update_vault_tests {
         );
     }
 
+    #[test]
+    fn with_execute_contract_action_against_the_dca_contract_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            Some(vec![Destination {
+                address: env.contract.address,
+                allocation: Decimal::percent(100),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary("callback").unwrap(),
+                },
+            }]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Cannot invoke provided destination callback against the DCA contract"
+        );
+    }
+
+    #[test]
+    fn with_delegate_action_for_non_bond_denom_fails() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(deps.as_mut(), mock_env(), Vault::default());
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            Some(vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::Delegate {
+                    validator_address: Addr::unchecked("validator"),
+                },
+            }]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: {} is not the bond denomination", vault.target_denom)
+        );
+    }
+
+    #[test]
+    fn with_deposit_to_vault_action_for_different_denom_fails() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(deps.as_mut(), mock_env(), Vault::default());
+        let other_vault = setup_vault(deps.as_mut(), mock_env(), Vault::default());
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            Some(vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::DepositToVault {
+                    vault_id: other_vault.id,
+                },
+            }]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: received asset with denom {}, but needed {}",
+                vault.target_denom, other_vault.balance.denom
+            )
+        );
+    }
+
     #[test]
     fn with_destination_allocations_less_than_100_percent_fails() {
         let mut deps = mock_dependencies();
@@ -333,17 +502,18 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
-                msg: None,
+                action: DestinationAction::Send {},
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
-                msg: None,
+                action: DestinationAction::Send {},
             },
         ];
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -352,6 +522,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -371,17 +545,18 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
-                msg: None,
+                action: DestinationAction::Send {},
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(51),
-                msg: None,
+                action: DestinationAction::Send {},
             },
         ];
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -390,6 +565,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -409,17 +588,18 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(100),
-                msg: None,
+                action: DestinationAction::Send {},
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::zero(),
-                msg: None,
+                action: DestinationAction::Send {},
             },
         ];
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -428,6 +608,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -462,6 +646,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -470,6 +655,10 @@ mod update_vault_tests {
             None,
             None,
             Some(new_swap_adjustment_strategy.clone()),
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -509,6 +698,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -517,6 +707,10 @@ mod update_vault_tests {
             None,
             None,
             new_swap_adjustment_strategy.clone(),
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -543,6 +737,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -551,6 +746,10 @@ mod update_vault_tests {
             None,
             None,
             strategy.clone(),
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -592,6 +791,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -600,6 +800,10 @@ mod update_vault_tests {
             None,
             None,
             strategy,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -625,6 +829,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             label.clone(),
@@ -633,6 +838,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -651,17 +860,18 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
-                msg: None,
+                action: DestinationAction::Send {},
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
-                msg: None,
+                action: DestinationAction::Send {},
             },
         ];
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -670,6 +880,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -687,6 +901,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -695,6 +910,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -706,7 +925,7 @@ mod update_vault_tests {
             vec![Destination {
                 address: vault.owner,
                 allocation: Decimal::percent(100),
-                msg: None,
+                action: DestinationAction::Send {},
             }]
         );
     }
@@ -721,6 +940,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -729,6 +949,10 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -747,6 +971,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -755,6 +980,10 @@ mod update_vault_tests {
             minimum_receive_amount,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -775,6 +1004,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -783,6 +1013,10 @@ mod update_vault_tests {
             None,
             Some(time_interval.clone()),
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -790,4 +1024,133 @@ mod update_vault_tests {
 
         assert_eq!(updated_vault.time_interval, time_interval);
     }
+
+    #[test]
+    fn updates_termination_conditions() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(deps.as_mut(), mock_env(), Vault::default());
+
+        let stop_loss = Some(TerminationCondition {
+            price: Decimal::percent(80),
+            action: TerminationAction::Refund,
+        });
+
+        let take_profit = Some(TerminationCondition {
+            price: Decimal::percent(120),
+            action: TerminationAction::Swap,
+        });
+
+        update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            stop_loss.clone(),
+            take_profit.clone(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.stop_loss, stop_loss);
+        assert_eq!(updated_vault.take_profit, take_profit);
+    }
+
+    #[test]
+    fn clears_stop_loss_and_keeps_take_profit() {
+        let mut deps = mock_dependencies();
+
+        let take_profit = Some(TerminationCondition {
+            price: Decimal::percent(120),
+            action: TerminationAction::Swap,
+        });
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            mock_env(),
+            Vault {
+                stop_loss: Some(TerminationCondition {
+                    price: Decimal::percent(80),
+                    action: TerminationAction::Refund,
+                }),
+                take_profit: take_profit.clone(),
+                ..Vault::default()
+            },
+        );
+
+        update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.stop_loss, None);
+        assert_eq!(updated_vault.take_profit, take_profit);
+    }
+
+    #[test]
+    fn with_stop_loss_above_existing_take_profit_fails() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            mock_env(),
+            Vault {
+                take_profit: Some(TerminationCondition {
+                    price: Decimal::percent(120),
+                    action: TerminationAction::Swap,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(TerminationCondition {
+                price: Decimal::percent(130),
+                action: TerminationAction::Refund,
+            }),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: stop_loss price must be less than take_profit price"
+        );
+    }
 }
@@ -0,0 +1,496 @@
+use crate::error::ContractError;
+use crate::helpers::coin::subtract_from;
+use crate::helpers::time::get_next_target_time;
+use crate::helpers::validation::{
+    assert_vault_is_not_cancelled, assert_withdrawal_amount_is_valid, asset_sender_is_vault_owner,
+};
+use crate::msg::ExecuteMsg;
+use crate::state::events::create_event;
+use crate::state::triggers::{delete_trigger, save_trigger};
+use crate::state::vaults::{get_vault, update_vault};
+use crate::types::event::{EventBuilder, EventData};
+use crate::types::trigger::{Trigger, TriggerConfiguration};
+use crate::types::vault::VaultStatus;
+use cosmwasm_std::{
+    to_binary, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128, WasmMsg,
+};
+
+pub fn withdraw_from_vault_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let mut vault = get_vault(deps.storage, vault_id)?;
+
+    asset_sender_is_vault_owner(vault.owner.clone(), info.sender)?;
+    assert_vault_is_not_cancelled(&vault)?;
+    assert_withdrawal_amount_is_valid(&vault, amount)?;
+
+    let withdrawn_amount = Coin::new(amount.into(), vault.get_swap_denom());
+
+    vault.balance = subtract_from(vault.balance, amount);
+    vault.deposited_amount = subtract_from(vault.deposited_amount, amount);
+
+    let was_paused = vault.is_paused();
+
+    if vault.balance.amount.is_zero()
+        && (vault.is_active() || vault.is_scheduled() || vault.is_paused())
+    {
+        vault.status = VaultStatus::Inactive;
+    }
+
+    update_vault(deps.storage, vault.clone())?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            vault.id,
+            env.block.clone(),
+            EventData::DcaVaultFundsWithdrawn {
+                amount: withdrawn_amount.clone(),
+            },
+        ),
+    )?;
+
+    let mut sub_msgs = vec![SubMsg::new(BankMsg::Send {
+        to_address: vault.owner.to_string(),
+        amount: vec![withdrawn_amount],
+    })];
+
+    if vault.should_not_continue() {
+        if vault.escrowed_amount.amount > Uint128::zero() {
+            sub_msgs.push(SubMsg::new(WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id })?,
+                funds: vec![],
+            }));
+        }
+
+        delete_trigger(deps.storage, vault.id)?;
+    } else if was_paused && vault.is_inactive() {
+        // paused vaults have no trigger, so the standard dca comparison needs a new one to finish
+        save_trigger(
+            deps.storage,
+            Trigger {
+                vault_id: vault.id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: get_next_target_time(
+                        env.block.time,
+                        vault.started_at.unwrap_or(env.block.time),
+                        vault.time_interval.clone(),
+                    ),
+                },
+            },
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("withdraw_from_vault", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("owner", vault.owner)
+        .add_attribute("withdrawn_amount", amount)
+        .add_submessages(sub_msgs))
+}
+
+#[cfg(test)]
+mod withdraw_from_vault_tests {
+    use super::*;
+    use crate::constants::{ONE, TEN};
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::handlers::pause_vault::pause_vault_handler;
+    use crate::handlers::resume_vault::resume_vault_handler;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, DENOM_STAKE, DENOM_UOSMO, USER};
+    use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategy;
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_owner_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err =
+            withdraw_from_vault_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), vault.id, ONE)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_cancelled_vault_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Cancelled,
+                ..Vault::default()
+            },
+        );
+
+        let err =
+            withdraw_from_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id, ONE)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: vault is already cancelled");
+    }
+
+    #[test]
+    fn with_zero_amount_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            Uint128::zero(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: withdrawal amount must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_amount_larger_than_balance_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            TEN + ONE,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot withdraw 11000000 from vault with a balance of 10000000"
+        );
+    }
+
+    #[test]
+    fn with_amount_larger_than_standard_dca_balance_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new((TEN - ONE).into(), DENOM_UOSMO),
+                        received_amount: Coin::new((TEN - ONE).into(), DENOM_STAKE),
+                    },
+                ),
+                ..Vault::default()
+            },
+        );
+
+        let err = withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            ONE + ONE,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot withdraw more than 1000000 while the vault performance is being compared to a standard dca"
+        );
+    }
+
+    #[test]
+    fn sends_withdrawn_amount_to_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let response =
+            withdraw_from_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id, ONE)
+                .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![Coin::new(ONE.into(), vault.get_swap_denom())],
+            })]
+        );
+    }
+
+    #[test]
+    fn reduces_balance_and_deposited_amount() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        withdraw_from_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id, ONE)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.balance.amount, vault.balance.amount - ONE);
+        assert_eq!(
+            updated_vault.deposited_amount.amount,
+            vault.deposited_amount.amount - ONE
+        );
+        assert_eq!(updated_vault.status, VaultStatus::Active);
+        assert!(updated_vault.trigger.is_some());
+    }
+
+    #[test]
+    fn publishes_withdrawal_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        withdraw_from_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            ONE,
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultFundsWithdrawn {
+                    amount: Coin::new(ONE.into(), vault.get_swap_denom()),
+                },
+            )
+            .build(1),
+        ));
+    }
+
+    #[test]
+    fn withdrawing_entire_balance_makes_vault_inactive_and_deletes_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            vault.balance.amount,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.balance.amount, Uint128::zero());
+        assert_eq!(updated_vault.trigger, None);
+    }
+
+    #[test]
+    fn withdrawing_entire_balance_of_paused_vault_makes_vault_inactive() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                trigger: None,
+                escrowed_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                ..Vault::default()
+            },
+        );
+
+        let response = withdraw_from_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            vault.balance.amount,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.trigger, None);
+        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id }).unwrap(),
+            funds: vec![],
+        })));
+    }
+
+    #[test]
+    fn withdrawing_entire_balance_of_paused_vault_reschedules_standard_dca_comparison() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                trigger: None,
+                balance: Coin::new((TEN - ONE).into(), DENOM_UOSMO),
+                swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new((ONE / Uint128::new(2)).into(), DENOM_UOSMO),
+                        received_amount: Coin::new((ONE / Uint128::new(2)).into(), DENOM_STAKE),
+                    },
+                ),
+                ..Vault::default()
+            },
+        );
+
+        withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            TEN - ONE,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert!(updated_vault.trigger.is_some());
+    }
+
+    #[test]
+    fn pausing_then_withdrawing_entire_balance_prevents_resuming() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        pause_vault_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), vault.id).unwrap();
+
+        withdraw_from_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            vault.balance.amount,
+        )
+        .unwrap();
+
+        let err =
+            resume_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap_err();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(err.to_string(), "Error: only paused vaults can be resumed");
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.trigger, None);
+    }
+
+    #[test]
+    fn keeps_standard_dca_comparison_consistent() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new((TEN - ONE).into(), DENOM_UOSMO),
+                swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new((ONE + ONE).into(), DENOM_UOSMO),
+                        received_amount: Coin::new((ONE + ONE).into(), DENOM_STAKE),
+                    },
+                ),
+                ..Vault::default()
+            },
+        );
+
+        withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            ONE + ONE,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        let Some(PerformanceAssessmentStrategy::CompareToStandardDca {
+            swapped_amount: standard_dca_swapped_amount,
+            ..
+        }) = updated_vault.performance_assessment_strategy.clone()
+        else {
+            panic!("expected a compare to standard dca strategy")
+        };
+
+        assert_eq!(
+            updated_vault.deposited_amount.amount - updated_vault.swapped_amount.amount,
+            updated_vault.balance.amount
+        );
+        assert_eq!(
+            updated_vault.deposited_amount.amount - standard_dca_swapped_amount.amount,
+            TEN - (ONE + ONE) - (ONE + ONE)
+        );
+    }
+}
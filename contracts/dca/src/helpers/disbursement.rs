@@ -1,88 +1,177 @@
 use super::math::checked_mul;
 use crate::{
-    constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
+    constants::{
+        AFTER_FAILED_AUTOMATION_REPLY_ID, IBC_TRANSFER_PORT, IBC_TRANSFER_TIMEOUT_SECONDS,
+    },
+    msg::ExecuteMsg,
     state::cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
-    types::vault::Vault,
+    types::{destination::DestinationAction, ibc_transfer::MsgTransfer, vault::Vault},
 };
-use cosmwasm_std::{BankMsg, Coin, StdResult, Storage, SubMsg, Uint128, WasmMsg};
+use cosmwasm_std::{
+    to_binary, Addr, BankMsg, Coin, CosmosMsg, Env, StdError, StdResult, Storage, SubMsg, Uint128,
+    WasmMsg,
+};
+use serde_json::{Map, Value};
 use std::collections::VecDeque;
 
 pub fn get_disbursement_messages(
     store: &mut dyn Storage,
+    env: &Env,
     vault: &Vault,
     amount_to_disburse: Uint128,
 ) -> StdResult<VecDeque<SubMsg>> {
     let mut post_execution_action_caches = VecDeque::<PostExecutionActionCacheEntry>::new();
+    let mut messages = VecDeque::<SubMsg>::new();
 
-    let messages = vault
-        .destinations
-        .iter()
-        .flat_map(|destination| {
-            let allocation_amount = Coin::new(
-                checked_mul(amount_to_disburse, destination.allocation)
-                    .expect("amount to be distributed should be valid")
-                    .into(),
-                vault.target_denom.clone(),
-            );
-
-            if allocation_amount.amount.gt(&Uint128::zero()) {
-                let msg = destination.msg.clone().map_or(
-                    SubMsg::reply_always(
-                        BankMsg::Send {
-                            to_address: destination.address.to_string(),
-                            amount: vec![allocation_amount.clone()],
-                        },
-                        AFTER_FAILED_AUTOMATION_REPLY_ID,
-                    ),
-                    |msg| {
-                        SubMsg::reply_always(
-                            WasmMsg::Execute {
-                                contract_addr: destination.address.to_string(),
-                                msg,
-                                funds: vec![allocation_amount.clone()],
-                            },
-                            AFTER_FAILED_AUTOMATION_REPLY_ID,
-                        )
-                    },
-                );
+    for destination in vault.destinations.iter() {
+        let allocation_amount = Coin::new(
+            checked_mul(amount_to_disburse, destination.allocation)
+                .expect("amount to be distributed should be valid")
+                .into(),
+            vault.target_denom.clone(),
+        );
 
-                post_execution_action_caches.push_back(PostExecutionActionCacheEntry {
-                    msg: msg.clone(),
-                    funds: vec![allocation_amount],
-                });
+        if allocation_amount.amount.is_zero() {
+            continue;
+        }
 
-                return Some(msg);
-            }
+        let msg = SubMsg::reply_always(
+            get_destination_message(
+                env,
+                &destination.address,
+                destination.action.clone(),
+                allocation_amount.clone(),
+            )?,
+            AFTER_FAILED_AUTOMATION_REPLY_ID,
+        );
 
-            None
-        })
-        .collect::<VecDeque<SubMsg>>();
+        post_execution_action_caches.push_back(PostExecutionActionCacheEntry {
+            msg: msg.clone(),
+            funds: vec![allocation_amount],
+            destination: destination.clone(),
+        });
+
+        messages.push_back(msg);
+    }
 
     POST_EXECUTION_ACTION_CACHE.save(store, vault.id.into(), &post_execution_action_caches)?;
 
     Ok(messages)
 }
 
+pub fn get_destination_message(
+    env: &Env,
+    address: &Addr,
+    action: DestinationAction,
+    funds: Coin,
+) -> StdResult<CosmosMsg> {
+    let contract_msg = match action {
+        DestinationAction::Send {} => {
+            return Ok(BankMsg::Send {
+                to_address: address.to_string(),
+                amount: vec![funds],
+            }
+            .into())
+        }
+        DestinationAction::ExecuteContract { msg } => {
+            return Ok(WasmMsg::Execute {
+                contract_addr: address.to_string(),
+                msg,
+                funds: vec![funds],
+            }
+            .into())
+        }
+        DestinationAction::Delegate { validator_address } => ExecuteMsg::ZDelegate {
+            delegator_address: address.clone(),
+            validator_address,
+        },
+        DestinationAction::ProvideLiquidity {
+            pool_id,
+            duration,
+            slippage_tolerance,
+        } => ExecuteMsg::ZProvideLiquidity {
+            provider_address: address.clone(),
+            pool_id,
+            duration,
+            slippage_tolerance,
+        },
+        DestinationAction::DepositToVault { vault_id } => ExecuteMsg::Deposit {
+            address: address.clone(),
+            vault_id,
+        },
+        DestinationAction::IbcTransfer {
+            channel_id,
+            receiver,
+            memo,
+        } => {
+            return Ok(MsgTransfer {
+                source_port: IBC_TRANSFER_PORT.to_string(),
+                source_channel: channel_id,
+                token: Some(funds.into()),
+                sender: env.contract.address.to_string(),
+                receiver,
+                timeout_timestamp: env
+                    .block
+                    .time
+                    .plus_seconds(IBC_TRANSFER_TIMEOUT_SECONDS)
+                    .nanos(),
+                memo: get_ibc_callback_memo(&env.contract.address, memo)?,
+            }
+            .into())
+        }
+    };
+
+    Ok(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&contract_msg)?,
+        funds: vec![funds],
+    }
+    .into())
+}
+
+fn get_ibc_callback_memo(contract_address: &Addr, memo: Option<String>) -> StdResult<String> {
+    let mut memo = match memo {
+        Some(memo) => serde_json::from_str::<Map<String, Value>>(&memo)
+            .map_err(|err| StdError::parse_err("ibc transfer memo", err))?,
+        None => Map::new(),
+    };
+
+    memo.insert(
+        "ibc_callback".to_string(),
+        Value::String(contract_address.to_string()),
+    );
+
+    serde_json::to_string(&memo).map_err(|err| StdError::serialize_err("ibc transfer memo", err))
+}
+
 #[cfg(test)]
 mod get_disbursement_messages_tests {
     use super::get_disbursement_messages;
     use crate::{
-        constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, ONE},
+        constants::{
+            AFTER_FAILED_AUTOMATION_REPLY_ID, IBC_TRANSFER_PORT, IBC_TRANSFER_TIMEOUT_SECONDS, ONE,
+        },
+        msg::ExecuteMsg,
         state::cache::POST_EXECUTION_ACTION_CACHE,
-        types::{destination::Destination, vault::Vault},
+        types::{
+            destination::{Destination, DestinationAction},
+            ibc_transfer::MsgTransfer,
+            vault::Vault,
+        },
     };
     use cosmwasm_std::{
-        testing::mock_dependencies, to_binary, Addr, BankMsg, Coin, Decimal, SubMsg, WasmMsg,
+        testing::{mock_dependencies, mock_env},
+        to_binary, Addr, BankMsg, Coin, Decimal, SubMsg, Uint128, WasmMsg,
     };
 
     #[test]
-    fn generates_bank_sends_for_destinations_with_no_msg() {
+    fn generates_bank_sends_for_send_destinations() {
         let mut deps = mock_dependencies();
 
         let destination = Destination {
             address: Addr::unchecked("test"),
             allocation: Decimal::percent(100),
-            msg: None,
+            action: DestinationAction::Send {},
         };
 
         let vault = Vault {
@@ -90,7 +179,8 @@ mod get_disbursement_messages_tests {
             ..Vault::default()
         };
 
-        let messages = get_disbursement_messages(deps.as_mut().storage, &vault, ONE).unwrap();
+        let messages =
+            get_disbursement_messages(deps.as_mut().storage, &mock_env(), &vault, ONE).unwrap();
 
         assert!(messages.contains(&SubMsg::reply_always(
             BankMsg::Send {
@@ -101,27 +191,129 @@ mod get_disbursement_messages_tests {
         )))
     }
 
+    #[test]
+    fn generates_contract_executions_for_contract_actions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = Vault {
+            destinations: vec![
+                Destination {
+                    address: Addr::unchecked("delegator"),
+                    allocation: Decimal::percent(50),
+                    action: DestinationAction::Delegate {
+                        validator_address: Addr::unchecked("validator"),
+                    },
+                },
+                Destination {
+                    address: Addr::unchecked("owner"),
+                    allocation: Decimal::percent(50),
+                    action: DestinationAction::DepositToVault {
+                        vault_id: Uint128::new(2),
+                    },
+                },
+            ],
+            ..Vault::default()
+        };
+
+        let messages = get_disbursement_messages(deps.as_mut().storage, &env, &vault, ONE).unwrap();
+
+        let funds = vec![Coin::new(
+            (ONE * Decimal::percent(50)).into(),
+            vault.target_denom,
+        )];
+
+        assert_eq!(
+            messages,
+            vec![
+                SubMsg::reply_always(
+                    WasmMsg::Execute {
+                        contract_addr: env.contract.address.to_string(),
+                        msg: to_binary(&ExecuteMsg::ZDelegate {
+                            delegator_address: Addr::unchecked("delegator"),
+                            validator_address: Addr::unchecked("validator"),
+                        })
+                        .unwrap(),
+                        funds: funds.clone(),
+                    },
+                    AFTER_FAILED_AUTOMATION_REPLY_ID
+                ),
+                SubMsg::reply_always(
+                    WasmMsg::Execute {
+                        contract_addr: env.contract.address.to_string(),
+                        msg: to_binary(&ExecuteMsg::Deposit {
+                            address: Addr::unchecked("owner"),
+                            vault_id: Uint128::new(2),
+                        })
+                        .unwrap(),
+                        funds,
+                    },
+                    AFTER_FAILED_AUTOMATION_REPLY_ID
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn generates_ibc_transfers_with_callback_memo_for_ibc_transfer_actions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = Vault {
+            destinations: vec![Destination {
+                address: Addr::unchecked("owner"),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::IbcTransfer {
+                    channel_id: "channel-0".to_string(),
+                    receiver: "cosmos1receiver".to_string(),
+                    memo: Some("{\"forward\":{}}".to_string()),
+                },
+            }],
+            ..Vault::default()
+        };
+
+        let messages = get_disbursement_messages(deps.as_mut().storage, &env, &vault, ONE).unwrap();
+
+        assert_eq!(
+            messages,
+            vec![SubMsg::reply_always(
+                MsgTransfer {
+                    source_port: IBC_TRANSFER_PORT.to_string(),
+                    source_channel: "channel-0".to_string(),
+                    token: Some(Coin::new(ONE.into(), vault.target_denom).into()),
+                    sender: env.contract.address.to_string(),
+                    receiver: "cosmos1receiver".to_string(),
+                    timeout_timestamp: env
+                        .block
+                        .time
+                        .plus_seconds(IBC_TRANSFER_TIMEOUT_SECONDS)
+                        .nanos(),
+                    memo: format!(
+                        "{{\"forward\":{{}},\"ibc_callback\":\"{}\"}}",
+                        env.contract.address
+                    ),
+                },
+                AFTER_FAILED_AUTOMATION_REPLY_ID
+            )]
+        );
+    }
+
     #[test]
     fn saves_disbursement_messages_to_cache_queue() {
         let mut deps = mock_dependencies();
 
+        let msg = to_binary("test").unwrap();
+
         let destinations = vec![
             Destination {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
-                msg: None,
+                action: DestinationAction::Send {},
             },
             Destination {
                 address: Addr::unchecked("contract"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract { msg: msg.clone() },
             },
         ];
 
@@ -130,7 +322,7 @@ mod get_disbursement_messages_tests {
             ..Vault::default()
         };
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, ONE).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &mock_env(), &vault, ONE).unwrap();
 
         let mut cache = POST_EXECUTION_ACTION_CACHE
             .load(deps.as_ref().storage, vault.id.into())
@@ -155,7 +347,7 @@ mod get_disbursement_messages_tests {
             SubMsg::reply_always(
                 WasmMsg::Execute {
                     contract_addr: destinations[1].address.to_string(),
-                    msg: destinations[1].msg.clone().unwrap(),
+                    msg,
                     funds: vec![Coin::new(
                         (ONE * destinations[1].allocation).into(),
                         vault.target_denom
@@ -0,0 +1,127 @@
+use super::{
+    time::{get_next_execution_window_time, is_within_execution_window},
+    validation::{assert_target_price_is_reached, assert_target_time_is_in_past},
+    vault::get_swap_amount,
+};
+use crate::{
+    error::ContractError,
+    types::{
+        event::ExecutionSkippedReason,
+        pair::Pair,
+        termination_condition::{TerminationAction, TerminationReason},
+        trigger::TriggerConfiguration,
+        vault::Vault,
+    },
+};
+use cosmwasm_std::{Coin, Decimal, Deps, Env, Timestamp, Uint128};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExecutionDecision {
+    OutsideExecutionWindow {
+        next_window_time: Timestamp,
+    },
+    Terminate {
+        reason: TerminationReason,
+        action: TerminationAction,
+    },
+    Swap,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SwapDecision {
+    Skip {
+        reason: ExecutionSkippedReason,
+    },
+    Swap {
+        swap_amount: Coin,
+        minimum_receive_amount: Option<Uint128>,
+    },
+}
+
+pub fn get_execution_decision(
+    env: &Env,
+    vault: &Vault,
+    pair: &Pair,
+    belief_price: Decimal,
+) -> Result<ExecutionDecision, ContractError> {
+    if vault.is_cancelled() {
+        return Err(ContractError::VaultCancelled { vault_id: vault.id });
+    }
+
+    match vault.trigger.clone() {
+        Some(TriggerConfiguration::Time { target_time }) => {
+            assert_target_time_is_in_past(env.block.time, target_time)?;
+        }
+        Some(TriggerConfiguration::Price {
+            target_price,
+            direction,
+        }) => {
+            assert_target_price_is_reached(belief_price, target_price, direction)?;
+        }
+        None => {
+            return Err(ContractError::VaultHasNoTrigger { vault_id: vault.id });
+        }
+    }
+
+    if let Some(execution_window) = &vault.execution_window {
+        if !is_within_execution_window(env.block.time, execution_window) {
+            return Ok(ExecutionDecision::OutsideExecutionWindow {
+                next_window_time: get_next_execution_window_time(env.block.time, execution_window),
+            });
+        }
+    }
+
+    if vault.is_active() || vault.is_scheduled() {
+        if let Some(reason) = vault.end_condition_met(env.block.time) {
+            return Ok(ExecutionDecision::Terminate {
+                reason,
+                action: TerminationAction::Refund,
+            });
+        }
+
+        if let Some((reason, action)) = vault
+            .termination_condition_met(pair.position_type(vault.get_swap_denom()), belief_price)?
+        {
+            return Ok(ExecutionDecision::Terminate { reason, action });
+        }
+    }
+
+    Ok(ExecutionDecision::Swap)
+}
+
+pub fn get_swap_decision(
+    deps: &Deps,
+    env: &Env,
+    vault: &Vault,
+    belief_price: Decimal,
+    swap_entire_balance: bool,
+) -> Result<SwapDecision, ContractError> {
+    let swap_amount = if swap_entire_balance {
+        vault.balance.clone()
+    } else {
+        get_swap_amount(deps, env, vault)?
+    };
+
+    if swap_amount.amount.is_zero() {
+        return Ok(SwapDecision::Skip {
+            reason: ExecutionSkippedReason::SwapAmountAdjustedToZero,
+        });
+    }
+
+    if !swap_entire_balance && vault.price_threshold_exceeded(belief_price)? {
+        return Ok(SwapDecision::Skip {
+            reason: ExecutionSkippedReason::PriceThresholdExceeded {
+                price: belief_price,
+            },
+        });
+    }
+
+    let minimum_receive_amount = vault.minimum_receive_amount.map(|minimum_receive_amount| {
+        Decimal::from_ratio(swap_amount.amount, vault.swap_amount) * minimum_receive_amount
+    });
+
+    Ok(SwapDecision::Swap {
+        swap_amount,
+        minimum_receive_amount,
+    })
+}
@@ -48,7 +48,7 @@ pub fn get_automation_fee_rate(storage: &dyn Storage, vault: &Vault) -> StdResul
         vault
             .destinations
             .iter()
-            .filter(|destination| destination.msg.is_some())
+            .filter(|destination| destination.is_automated())
             .map(|destination| destination.allocation)
             .sum(),
     )?)
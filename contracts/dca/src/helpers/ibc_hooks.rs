@@ -0,0 +1,59 @@
+use bech32::{ToBase32, Variant};
+use cosmwasm_std::{Addr, StdError, StdResult};
+use sha2::{Digest, Sha256};
+
+const IBC_HOOK_SENDER_PREFIX: &str = "ibc-wasm-hook-intermediary";
+
+pub fn get_ibc_hook_sender(
+    contract_address: &Addr,
+    channel_id: &str,
+    original_sender: &str,
+) -> StdResult<Addr> {
+    let (prefix, _) = contract_address.as_str().rsplit_once('1').ok_or_else(|| {
+        StdError::generic_err(format!(
+            "contract address {} is not a bech32 address",
+            contract_address
+        ))
+    })?;
+
+    let hash = Sha256::new()
+        .chain_update(Sha256::digest(IBC_HOOK_SENDER_PREFIX.as_bytes()))
+        .chain_update(format!("{}/{}", channel_id, original_sender).as_bytes())
+        .finalize();
+
+    bech32::encode(prefix, hash.to_base32(), Variant::Bech32)
+        .map(Addr::unchecked)
+        .map_err(|err| {
+            StdError::generic_err(format!(
+                "ibc hook sender for contract address {}: {}",
+                contract_address, err
+            ))
+        })
+}
+
+#[cfg(test)]
+mod get_ibc_hook_sender_tests {
+    use super::get_ibc_hook_sender;
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn derives_sender_using_contract_address_prefix() {
+        assert_eq!(
+            get_ibc_hook_sender(
+                &Addr::unchecked("osmo1contract"),
+                "channel-0",
+                "cosmos1sender"
+            )
+            .unwrap(),
+            Addr::unchecked("osmo1d9wa3m5gfzfut69wlaqal6r03vt3sjdgr2g7zk0fex79zqttqdvsns7vym")
+        );
+    }
+
+    #[test]
+    fn with_non_bech32_contract_address_fails() {
+        assert!(
+            get_ibc_hook_sender(&Addr::unchecked("contract"), "channel-0", "cosmos1sender")
+                .is_err()
+        );
+    }
+}
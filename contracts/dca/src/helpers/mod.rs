@@ -1,7 +1,9 @@
 pub mod authz;
 pub mod coin;
 pub mod disbursement;
+pub mod execution;
 pub mod fees;
+pub mod ibc_hooks;
 pub mod math;
 pub mod price;
 pub mod routes;
@@ -1,59 +1,104 @@
-use super::routes::{calculate_route, get_pool, get_token_out_denom};
-use crate::{
-    state::config::get_config,
-    types::{pair::Pair, position_type::PositionType},
-};
+use super::routes::{calculate_route, calculate_route_pools, split_amount_across_routes};
+use crate::{state::config::get_config, types::pair::Pair};
 use cosmwasm_std::{Coin, Decimal, Deps, Env, QuerierWrapper, StdError, StdResult, Uint128};
 use osmosis_std::{
     shim::Timestamp,
-    types::osmosis::{poolmanager::v1beta1::PoolmanagerQuerier, twap::v1beta1::TwapQuerier},
+    types::osmosis::{
+        poolmanager::v1beta1::{PoolmanagerQuerier, SwapAmountInRoute},
+        twap::v1beta1::TwapQuerier,
+    },
 };
 
 pub fn query_belief_price(
     deps: &Deps,
     env: &Env,
     pair: &Pair,
-    mut swap_denom: String,
+    swap_denom: String,
 ) -> StdResult<Decimal> {
-    let route = match pair.position_type(swap_denom.clone()) {
-        PositionType::Enter => pair.route.clone(),
-        PositionType::Exit => pair.route.clone().into_iter().rev().collect(),
-    };
+    let routes = pair.routes();
 
-    let mut price = Decimal::one();
+    if routes.len() == 1 {
+        return query_route_belief_price(
+            deps,
+            env,
+            &pair.with_route(routes[0].route.clone()),
+            swap_denom,
+        );
+    }
 
-    let config = get_config(deps.storage)?;
+    let mut inverse_price = Decimal::zero();
 
-    for pool_id in route.into_iter() {
-        let target_denom = get_token_out_denom(&deps.querier, swap_denom.clone(), pool_id)?;
+    for weighted_route in routes.iter() {
+        let route_price = query_route_belief_price(
+            deps,
+            env,
+            &pair.with_route(weighted_route.route.clone()),
+            swap_denom.clone(),
+        )?;
 
-        let pool = get_pool(&deps.querier, pool_id)?;
+        inverse_price += weighted_route
+            .weight
+            .checked_div(route_price)
+            .map_err(|_| {
+                StdError::generic_err(format!(
+                    "belief price for swapping {} via {:#?}",
+                    swap_denom, weighted_route.route
+                ))
+            })?;
+    }
 
-        let swap_fee = pool
-            .pool_params
-            .unwrap()
-            .swap_fee
-            .parse::<Decimal>()
-            .unwrap();
+    Decimal::one().checked_div(inverse_price).map_err(|_| {
+        StdError::generic_err(format!(
+            "belief price for swapping {} via {:#?}",
+            swap_denom,
+            routes
+                .iter()
+                .map(|weighted_route| weighted_route.route.clone())
+                .collect::<Vec<Vec<u64>>>()
+        ))
+    })
+}
+
+fn query_route_belief_price(
+    deps: &Deps,
+    env: &Env,
+    pair: &Pair,
+    mut swap_denom: String,
+) -> StdResult<Decimal> {
+    let route = calculate_route_pools(&deps.querier, pair, swap_denom.clone())?;
 
+    let mut price = Decimal::one();
+
+    let twap_period = match pair.twap_period {
+        Some(twap_period) => twap_period,
+        None => get_config(deps.storage)?.twap_period,
+    };
+
+    for (
+        pool,
+        SwapAmountInRoute {
+            token_out_denom, ..
+        },
+    ) in route.into_iter()
+    {
         let pool_price = TwapQuerier::new(&deps.querier)
             .arithmetic_twap_to_now(
-                pool_id,
-                target_denom.clone(),
+                pool.id,
+                token_out_denom.clone(),
                 swap_denom.clone(),
                 Some(Timestamp {
-                    seconds: (env.block.time.seconds() - config.twap_period) as i64,
+                    seconds: (env.block.time.seconds() - twap_period) as i64,
                     nanos: 0,
                 }),
             )
             .unwrap()
             .arithmetic_twap
             .parse::<Decimal>()?
-            * (Decimal::one() + swap_fee);
+            * (Decimal::one() + pool.swap_fee);
 
         price = pool_price * price;
 
-        swap_denom = target_denom;
+        swap_denom = token_out_denom;
     }
 
     Ok(price)
@@ -65,9 +110,36 @@ pub fn query_price(
     pair: &Pair,
     swap_amount: &Coin,
 ) -> StdResult<Decimal> {
+    let weighted_routes = pair.routes();
+    let route_swap_amounts = split_amount_across_routes(swap_amount.amount, &weighted_routes);
+
+    let mut token_out_amount = Uint128::zero();
+
+    for (weighted_route, route_swap_amount) in weighted_routes.into_iter().zip(route_swap_amounts) {
+        if route_swap_amount.is_zero() {
+            continue;
+        }
+
+        token_out_amount += query_route_token_out_amount(
+            querier,
+            env,
+            &pair.with_route(weighted_route.route),
+            &Coin::new(route_swap_amount.into(), swap_amount.denom.clone()),
+        )?;
+    }
+
+    Ok(Decimal::from_ratio(swap_amount.amount, token_out_amount))
+}
+
+fn query_route_token_out_amount(
+    querier: &QuerierWrapper,
+    env: &Env,
+    pair: &Pair,
+    swap_amount: &Coin,
+) -> StdResult<Uint128> {
     let routes = calculate_route(querier, pair, swap_amount.denom.clone())?;
 
-    let token_out_amount = PoolmanagerQuerier::new(querier)
+    PoolmanagerQuerier::new(querier)
         .estimate_swap_exact_amount_in(
             env.contract.address.to_string(),
             0,
@@ -83,9 +155,7 @@ pub fn query_price(
             ))
         })?
         .token_out_amount
-        .parse::<Uint128>()?;
-
-    Ok(Decimal::from_ratio(swap_amount.amount, token_out_amount))
+        .parse::<Uint128>()
 }
 
 pub fn calculate_slippage(actual_price: Decimal, belief_price: Decimal) -> Decimal {
@@ -109,13 +179,14 @@ mod query_belief_price_tests {
             helpers::instantiate_contract,
             mocks::{calc_mock_dependencies, ADMIN},
         },
+        types::pair::WeightedRoute,
     };
     use cosmwasm_std::{
         testing::{mock_env, mock_info},
         to_binary, StdError,
     };
     use osmosis_std::types::osmosis::twap::v1beta1::{
-        ArithmeticTwapRequest, ArithmeticTwapResponse,
+        ArithmeticTwapRequest, ArithmeticTwapResponse, ArithmeticTwapToNowRequest,
     };
     use prost::Message;
     use std::str::FromStr;
@@ -198,4 +269,184 @@ mod query_belief_price_tests {
                 * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap())
         );
     }
+
+    #[test]
+    fn query_belief_price_with_pair_twap_period_should_override_config_twap_period() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let pair_twap_start_time = env.block.time.seconds() - 600;
+
+        deps.querier.update_stargate(move |path, data| {
+            if path == "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" {
+                let start_time = ArithmeticTwapToNowRequest::decode(data.as_slice())
+                    .unwrap()
+                    .start_time
+                    .unwrap();
+
+                let price = if start_time.seconds as u64 == pair_twap_start_time {
+                    "0.8"
+                } else {
+                    "1.0"
+                };
+
+                return to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: price.to_string(),
+                });
+            }
+            Err(StdError::generic_err("invoke fallback"))
+        });
+
+        let pair = Pair {
+            twap_period: Some(600),
+            ..Pair::default()
+        };
+
+        let price =
+            query_belief_price(&deps.as_ref(), &env, &pair.clone(), pair.quote_denom).unwrap();
+
+        assert_eq!(
+            price,
+            Decimal::percent(80) * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap())
+        );
+    }
+
+    #[test]
+    fn query_belief_price_with_weighted_routes_should_combine_route_prices() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        deps.querier.update_stargate(|path, data| {
+            if path == "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" {
+                let price = match ArithmeticTwapRequest::decode(data.as_slice())
+                    .unwrap()
+                    .pool_id
+                {
+                    1 => "0.2",
+                    3 => "0.8",
+                    4 => "1.2",
+                    _ => "1.0",
+                };
+
+                return to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: price.to_string(),
+                });
+            }
+            Err(StdError::generic_err("invoke fallback"))
+        });
+
+        let pair = Pair {
+            weighted_routes: Some(vec![
+                WeightedRoute {
+                    route: vec![3],
+                    weight: Decimal::percent(75),
+                },
+                WeightedRoute {
+                    route: vec![4, 1],
+                    weight: Decimal::percent(25),
+                },
+            ]),
+            ..Pair::default()
+        };
+
+        let price =
+            query_belief_price(&deps.as_ref(), &env, &pair.clone(), pair.quote_denom).unwrap();
+
+        let fee_factor = Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap();
+        let direct_route_price = Decimal::percent(80) * fee_factor;
+        let indirect_route_price =
+            Decimal::percent(20) * fee_factor * Decimal::percent(120) * fee_factor;
+
+        assert_eq!(
+            price,
+            Decimal::one()
+                / (Decimal::percent(75) / direct_route_price
+                    + Decimal::percent(25) / indirect_route_price)
+        );
+    }
+
+    #[test]
+    fn query_belief_price_with_weighted_route_price_of_zero_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        deps.querier.update_stargate(|path, data| {
+            if path == "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" {
+                let price = match ArithmeticTwapRequest::decode(data.as_slice())
+                    .unwrap()
+                    .pool_id
+                {
+                    1 => "0",
+                    _ => "1.0",
+                };
+
+                return to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: price.to_string(),
+                });
+            }
+            Err(StdError::generic_err("invoke fallback"))
+        });
+
+        let pair = Pair {
+            weighted_routes: Some(vec![
+                WeightedRoute {
+                    route: vec![3],
+                    weight: Decimal::percent(75),
+                },
+                WeightedRoute {
+                    route: vec![4, 1],
+                    weight: Decimal::percent(25),
+                },
+            ]),
+            ..Pair::default()
+        };
+
+        let err = query_belief_price(
+            &deps.as_ref(),
+            &env,
+            &pair.clone(),
+            pair.quote_denom.clone(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Generic error: belief price for swapping {} via {:#?}",
+                pair.quote_denom,
+                vec![4, 1]
+            )
+        );
+    }
+
+    #[test]
+    fn query_belief_price_with_concentrated_liquidity_pool_should_include_spread_factor() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let pair = Pair {
+            route: vec![7],
+            ..Pair::default()
+        };
+
+        let price =
+            query_belief_price(&deps.as_ref(), &env, &pair.clone(), pair.quote_denom).unwrap();
+
+        assert_eq!(
+            price,
+            Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap()
+        );
+    }
 }
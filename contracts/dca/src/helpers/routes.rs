@@ -1,64 +1,79 @@
-use crate::types::{pair::Pair, position_type::PositionType};
-use cosmwasm_std::{QuerierWrapper, StdError, StdResult};
-use osmosis_std::types::osmosis::{
-    gamm::{v1beta1::GammQuerier, v1beta1::Pool},
-    poolmanager::v1beta1::SwapAmountInRoute,
+use crate::types::{
+    pair::{Pair, WeightedRoute},
+    pool::{Pool, PoolRequest, PoolResponse},
+    position_type::PositionType,
 };
-use prost::DecodeError;
+use cosmwasm_std::{QuerierWrapper, QueryRequest, StdError, StdResult, Uint128};
+use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
+use prost::Message;
+use std::cmp::min;
 
 pub fn get_token_out_denom(
-    querier: &QuerierWrapper,
-    token_in_denom: String,
-    pool_id: u64,
+    pool: &Pool,
+    token_in_denom: &String,
+    next_pool: Option<&Pool>,
+    target_denom: &String,
 ) -> StdResult<String> {
-    let pool = get_pool(querier, pool_id)?;
-
-    if pool.pool_assets.len() != 2 {
+    if !pool.denoms.contains(token_in_denom) {
         return Err(StdError::generic_err(format!(
-            "pool id {} is not a 2 asset pool",
-            pool_id
+            "denom {} not found in pool id {}",
+            token_in_denom, pool.id
         )));
     }
 
-    if pool
-        .pool_assets
+    let token_out_denoms = pool
+        .denoms
         .iter()
-        .all(|asset| asset.token.clone().unwrap().denom != token_in_denom)
-    {
+        .filter(|denom| *denom != token_in_denom)
+        .collect::<Vec<&String>>();
+
+    if token_out_denoms.len() == 1 {
+        return Ok(token_out_denoms[0].clone());
+    }
+
+    let token_out_denoms = token_out_denoms
+        .into_iter()
+        .filter(|denom| match next_pool {
+            Some(next_pool) => next_pool.denoms.contains(denom),
+            None => *denom == target_denom,
+        })
+        .collect::<Vec<&String>>();
+
+    if token_out_denoms.len() != 1 {
         return Err(StdError::generic_err(format!(
-            "denom {} not found in pool id {}",
-            token_in_denom, pool_id
+            "unable to determine token out denom for swapping {} in pool id {}",
+            token_in_denom, pool.id
         )));
     }
 
-    let token_out_denom = pool
-        .pool_assets
-        .iter()
-        .find(|asset| asset.token.clone().unwrap().denom != token_in_denom)
-        .map(|asset| asset.token.clone().unwrap().denom)
-        .ok_or_else(|| StdError::generic_err("no token out denom found"));
-
-    token_out_denom
+    Ok(token_out_denoms[0].clone())
 }
 
-pub fn get_pool(querier: &QuerierWrapper, pool_id: u64) -> Result<Pool, StdError> {
-    GammQuerier::new(querier).pool(pool_id)?.pool.map_or(
-        Err(StdError::generic_err("pool not found")),
-        |pool| {
-            pool.try_into()
-                .map_err(|e: DecodeError| StdError::ParseErr {
-                    target_type: Pool::TYPE_URL.to_string(),
-                    msg: e.to_string(),
-                })
-        },
-    )
+pub fn get_pool(querier: &QuerierWrapper, pool_id: u64) -> StdResult<Pool> {
+    let response: PoolResponse = querier.query(&QueryRequest::Stargate {
+        path: PoolRequest::PATH.to_string(),
+        data: PoolRequest { pool_id }.encode_to_vec().into(),
+    })?;
+
+    response.pool.try_into()
 }
 
 pub fn calculate_route(
     querier: &QuerierWrapper,
     pair: &Pair,
-    mut swap_denom: String,
+    swap_denom: String,
 ) -> StdResult<Vec<SwapAmountInRoute>> {
+    Ok(calculate_route_pools(querier, pair, swap_denom)?
+        .into_iter()
+        .map(|(_, route)| route)
+        .collect())
+}
+
+pub fn calculate_route_pools(
+    querier: &QuerierWrapper,
+    pair: &Pair,
+    mut swap_denom: String,
+) -> StdResult<Vec<(Pool, SwapAmountInRoute)>> {
     let pair_denoms = pair.denoms();
 
     if !pair_denoms.contains(&swap_denom) {
@@ -68,64 +83,156 @@ pub fn calculate_route(
         )));
     }
 
+    let target_denom = pair.other_denom(swap_denom.clone());
+
     let pool_ids = match pair.position_type(swap_denom.clone()) {
         PositionType::Enter => pair.route.clone(),
         PositionType::Exit => pair.route.clone().into_iter().rev().collect(),
     };
 
-    let mut route: Vec<SwapAmountInRoute> = vec![];
+    let pools = pool_ids
+        .into_iter()
+        .map(|pool_id| get_pool(querier, pool_id))
+        .collect::<StdResult<Vec<Pool>>>()?;
 
-    for pool_id in pool_ids.into_iter() {
-        let target_denom = get_token_out_denom(querier, swap_denom.clone(), pool_id)?;
+    let mut route: Vec<(Pool, SwapAmountInRoute)> = vec![];
 
-        route.push(SwapAmountInRoute {
-            pool_id,
-            token_out_denom: target_denom.clone(),
-        });
+    for (index, pool) in pools.iter().enumerate() {
+        let token_out_denom =
+            get_token_out_denom(pool, &swap_denom, pools.get(index + 1), &target_denom)?;
 
-        swap_denom = target_denom;
+        route.push((
+            pool.clone(),
+            SwapAmountInRoute {
+                pool_id: pool.id,
+                token_out_denom: token_out_denom.clone(),
+            },
+        ));
+
+        swap_denom = token_out_denom;
     }
 
-    if !pair_denoms.contains(&route.last().unwrap().token_out_denom) {
+    let last_token_out_denom = route.last().unwrap().1.token_out_denom.clone();
+
+    if !pair_denoms.contains(&last_token_out_denom) {
         return Err(StdError::generic_err(format!(
             "last token out denom {} not in pair denoms {:?}",
-            route.last().unwrap().token_out_denom,
-            pair_denoms
+            last_token_out_denom, pair_denoms
         )));
     }
 
     Ok(route)
 }
 
+pub fn split_amount_across_routes(amount: Uint128, routes: &[WeightedRoute]) -> Vec<Uint128> {
+    let mut remaining_amount = amount;
+
+    routes
+        .iter()
+        .enumerate()
+        .map(|(index, weighted_route)| {
+            let route_amount = if index == routes.len() - 1 {
+                remaining_amount
+            } else {
+                min(amount * weighted_route.weight, remaining_amount)
+            };
+
+            remaining_amount -= route_amount;
+            route_amount
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod get_token_out_denom_tests {
     use super::get_token_out_denom;
     use crate::{
-        tests::mocks::{calc_mock_dependencies, DENOM_UATOM, DENOM_UOSMO},
-        types::pair::Pair,
+        tests::mocks::{DENOM_STAKE, DENOM_UATOM, DENOM_UION, DENOM_UOSMO, DENOM_USDC},
+        types::pool::Pool,
     };
+    use cosmwasm_std::Decimal;
+
+    fn pool(id: u64, denoms: &[&str]) -> Pool {
+        Pool {
+            id,
+            denoms: denoms.iter().map(|denom| denom.to_string()).collect(),
+            swap_fee: Decimal::zero(),
+        }
+    }
 
     #[test]
-    fn fails_when_swap_denom_not_in_pair_denoms() {
-        let deps = calc_mock_dependencies();
+    fn fails_when_token_in_denom_not_in_pool() {
+        let swap_denom = "not_in_pool".to_string();
 
-        let pair = Pair {
-            route: vec![0],
-            quote_denom: DENOM_UATOM.to_string(),
-            base_denom: DENOM_UOSMO.to_string(),
-        };
+        let err = get_token_out_denom(
+            &pool(0, &[DENOM_UOSMO, DENOM_UATOM]),
+            &swap_denom,
+            None,
+            &DENOM_UOSMO.to_string(),
+        )
+        .unwrap_err();
 
-        let swap_denom = "not_in_pair".to_string();
+        assert_eq!(
+            err.to_string(),
+            format!("Generic error: denom {} not found in pool id 0", swap_denom)
+        );
+    }
 
-        let err = get_token_out_denom(&deps.as_ref().querier, swap_denom.clone(), pair.route[0])
-            .unwrap_err();
+    #[test]
+    fn returns_other_denom_for_2_asset_pool() {
+        assert_eq!(
+            get_token_out_denom(
+                &pool(0, &[DENOM_UOSMO, DENOM_UATOM]),
+                &DENOM_UATOM.to_string(),
+                None,
+                &DENOM_STAKE.to_string(),
+            )
+            .unwrap(),
+            DENOM_UOSMO.to_string()
+        );
+    }
 
+    #[test]
+    fn returns_target_denom_for_last_multi_asset_pool() {
         assert_eq!(
-            err.to_string(),
-            format!(
-                "Generic error: denom {} not found in pool id {}",
-                swap_denom, pair.route[0]
+            get_token_out_denom(
+                &pool(5, &[DENOM_UOSMO, DENOM_STAKE, DENOM_UATOM]),
+                &DENOM_STAKE.to_string(),
+                None,
+                &DENOM_UOSMO.to_string(),
+            )
+            .unwrap(),
+            DENOM_UOSMO.to_string()
+        );
+    }
+
+    #[test]
+    fn returns_denom_shared_with_next_pool_for_intermediary_multi_asset_pool() {
+        assert_eq!(
+            get_token_out_denom(
+                &pool(6, &[DENOM_UATOM, DENOM_UION, DENOM_USDC]),
+                &DENOM_USDC.to_string(),
+                Some(&pool(1, &[DENOM_UOSMO, DENOM_UION])),
+                &DENOM_UOSMO.to_string(),
             )
+            .unwrap(),
+            DENOM_UION.to_string()
+        );
+    }
+
+    #[test]
+    fn fails_when_multi_asset_pool_token_out_denom_is_ambiguous() {
+        let err = get_token_out_denom(
+            &pool(6, &[DENOM_UATOM, DENOM_UION, DENOM_USDC]),
+            &DENOM_USDC.to_string(),
+            Some(&pool(8, &[DENOM_UATOM, DENOM_UION])),
+            &DENOM_UOSMO.to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: unable to determine token out denom for swapping uaxlusdc in pool id 6"
         );
     }
 }
@@ -134,7 +241,9 @@ mod get_token_out_denom_tests {
 mod calculate_route_tests {
     use super::calculate_route;
     use crate::{
-        tests::mocks::{calc_mock_dependencies, DENOM_UATOM, DENOM_UION, DENOM_UOSMO, DENOM_USDC},
+        tests::mocks::{
+            calc_mock_dependencies, DENOM_STAKE, DENOM_UATOM, DENOM_UION, DENOM_UOSMO, DENOM_USDC,
+        },
         types::pair::Pair,
     };
     use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
@@ -147,6 +256,10 @@ mod calculate_route_tests {
             route: vec![0],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         let swap_denom = "not_in_pair".to_string();
@@ -171,6 +284,10 @@ mod calculate_route_tests {
             route: vec![2],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         let err =
@@ -193,6 +310,10 @@ mod calculate_route_tests {
             route: vec![0, 2],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         let err =
@@ -215,6 +336,10 @@ mod calculate_route_tests {
             route: vec![0, 1],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         let err =
@@ -237,6 +362,10 @@ mod calculate_route_tests {
             route: vec![0],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         assert_eq!(
@@ -264,6 +393,10 @@ mod calculate_route_tests {
             route: vec![0, 1],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UION.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         assert_eq!(
@@ -303,6 +436,10 @@ mod calculate_route_tests {
             route: vec![2, 1, 0],
             quote_denom: DENOM_USDC.to_string(),
             base_denom: DENOM_UATOM.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         };
 
         assert_eq!(
@@ -341,4 +478,176 @@ mod calculate_route_tests {
             ]
         );
     }
+
+    #[test]
+    fn calculates_route_through_stableswap_pool() {
+        let deps = calc_mock_dependencies();
+
+        let pair = Pair {
+            route: vec![5],
+            quote_denom: DENOM_STAKE.to_string(),
+            base_denom: DENOM_UOSMO.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
+        };
+
+        assert_eq!(
+            calculate_route(&deps.as_ref().querier, &pair, DENOM_STAKE.to_string()).unwrap(),
+            vec![SwapAmountInRoute {
+                pool_id: 5,
+                token_out_denom: DENOM_UOSMO.to_string(),
+            }]
+        );
+
+        assert_eq!(
+            calculate_route(&deps.as_ref().querier, &pair, DENOM_UOSMO.to_string()).unwrap(),
+            vec![SwapAmountInRoute {
+                pool_id: 5,
+                token_out_denom: DENOM_STAKE.to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn calculates_route_through_multi_asset_weighted_pool() {
+        let deps = calc_mock_dependencies();
+
+        let pair = Pair {
+            route: vec![6, 1],
+            quote_denom: DENOM_USDC.to_string(),
+            base_denom: DENOM_UOSMO.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
+        };
+
+        assert_eq!(
+            calculate_route(&deps.as_ref().querier, &pair, DENOM_USDC.to_string()).unwrap(),
+            vec![
+                SwapAmountInRoute {
+                    pool_id: 6,
+                    token_out_denom: DENOM_UION.to_string(),
+                },
+                SwapAmountInRoute {
+                    pool_id: 1,
+                    token_out_denom: DENOM_UOSMO.to_string(),
+                }
+            ]
+        );
+
+        assert_eq!(
+            calculate_route(&deps.as_ref().querier, &pair, DENOM_UOSMO.to_string()).unwrap(),
+            vec![
+                SwapAmountInRoute {
+                    pool_id: 1,
+                    token_out_denom: DENOM_UION.to_string(),
+                },
+                SwapAmountInRoute {
+                    pool_id: 6,
+                    token_out_denom: DENOM_USDC.to_string(),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn calculates_route_through_concentrated_liquidity_pool() {
+        let deps = calc_mock_dependencies();
+
+        let pair = Pair {
+            route: vec![7],
+            quote_denom: DENOM_STAKE.to_string(),
+            base_denom: DENOM_UOSMO.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
+        };
+
+        assert_eq!(
+            calculate_route(&deps.as_ref().querier, &pair, DENOM_STAKE.to_string()).unwrap(),
+            vec![SwapAmountInRoute {
+                pool_id: 7,
+                token_out_denom: DENOM_UOSMO.to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn fails_when_multi_asset_pool_does_not_contain_target_denom() {
+        let deps = calc_mock_dependencies();
+
+        let pair = Pair {
+            route: vec![6],
+            quote_denom: DENOM_UATOM.to_string(),
+            base_denom: DENOM_UOSMO.to_string(),
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
+        };
+
+        let err =
+            calculate_route(&deps.as_ref().querier, &pair, DENOM_UATOM.to_string()).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: unable to determine token out denom for swapping uatom in pool id 6"
+        );
+    }
+}
+
+#[cfg(test)]
+mod split_amount_across_routes_tests {
+    use super::split_amount_across_routes;
+    use crate::types::pair::WeightedRoute;
+    use cosmwasm_std::{Decimal, Uint128};
+
+    #[test]
+    fn splits_amount_by_route_weight() {
+        let routes = vec![
+            WeightedRoute {
+                route: vec![3],
+                weight: Decimal::percent(70),
+            },
+            WeightedRoute {
+                route: vec![4, 1],
+                weight: Decimal::percent(30),
+            },
+        ];
+
+        assert_eq!(
+            split_amount_across_routes(Uint128::new(1000), &routes),
+            vec![Uint128::new(700), Uint128::new(300)]
+        );
+    }
+
+    #[test]
+    fn assigns_rounding_remainder_to_last_route() {
+        let routes = vec![
+            WeightedRoute {
+                route: vec![3],
+                weight: Decimal::from_ratio(1u128, 3u128),
+            },
+            WeightedRoute {
+                route: vec![4, 1],
+                weight: Decimal::from_ratio(1u128, 3u128),
+            },
+            WeightedRoute {
+                route: vec![0],
+                weight: Decimal::from_ratio(1u128, 3u128),
+            },
+        ];
+
+        let amounts = split_amount_across_routes(Uint128::new(10), &routes);
+
+        assert_eq!(
+            amounts,
+            vec![Uint128::new(3), Uint128::new(3), Uint128::new(4)]
+        );
+        assert_eq!(amounts.iter().sum::<Uint128>(), Uint128::new(10));
+    }
 }
@@ -1,5 +1,8 @@
-use super::routes::calculate_route;
-use crate::types::pair::Pair;
+use super::routes::{calculate_route, split_amount_across_routes};
+use crate::types::{
+    pair::Pair,
+    split_route_swap::{MsgSplitRouteSwapExactAmountIn, SwapAmountInSplitRoute},
+};
 use cosmwasm_std::{Coin, Decimal, Env, QuerierWrapper, ReplyOn, StdResult, SubMsg, Uint128};
 use osmosis_std::types::osmosis::poolmanager::v1beta1::MsgSwapExactAmountIn;
 use std::cmp::max;
@@ -15,8 +18,6 @@ pub fn create_swap_message(
     reply_id: Option<u64>,
     reply_on: Option<ReplyOn>,
 ) -> StdResult<SubMsg> {
-    let routes = calculate_route(querier, pair, swap_amount.denom.clone())?;
-
     let expected_receive_amount = swap_amount.amount
         * (Decimal::one() / belief_price)
         * (Decimal::one() - slippage_tolerance);
@@ -26,15 +27,54 @@ pub fn create_swap_message(
             max(minimum_receive_amount, expected_receive_amount)
         });
 
-    Ok(SubMsg {
-        id: reply_id.unwrap_or(0),
-        msg: MsgSwapExactAmountIn {
+    let weighted_routes = pair.routes();
+
+    let msg = if weighted_routes.len() == 1 {
+        MsgSwapExactAmountIn {
             sender: env.contract.address.to_string(),
-            token_in: Some(swap_amount.into()),
+            token_in: Some(swap_amount.clone().into()),
             token_out_min_amount: token_out_min_amount.to_string(),
+            routes: calculate_route(
+                querier,
+                &pair.with_route(weighted_routes[0].route.clone()),
+                swap_amount.denom,
+            )?,
+        }
+        .into()
+    } else {
+        let route_swap_amounts = split_amount_across_routes(swap_amount.amount, &weighted_routes);
+
+        let mut routes = Vec::<SwapAmountInSplitRoute>::new();
+
+        for (weighted_route, route_swap_amount) in
+            weighted_routes.into_iter().zip(route_swap_amounts)
+        {
+            if route_swap_amount.is_zero() {
+                continue;
+            }
+
+            routes.push(SwapAmountInSplitRoute {
+                pools: calculate_route(
+                    querier,
+                    &pair.with_route(weighted_route.route),
+                    swap_amount.denom.clone(),
+                )?,
+                token_in_amount: route_swap_amount.to_string(),
+            });
+        }
+
+        MsgSplitRouteSwapExactAmountIn {
+            sender: env.contract.address.to_string(),
             routes,
+            token_in_denom: swap_amount.denom,
+            token_out_min_amount: token_out_min_amount.to_string(),
         }
-        .into(),
+        .into()
+    };
+
+    Ok(SubMsg {
+        id: reply_id.unwrap_or(0),
+        msg,
         gas_limit: None,
         reply_on: reply_on.unwrap_or(ReplyOn::Never),
     })
@@ -47,7 +87,10 @@ mod create_osmosis_swap_message_tests {
         constants::{ONE, TWO_MICRONS},
         helpers::routes::calculate_route,
         tests::mocks::{calc_mock_dependencies, DENOM_UOSMO},
-        types::pair::Pair,
+        types::{
+            pair::{Pair, WeightedRoute},
+            split_route_swap::{MsgSplitRouteSwapExactAmountIn, SwapAmountInSplitRoute},
+        },
     };
     use cosmwasm_std::{testing::mock_env, Coin, Decimal, ReplyOn, SubMsg};
     use osmosis_std::types::osmosis::poolmanager::v1beta1::MsgSwapExactAmountIn;
@@ -137,4 +180,75 @@ mod create_osmosis_swap_message_tests {
             }
         )
     }
+
+    #[test]
+    fn splits_swap_across_weighted_routes() {
+        let deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        let swap_amount = Coin::new(ONE.into(), DENOM_UOSMO);
+        let belief_price = Decimal::one();
+        let slippage_tolerance = Decimal::percent(1);
+        let pair = Pair {
+            weighted_routes: Some(vec![
+                WeightedRoute {
+                    route: vec![3],
+                    weight: Decimal::percent(70),
+                },
+                WeightedRoute {
+                    route: vec![4, 1],
+                    weight: Decimal::percent(30),
+                },
+            ]),
+            ..Pair::default()
+        };
+
+        let msg = create_swap_message(
+            &deps.as_ref().querier,
+            &env,
+            &pair,
+            swap_amount.clone(),
+            slippage_tolerance,
+            belief_price,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            SubMsg {
+                id: 0,
+                msg: MsgSplitRouteSwapExactAmountIn {
+                    sender: env.contract.address.to_string(),
+                    routes: vec![
+                        SwapAmountInSplitRoute {
+                            pools: calculate_route(
+                                &deps.as_ref().querier,
+                                &pair.with_route(vec![3]),
+                                swap_amount.denom.clone()
+                            )
+                            .unwrap(),
+                            token_in_amount: (ONE * Decimal::percent(70)).to_string(),
+                        },
+                        SwapAmountInSplitRoute {
+                            pools: calculate_route(
+                                &deps.as_ref().querier,
+                                &pair.with_route(vec![4, 1]),
+                                swap_amount.denom.clone()
+                            )
+                            .unwrap(),
+                            token_in_amount: (ONE * Decimal::percent(30)).to_string(),
+                        },
+                    ],
+                    token_in_denom: swap_amount.denom,
+                    token_out_min_amount: (ONE * (Decimal::one() - slippage_tolerance)).to_string(),
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: ReplyOn::Never,
+            }
+        )
+    }
 }
@@ -1,6 +1,10 @@
-use crate::types::time_interval::TimeInterval;
-use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+use crate::types::{
+    execution_window::ExecutionWindow,
+    time_interval::{DayOfWeek, TimeInterval},
+};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc, Weekday};
 use cosmwasm_std::Timestamp;
+use std::cmp::max;
 use std::convert::TryInto;
 
 pub fn target_time_elapsed(current_time: Timestamp, target_execution_time: Timestamp) -> bool {
@@ -28,6 +32,9 @@ pub fn get_next_target_time(
                 next_execution_time = get_next_time(next_execution_time, &interval);
             }
         }
+        TimeInterval::DaysOfWeek { .. } | TimeInterval::DaysOfMonth { .. } => {
+            next_execution_time = get_next_time(max(current_time, started_at_time), &interval);
+        }
         _ => {
             let interval_duration = get_duration(started_at_time, &interval);
 
@@ -61,6 +68,42 @@ pub fn get_total_execution_duration(
     })
 }
 
+pub fn is_within_execution_window(time: Timestamp, execution_window: &ExecutionWindow) -> bool {
+    let time = Utc
+        .timestamp_opt(time.seconds().try_into().unwrap(), 0)
+        .unwrap();
+
+    execution_window.includes_hour(time.hour() as u8)
+        && !execution_window
+            .excluded_days
+            .iter()
+            .any(|day| to_weekday(day) == time.weekday())
+}
+
+pub fn get_next_execution_window_time(
+    current_timestamp: Timestamp,
+    execution_window: &ExecutionWindow,
+) -> Timestamp {
+    let current_time = Utc
+        .timestamp_opt(current_timestamp.seconds().try_into().unwrap(), 0)
+        .unwrap();
+
+    let mut next_time = current_time
+        .with_minute(0)
+        .and_then(|time| time.with_second(0))
+        .expect("start of the hour should be valid");
+
+    loop {
+        next_time += Duration::hours(1);
+
+        let next_timestamp = Timestamp::from_seconds(next_time.timestamp().try_into().unwrap());
+
+        if is_within_execution_window(next_timestamp, execution_window) {
+            return next_timestamp;
+        }
+    }
+}
+
 fn get_duration(previous: DateTime<Utc>, interval: &TimeInterval) -> Duration {
     match interval {
         TimeInterval::EveryBlock => Duration::seconds(1),
@@ -73,6 +116,57 @@ fn get_duration(previous: DateTime<Utc>, interval: &TimeInterval) -> Duration {
         TimeInterval::Fortnightly => Duration::days(14),
         TimeInterval::Monthly => shift_months(previous, 1) - previous,
         TimeInterval::Custom { seconds } => Duration::seconds(*seconds as i64),
+        TimeInterval::DaysOfWeek { hour, minute, .. }
+        | TimeInterval::DaysOfMonth { hour, minute, .. } => {
+            get_next_calendar_time(previous, interval, *hour, *minute) - previous
+        }
+    }
+}
+
+fn get_next_calendar_time(
+    previous: DateTime<Utc>,
+    interval: &TimeInterval,
+    hour: u8,
+    minute: u8,
+) -> DateTime<Utc> {
+    let mut date = previous.date_naive();
+
+    loop {
+        let candidate = Utc.from_utc_datetime(
+            &date
+                .and_hms_opt(hour.into(), minute.into(), 0)
+                .expect("calendar time of day should be valid"),
+        );
+
+        if candidate > previous && is_calendar_day(candidate, interval) {
+            return candidate;
+        }
+
+        date = date.succ_opt().expect("next date should be valid");
+    }
+}
+
+fn is_calendar_day(date: DateTime<Utc>, interval: &TimeInterval) -> bool {
+    match interval {
+        TimeInterval::DaysOfWeek { days, .. } => {
+            days.iter().any(|day| to_weekday(day) == date.weekday())
+        }
+        TimeInterval::DaysOfMonth { days, .. } => days
+            .iter()
+            .any(|day| normalise_day(date.year(), date.month(), (*day).into()) == date.day()),
+        _ => false,
+    }
+}
+
+fn to_weekday(day: &DayOfWeek) -> Weekday {
+    match day {
+        DayOfWeek::Monday => Weekday::Mon,
+        DayOfWeek::Tuesday => Weekday::Tue,
+        DayOfWeek::Wednesday => Weekday::Wed,
+        DayOfWeek::Thursday => Weekday::Thu,
+        DayOfWeek::Friday => Weekday::Fri,
+        DayOfWeek::Saturday => Weekday::Sat,
+        DayOfWeek::Sunday => Weekday::Sun,
     }
 }
 
@@ -628,12 +722,142 @@ mod tests {
 
         assert_eq!(result.timestamp(), expected_time.timestamp());
     }
+
+    #[test]
+    fn assert_days_of_week_next_execution_times() {
+        let last_execution_time = Utc.with_ymd_and_hms(2023, 1, 2, 14, 0, 0).unwrap();
+        let scenarios = vec![
+            (
+                last_execution_time,
+                Utc.with_ymd_and_hms(2023, 1, 9, 14, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 1, 5, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2023, 1, 9, 14, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 1, 9, 13, 59, 59).unwrap(),
+                Utc.with_ymd_and_hms(2023, 1, 9, 14, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 1, 9, 14, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2023, 1, 16, 14, 0, 0).unwrap(),
+            ),
+        ];
+
+        assert_expected_next_execution_times(
+            &TimeInterval::DaysOfWeek {
+                days: vec![DayOfWeek::Monday],
+                hour: 14,
+                minute: 0,
+            },
+            last_execution_time,
+            scenarios,
+        );
+    }
+
+    #[test]
+    fn assert_weekdays_next_execution_times() {
+        let last_execution_time = Utc.with_ymd_and_hms(2023, 1, 6, 9, 30, 0).unwrap();
+        let scenarios = vec![
+            (
+                last_execution_time,
+                Utc.with_ymd_and_hms(2023, 1, 9, 9, 30, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 1, 7, 12, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2023, 1, 9, 9, 30, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 1, 9, 9, 30, 0).unwrap(),
+                Utc.with_ymd_and_hms(2023, 1, 10, 9, 30, 0).unwrap(),
+            ),
+        ];
+
+        assert_expected_next_execution_times(
+            &TimeInterval::DaysOfWeek {
+                days: vec![
+                    DayOfWeek::Monday,
+                    DayOfWeek::Tuesday,
+                    DayOfWeek::Wednesday,
+                    DayOfWeek::Thursday,
+                    DayOfWeek::Friday,
+                ],
+                hour: 9,
+                minute: 30,
+            },
+            last_execution_time,
+            scenarios,
+        );
+    }
+
+    #[test]
+    fn assert_days_of_month_next_execution_times() {
+        let last_execution_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let scenarios = vec![
+            (
+                last_execution_time,
+                Utc.with_ymd_and_hms(2023, 1, 15, 0, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 1, 15, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 12, 20, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            ),
+        ];
+
+        assert_expected_next_execution_times(
+            &TimeInterval::DaysOfMonth {
+                days: vec![1, 15],
+                hour: 0,
+                minute: 0,
+            },
+            last_execution_time,
+            scenarios,
+        );
+    }
+
+    #[test]
+    fn assert_days_of_month_next_execution_times_use_last_day_of_shorter_months() {
+        let last_execution_time = Utc.with_ymd_and_hms(2023, 1, 31, 12, 0, 0).unwrap();
+        let scenarios = vec![
+            (
+                last_execution_time,
+                Utc.with_ymd_and_hms(2023, 2, 28, 12, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 2, 28, 12, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2023, 3, 31, 12, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2023, 4, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2023, 4, 30, 12, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap(),
+            ),
+        ];
+
+        assert_expected_next_execution_times(
+            &TimeInterval::DaysOfMonth {
+                days: vec![31],
+                hour: 12,
+                minute: 0,
+            },
+            last_execution_time,
+            scenarios,
+        );
+    }
 }
 
 #[cfg(test)]
 mod get_total_execution_duration_tests {
     use super::{get_total_execution_duration, shift_months};
-    use crate::types::time_interval::TimeInterval;
+    use crate::types::time_interval::{DayOfWeek, TimeInterval};
     use chrono::{Duration, TimeZone, Utc};
     use cosmwasm_std::Timestamp;
 
@@ -714,4 +938,38 @@ mod get_total_execution_duration_tests {
             shift_months(block_time_utc, 3) - block_time_utc,
         );
     }
+
+    #[test]
+    fn get_total_execution_duration_with_calendar_intervals_tests() {
+        let block_time_utc = Utc.with_ymd_and_hms(2023, 1, 6, 9, 30, 0).unwrap();
+        let block_timestamp =
+            Timestamp::from_seconds(block_time_utc.timestamp().try_into().unwrap());
+
+        assert_total_execution_duration(
+            block_timestamp,
+            3,
+            TimeInterval::DaysOfWeek {
+                days: vec![
+                    DayOfWeek::Monday,
+                    DayOfWeek::Tuesday,
+                    DayOfWeek::Wednesday,
+                    DayOfWeek::Thursday,
+                    DayOfWeek::Friday,
+                ],
+                hour: 9,
+                minute: 30,
+            },
+            Duration::days(5),
+        );
+        assert_total_execution_duration(
+            block_timestamp,
+            2,
+            TimeInterval::DaysOfMonth {
+                days: vec![1, 15],
+                hour: 9,
+                minute: 30,
+            },
+            Utc.with_ymd_and_hms(2023, 2, 1, 9, 30, 0).unwrap() - block_time_utc,
+        );
+    }
 }
@@ -1,26 +1,34 @@
+use super::ibc_hooks::get_ibc_hook_sender;
 use super::routes::calculate_route;
 use crate::error::ContractError;
 use crate::msg::ExecuteMsg;
 use crate::state::config::get_config;
 use crate::state::pairs::{find_pair, get_pairs};
-use crate::types::destination::Destination;
+use crate::state::vaults::get_vault;
+use crate::types::accumulation::Accumulation;
+use crate::types::destination::{Destination, DestinationAction};
+use crate::types::execution_window::ExecutionWindow;
 use crate::types::fee_collector::FeeCollector;
 use crate::types::pair::Pair;
-use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategyParams;
+use crate::types::performance_assessment_strategy::{
+    PerformanceAssessmentStrategy, PerformanceAssessmentStrategyParams,
+};
+use crate::types::retry_policy::RetryPolicy;
+use crate::types::role::Role;
 use crate::types::swap_adjustment_strategy::{
     SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
-use crate::types::time_interval::TimeInterval;
+use crate::types::termination_condition::TerminationCondition;
+use crate::types::time_interval::{DayOfWeek, TimeInterval};
+use crate::types::trigger::PriceDirection;
 use crate::types::vault::{Vault, VaultStatus};
 use cosmwasm_std::{
-    from_binary, Addr, Coin, Decimal, Deps, Env, QuerierWrapper, Storage, Timestamp, Uint128,
+    Addr, Coin, Decimal, Deps, Env, QuerierWrapper, Storage, Timestamp, Uint128, Uint64,
 };
 
 pub fn assert_exactly_one_asset(funds: Vec<Coin>) -> Result<(), ContractError> {
     if funds.is_empty() || funds.len() > 1 {
-        return Err(ContractError::CustomError {
-            val: format!("received {} denoms but required exactly 1", funds.len()),
-        });
+        return Err(ContractError::InvalidFundsCount { count: funds.len() });
     }
     Ok(())
 }
@@ -28,9 +36,7 @@ pub fn assert_exactly_one_asset(funds: Vec<Coin>) -> Result<(), ContractError> {
 pub fn assert_contract_is_not_paused(storage: &mut dyn Storage) -> Result<(), ContractError> {
     let config = get_config(storage)?;
     if config.paused {
-        return Err(ContractError::CustomError {
-            val: "contract is paused".to_string(),
-        });
+        return Err(ContractError::ContractPaused {});
     }
     Ok(())
 }
@@ -46,6 +52,35 @@ pub fn assert_sender_is_admin(
     Ok(())
 }
 
+pub fn assert_sender_has_role(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    role: Role,
+) -> Result<(), ContractError> {
+    if !get_config(storage)?.has_role(sender, role) {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn assert_sender_is_pending_admin(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    match get_config(storage)?.pending_admin {
+        None => Err(ContractError::NoPendingAdminTransfer {}),
+        Some(pending_admin) if pending_admin != *sender => Err(ContractError::Unauthorized {}),
+        _ => Ok(()),
+    }
+}
+
+pub fn assert_new_admin_is_valid(admin: &Addr, new_admin: &Addr) -> Result<(), ContractError> {
+    if new_admin == admin {
+        return Err(ContractError::NewAdminIsCurrentAdmin {});
+    }
+    Ok(())
+}
+
 pub fn assert_sender_is_executor(
     storage: &mut dyn Storage,
     env: &Env,
@@ -68,6 +103,47 @@ pub fn asset_sender_is_vault_owner(vault_owner: Addr, sender: Addr) -> Result<()
     Ok(())
 }
 
+pub fn assert_new_vault_owner_is_valid(
+    vault_owner: Addr,
+    new_owner: Addr,
+) -> Result<(), ContractError> {
+    if new_owner == vault_owner {
+        return Err(ContractError::NewOwnerIsCurrentOwner {});
+    }
+    Ok(())
+}
+
+pub fn assert_sender_is_ibc_hook_sender(
+    env: &Env,
+    sender: &Addr,
+    channel_id: &str,
+    original_sender: &str,
+) -> Result<(), ContractError> {
+    if *sender != get_ibc_hook_sender(&env.contract.address, channel_id, original_sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn assert_ibc_hook_msg_is_supported(msg: &ExecuteMsg) -> Result<(), ContractError> {
+    match msg {
+        ExecuteMsg::CreateVault { owner: None, .. } => Err(ContractError::IbcHookOwnerRequired {}),
+        ExecuteMsg::CreateVault { .. } | ExecuteMsg::Deposit { .. } => Ok(()),
+        _ => Err(ContractError::UnsupportedIbcHookMsg {}),
+    }
+}
+
+pub fn assert_sender_is_pending_vault_owner(
+    vault: &Vault,
+    sender: Addr,
+) -> Result<(), ContractError> {
+    match vault.pending_owner.clone() {
+        None => Err(ContractError::NoPendingOwnershipTransfer { vault_id: vault.id }),
+        Some(pending_owner) if pending_owner != sender => Err(ContractError::Unauthorized {}),
+        _ => Ok(()),
+    }
+}
+
 pub fn assert_sender_is_admin_or_vault_owner(
     storage: &mut dyn Storage,
     vault_owner: Addr,
@@ -94,17 +170,68 @@ pub fn assert_sender_is_contract_or_admin(
 
 pub fn assert_vault_is_not_cancelled(vault: &Vault) -> Result<(), ContractError> {
     if vault.status == VaultStatus::Cancelled {
-        return Err(ContractError::CustomError {
-            val: "vault is already cancelled".to_string(),
+        return Err(ContractError::VaultAlreadyCancelled { vault_id: vault.id });
+    }
+    Ok(())
+}
+
+// Scheduled vaults are not pausable: pausing drops the vault trigger, and a resumed vault
+// continues from its `started_at` on time triggers, which would discard a pending start time or
+// target price. Scheduled vaults can be cancelled instead.
+pub fn assert_vault_can_be_paused(vault: &Vault) -> Result<(), ContractError> {
+    if !vault.is_active() {
+        return Err(ContractError::VaultNotActive { vault_id: vault.id });
+    }
+    Ok(())
+}
+
+pub fn assert_vault_can_be_resumed(vault: &Vault) -> Result<(), ContractError> {
+    if !vault.is_paused() {
+        return Err(ContractError::VaultNotPaused { vault_id: vault.id });
+    }
+    if vault.balance.amount.is_zero() {
+        return Err(ContractError::VaultBalanceIsEmpty { vault_id: vault.id });
+    }
+    Ok(())
+}
+
+pub fn assert_withdrawal_amount_is_valid(
+    vault: &Vault,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroWithdrawalAmount {});
+    }
+    if amount > vault.balance.amount {
+        return Err(ContractError::InsufficientVaultBalance {
+            amount,
+            balance: vault.balance.amount,
         });
     }
+    if let Some(PerformanceAssessmentStrategy::CompareToStandardDca { swapped_amount, .. }) =
+        &vault.performance_assessment_strategy
+    {
+        let standard_dca_balance = vault
+            .deposited_amount
+            .amount
+            .saturating_sub(swapped_amount.amount);
+
+        if amount > standard_dca_balance {
+            return Err(ContractError::WithdrawalExceedsStandardDcaBalance {
+                maximum: standard_dca_balance,
+            });
+        }
+    }
     Ok(())
 }
 
-pub fn assert_swap_amount_is_greater_than_50000(swap_amount: Uint128) -> Result<(), ContractError> {
-    if swap_amount <= Uint128::from(50000u128) {
-        return Err(ContractError::CustomError {
-            val: String::from("swap amount must be greater than 50000"),
+pub fn assert_swap_amount_is_greater_than_minimum(
+    swap_amount: Uint128,
+    minimum_swap_amount: Uint128,
+) -> Result<(), ContractError> {
+    if swap_amount <= minimum_swap_amount {
+        return Err(ContractError::SwapAmountTooSmall {
+            minimum_swap_amount,
         });
     }
     Ok(())
@@ -115,11 +242,9 @@ pub fn assert_deposited_denom_matches_send_denom(
     send_denom: String,
 ) -> Result<(), ContractError> {
     if deposit_denom != send_denom {
-        return Err(ContractError::CustomError {
-            val: format!(
-                "received asset with denom {}, but needed {}",
-                deposit_denom, send_denom
-            ),
+        return Err(ContractError::UnexpectedDenom {
+            received: deposit_denom,
+            expected: send_denom,
         });
     }
     Ok(())
@@ -130,9 +255,35 @@ pub fn assert_target_start_time_is_not_in_the_past(
     target_start_time: Timestamp,
 ) -> Result<(), ContractError> {
     if current_time.seconds().gt(&target_start_time.seconds()) {
-        return Err(ContractError::CustomError {
-            val: String::from("target_start_time_utc_seconds must be some time in the future"),
-        });
+        return Err(ContractError::StartTimeInPast {});
+    }
+    Ok(())
+}
+
+pub fn assert_end_time_is_after_start_time(
+    start_time: Timestamp,
+    end_time: Timestamp,
+) -> Result<(), ContractError> {
+    if end_time.seconds() <= start_time.seconds() {
+        return Err(ContractError::InvalidEndTime {});
+    }
+    Ok(())
+}
+
+pub fn assert_max_executions_is_greater_than_zero(
+    max_executions: u64,
+) -> Result<(), ContractError> {
+    if max_executions == 0 {
+        return Err(ContractError::ZeroMaxExecutions {});
+    }
+    Ok(())
+}
+
+pub fn assert_no_target_start_time_with_target_price(
+    target_start_time_utc_seconds: Option<Uint64>,
+) -> Result<(), ContractError> {
+    if target_start_time_utc_seconds.is_some() {
+        return Err(ContractError::StartTimeWithTargetPrice {});
     }
     Ok(())
 }
@@ -142,20 +293,102 @@ pub fn assert_target_time_is_in_past(
     target_time: Timestamp,
 ) -> Result<(), ContractError> {
     if current_time.seconds().lt(&target_time.seconds()) {
-        return Err(ContractError::CustomError {
-            val: String::from("trigger execution time has not yet elapsed"),
+        return Err(ContractError::TriggerTimeNotElapsed {});
+    }
+    Ok(())
+}
+
+pub fn assert_target_price_is_reached(
+    current_price: Decimal,
+    target_price: Decimal,
+    direction: PriceDirection,
+) -> Result<(), ContractError> {
+    if !direction.is_reached(target_price, current_price) {
+        return Err(ContractError::TriggerPriceNotReached {
+            target_price,
+            current_price,
         });
     }
     Ok(())
 }
 
+pub fn assert_target_price_is_greater_than_zero(
+    target_price: Decimal,
+) -> Result<(), ContractError> {
+    if target_price.is_zero() {
+        return Err(ContractError::ZeroTargetPrice {});
+    }
+    Ok(())
+}
+
+pub fn assert_termination_conditions_are_valid(
+    stop_loss: &Option<TerminationCondition>,
+    take_profit: &Option<TerminationCondition>,
+) -> Result<(), ContractError> {
+    if stop_loss
+        .iter()
+        .chain(take_profit.iter())
+        .any(|condition| condition.price.is_zero())
+    {
+        return Err(ContractError::ZeroTerminationPrice {});
+    }
+    if let (Some(stop_loss), Some(take_profit)) = (stop_loss, take_profit) {
+        if stop_loss.price >= take_profit.price {
+            return Err(ContractError::StopLossNotBelowTakeProfit {});
+        }
+    }
+    Ok(())
+}
+
 pub fn assert_time_interval_is_valid(interval: &TimeInterval) -> Result<(), ContractError> {
-    if let TimeInterval::Custom { seconds } = interval {
-        if *seconds < 60 {
-            return Err(ContractError::CustomError {
-                val: String::from("custom time interval must be at least 60 seconds"),
-            });
+    match interval {
+        TimeInterval::Custom { seconds } if *seconds < 60 => {
+            return Err(ContractError::CustomTimeIntervalTooShort { seconds: *seconds });
         }
+        TimeInterval::DaysOfWeek { days, hour, minute } => {
+            if days.is_empty() {
+                return Err(ContractError::EmptyCalendarTimeInterval {});
+            }
+            assert_calendar_time_of_day_is_valid(*hour, *minute)?;
+        }
+        TimeInterval::DaysOfMonth { days, hour, minute } => {
+            if days.is_empty() {
+                return Err(ContractError::EmptyCalendarTimeInterval {});
+            }
+            if days.iter().any(|day| !(1..=31).contains(day)) {
+                return Err(ContractError::InvalidDayOfMonth {});
+            }
+            assert_calendar_time_of_day_is_valid(*hour, *minute)?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+pub fn assert_execution_window_is_valid(
+    execution_window: &ExecutionWindow,
+) -> Result<(), ContractError> {
+    if execution_window.start_hour > 23 || execution_window.end_hour > 24 {
+        return Err(ContractError::InvalidExecutionWindowHours {
+            start_hour: execution_window.start_hour,
+            end_hour: execution_window.end_hour,
+        });
+    }
+    if execution_window.start_hour == execution_window.end_hour {
+        return Err(ContractError::EmptyExecutionWindow {});
+    }
+    if DayOfWeek::ALL
+        .iter()
+        .all(|day| execution_window.excludes_day(day))
+    {
+        return Err(ContractError::ExecutionWindowExcludesEveryDay {});
+    }
+    Ok(())
+}
+
+fn assert_calendar_time_of_day_is_valid(hour: u8, minute: u8) -> Result<(), ContractError> {
+    if hour > 23 || minute > 59 {
+        return Err(ContractError::InvalidTimeOfDay { hour, minute });
     }
     Ok(())
 }
@@ -164,13 +397,27 @@ pub fn assert_destinations_limit_is_not_breached(
     destinations: &[Destination],
 ) -> Result<(), ContractError> {
     if destinations.len() > 10 {
-        return Err(ContractError::CustomError {
-            val: String::from("no more than 10 destinations can be provided"),
+        return Err(ContractError::TooManyDestinations {
+            count: destinations.len(),
         });
     };
     Ok(())
 }
 
+pub fn assert_trigger_ids_limit_is_not_breached(
+    storage: &dyn Storage,
+    trigger_ids: &[Uint128],
+) -> Result<(), ContractError> {
+    if trigger_ids.is_empty() {
+        return Err(ContractError::NoTriggerIds {});
+    }
+    let limit = get_config(storage)?.default_page_limit;
+    if trigger_ids.len() > limit as usize {
+        return Err(ContractError::TooManyTriggerIds { limit });
+    }
+    Ok(())
+}
+
 pub fn assert_destination_callback_addresses_are_valid(
     deps: Deps,
     destinations: &[Destination],
@@ -203,8 +450,9 @@ pub fn assert_address_is_valid(
     deps.api
         .addr_validate(address.as_ref())
         .map(|_| ())
-        .map_err(|_| ContractError::CustomError {
-            val: format!("{} address {} is invalid", label, address),
+        .map_err(|_| ContractError::InvalidAddress {
+            label: label.to_string(),
+            address: address.to_string(),
         })
 }
 
@@ -218,11 +466,29 @@ pub fn assert_addresses_are_valid(
         .try_for_each(|address| assert_address_is_valid(deps, address, label))
 }
 
+pub fn assert_keeper_reward_percent_is_valid(
+    keeper_reward_percent: Decimal,
+) -> Result<(), ContractError> {
+    if keeper_reward_percent > Decimal::percent(100) {
+        return Err(ContractError::InvalidKeeperRewardPercent {
+            keeper_reward_percent,
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_retry_policy_is_valid(retry_policy: &RetryPolicy) -> Result<(), ContractError> {
+    if retry_policy.backoff_seconds < 60 {
+        return Err(ContractError::RetryBackoffTooShort {
+            backoff_seconds: retry_policy.backoff_seconds,
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_twap_period_is_valid(twap_period: u64) -> Result<(), ContractError> {
     if !(30..=3600).contains(&twap_period) {
-        return Err(ContractError::CustomError {
-            val: "twap_period must be between 30 and 3600".to_string(),
-        });
+        return Err(ContractError::InvalidTwapPeriod { twap_period });
     }
     Ok(())
 }
@@ -231,9 +497,7 @@ pub fn assert_slippage_tolerance_is_less_than_or_equal_to_one(
     slippage_tolerance: Decimal,
 ) -> Result<(), ContractError> {
     if slippage_tolerance > Decimal::percent(100) {
-        return Err(ContractError::CustomError {
-            val: "slippage tolerance must be less than or equal to 1".to_string(),
-        });
+        return Err(ContractError::InvalidSlippageTolerance { slippage_tolerance });
     }
     Ok(())
 }
@@ -245,8 +509,9 @@ pub fn assert_pair_exists_for_denoms(
 ) -> Result<(), ContractError> {
     find_pair(deps.storage, [swap_denom.clone(), target_denom.clone()])
         .map(|_| ())
-        .map_err(|_| ContractError::CustomError {
-            val: format!("swapping {} to {} not supported", swap_denom, target_denom),
+        .map_err(|_| ContractError::UnsupportedPair {
+            swap_denom,
+            target_denom,
         })
 }
 
@@ -258,28 +523,19 @@ pub fn assert_swap_adjusment_and_performance_assessment_strategies_are_compatibl
         Some(SwapAdjustmentStrategyParams::RiskWeightedAverage { .. }) => {
             match performance_assessment_strategy_params {
                 Some(PerformanceAssessmentStrategyParams::CompareToStandardDca) => Ok(()),
-                None => Err(ContractError::CustomError {
-                    val: "incompatible swap adjustment and performance assessment strategies"
-                        .to_string(),
-                }),
+                None => Err(ContractError::IncompatibleStrategies {}),
             }
         }
         Some(SwapAdjustmentStrategyParams::WeightedScale { .. }) => {
             match performance_assessment_strategy_params {
                 Some(PerformanceAssessmentStrategyParams::CompareToStandardDca) => {
-                    Err(ContractError::CustomError {
-                        val: "incompatible swap adjustment and performance assessment strategies"
-                            .to_string(),
-                    })
+                    Err(ContractError::IncompatibleStrategies {})
                 }
                 None => Ok(()),
             }
         }
         None => match performance_assessment_strategy_params {
-            Some(_) => Err(ContractError::CustomError {
-                val: "incompatible swap adjustment and performance assessment strategies"
-                    .to_string(),
-            }),
+            Some(_) => Err(ContractError::IncompatibleStrategies {}),
             None => Ok(()),
         },
     }
@@ -288,55 +544,110 @@ pub fn assert_swap_adjusment_and_performance_assessment_strategies_are_compatibl
 pub fn assert_destination_allocations_add_up_to_one(
     destinations: &[Destination],
 ) -> Result<(), ContractError> {
-    if destinations
+    let total = destinations
         .iter()
         .fold(Decimal::zero(), |acc, destintation| {
             acc.checked_add(destintation.allocation).unwrap()
-        })
-        != Decimal::percent(100)
-    {
-        return Err(ContractError::CustomError {
-            val: String::from("destination allocations must add up to 1"),
         });
+
+    if total != Decimal::percent(100) {
+        return Err(ContractError::InvalidDestinationAllocation { total });
     }
     Ok(())
 }
 
-pub fn assert_contract_destination_callbacks_are_valid(
+pub fn assert_destination_actions_are_valid(
+    storage: &dyn Storage,
     destinations: &[Destination],
     contract_address: &Addr,
+    target_denom: &str,
 ) -> Result<(), ContractError> {
-    destinations
-        .iter()
-        .filter(|d| d.address == *contract_address)
-        .try_for_each(|d| {
-            d.msg
-                .clone()
-                .map_or(Ok(()), |msg| match from_binary(&msg).unwrap() {
-                    ExecuteMsg::ZDelegate { .. }
-                    | ExecuteMsg::Deposit { .. }
-                    | ExecuteMsg::ZProvideLiquidity { .. } => Ok(()),
-                    _ => Err(ContractError::CustomError {
-                        val: "Cannot invoke provided destination callback against the DCA contract"
-                            .to_string(),
-                    }),
-                })
-        })
+    for destination in destinations {
+        match &destination.action {
+            DestinationAction::Send {} => {}
+            DestinationAction::ExecuteContract { .. } => {
+                if destination.address == *contract_address {
+                    return Err(ContractError::InvalidDestinationCallback {});
+                }
+            }
+            DestinationAction::Delegate { .. } => {
+                assert_denom_is_bond_denom(target_denom.to_string())?;
+            }
+            DestinationAction::ProvideLiquidity {
+                slippage_tolerance, ..
+            } => {
+                if let Some(slippage_tolerance) = slippage_tolerance {
+                    assert_slippage_tolerance_is_less_than_or_equal_to_one(*slippage_tolerance)?;
+                }
+            }
+            DestinationAction::DepositToVault { vault_id } => {
+                let vault = get_vault(storage, *vault_id)?;
+                assert_vault_is_not_cancelled(&vault)?;
+                assert_deposited_denom_matches_send_denom(
+                    target_denom.to_string(),
+                    vault.balance.denom,
+                )?;
+            }
+            DestinationAction::IbcTransfer {
+                channel_id,
+                receiver,
+                memo,
+            } => {
+                assert_ibc_channel_id_is_valid(channel_id)?;
+                if receiver.trim().is_empty() {
+                    return Err(ContractError::EmptyIbcReceiver {});
+                }
+                if let Some(memo) = memo {
+                    assert_ibc_memo_is_valid(memo)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn assert_ibc_channel_id_is_valid(channel_id: &str) -> Result<(), ContractError> {
+    let is_valid = channel_id
+        .strip_prefix("channel-")
+        .map(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+        .unwrap_or(false);
+
+    if !is_valid {
+        return Err(ContractError::InvalidIbcChannel {
+            channel_id: channel_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn assert_ibc_memo_is_valid(memo: &str) -> Result<(), ContractError> {
+    match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(memo) {
+        Ok(memo) if !memo.contains_key("ibc_callback") => Ok(()),
+        _ => Err(ContractError::InvalidIbcMemo {}),
+    }
+}
+
+pub fn assert_accumulation_is_valid(accumulation: &Accumulation) -> Result<(), ContractError> {
+    if accumulation
+        .claim_threshold
+        .is_some_and(|claim_threshold| claim_threshold.is_zero())
+    {
+        return Err(ContractError::ZeroClaimThreshold {});
+    }
+    Ok(())
 }
 
 pub fn assert_fee_collector_allocations_add_up_to_one(
     fee_collectors: &[FeeCollector],
 ) -> Result<(), ContractError> {
-    if fee_collectors
+    let total = fee_collectors
         .iter()
         .fold(Decimal::zero(), |acc, fee_collector| {
             acc.checked_add(fee_collector.allocation).unwrap()
-        })
-        != Decimal::percent(100)
-    {
-        return Err(ContractError::CustomError {
-            val: String::from("fee collector allocations must add up to 1"),
         });
+
+    if total != Decimal::percent(100) {
+        return Err(ContractError::InvalidFeeCollectorAllocation { total });
     }
     Ok(())
 }
@@ -345,8 +656,8 @@ pub fn assert_no_more_than_10_fee_collectors(
     fee_collectors: &[FeeCollector],
 ) -> Result<(), ContractError> {
     if fee_collectors.len() > 10 {
-        return Err(ContractError::CustomError {
-            val: String::from("no more than 10 fee collectors are allowed"),
+        return Err(ContractError::TooManyFeeCollectors {
+            count: fee_collectors.len(),
         });
     }
     Ok(())
@@ -356,8 +667,8 @@ pub fn assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent(
     risk_weighted_average_escrow_level: Decimal,
 ) -> Result<(), ContractError> {
     if risk_weighted_average_escrow_level > Decimal::percent(100) {
-        return Err(ContractError::CustomError {
-            val: "risk_weighted_average_escrow_level cannot be greater than 100%".to_string(),
+        return Err(ContractError::InvalidEscrowLevel {
+            escrow_level: risk_weighted_average_escrow_level,
         });
     }
     Ok(())
@@ -367,9 +678,7 @@ pub fn assert_no_destination_allocations_are_zero(
     destinations: &[Destination],
 ) -> Result<(), ContractError> {
     if destinations.iter().any(|d| d.allocation.is_zero()) {
-        return Err(ContractError::CustomError {
-            val: String::from("all destination allocations must be greater than 0"),
-        });
+        return Err(ContractError::ZeroDestinationAllocation {});
     }
     Ok(())
 }
@@ -377,13 +686,9 @@ pub fn assert_no_destination_allocations_are_zero(
 pub fn assert_page_limit_is_valid(limit: Option<u16>) -> Result<(), ContractError> {
     if let Some(limit) = limit {
         if limit < 30 {
-            return Err(ContractError::CustomError {
-                val: "limit cannot be less than 30.".to_string(),
-            });
+            return Err(ContractError::PageLimitTooSmall { limit });
         } else if limit > 1000 {
-            return Err(ContractError::CustomError {
-                val: "limit cannot be greater than 1000.".to_string(),
-            });
+            return Err(ContractError::PageLimitTooLarge { limit });
         }
     }
     Ok(())
@@ -396,27 +701,21 @@ pub fn assert_validator_is_valid(
     let validator = deps.querier.query_validator(validator_address.clone()).ok();
 
     if validator.is_none() {
-        return Err(ContractError::CustomError {
-            val: format!("validator {} is invalid", validator_address),
-        });
+        return Err(ContractError::InvalidValidator { validator_address });
     }
     Ok(())
 }
 
 pub fn assert_denom_is_bond_denom(denom: String) -> Result<(), ContractError> {
     if denom != *"uosmo" {
-        return Err(ContractError::CustomError {
-            val: format!("{} is not the bond denomination", denom),
-        });
+        return Err(ContractError::NotBondDenom { denom });
     }
     Ok(())
 }
 
 pub fn assert_label_is_no_longer_than_100_characters(label: &str) -> Result<(), ContractError> {
     if label.len() > 100 {
-        return Err(ContractError::CustomError {
-            val: "Vault label cannot be longer than 100 characters".to_string(),
-        });
+        return Err(ContractError::LabelTooLong {});
     }
     Ok(())
 }
@@ -433,9 +732,7 @@ pub fn assert_route_matches_denoms(
 
 pub fn assert_route_not_empty(route: Vec<u64>) -> Result<(), ContractError> {
     if route.is_empty() {
-        return Err(ContractError::CustomError {
-            val: "Swap route must not be empty".to_string(),
-        });
+        return Err(ContractError::EmptyRoute {});
     }
     Ok(())
 }
@@ -445,10 +742,82 @@ pub fn assert_route_has_no_duplicate_entries(route: Vec<u64>) -> Result<(), Cont
     deduped_route.sort();
     deduped_route.dedup();
     if route.len() != deduped_route.len() {
-        return Err(ContractError::CustomError {
-            val: "Swap route must not contain duplicate entries".to_string(),
+        return Err(ContractError::DuplicateRouteEntries {});
+    }
+    Ok(())
+}
+
+pub fn assert_weighted_routes_are_valid(
+    querier: &QuerierWrapper,
+    pair: &Pair,
+) -> Result<(), ContractError> {
+    let Some(weighted_routes) = pair.weighted_routes.clone() else {
+        return Ok(());
+    };
+
+    if weighted_routes.is_empty() {
+        return Err(ContractError::EmptyWeightedRoutes {});
+    }
+
+    let mut routes = weighted_routes
+        .iter()
+        .map(|weighted_route| weighted_route.route.clone())
+        .collect::<Vec<Vec<u64>>>();
+    routes.sort();
+    routes.dedup();
+
+    if routes.len() != weighted_routes.len() {
+        return Err(ContractError::DuplicateWeightedRoutes {});
+    }
+
+    for weighted_route in weighted_routes.iter() {
+        if weighted_route.weight.is_zero() {
+            return Err(ContractError::ZeroWeightedRouteWeight {});
+        }
+
+        assert_route_not_empty(weighted_route.route.clone())?;
+        assert_route_has_no_duplicate_entries(weighted_route.route.clone())?;
+        assert_route_matches_denoms(querier, &pair.with_route(weighted_route.route.clone()))?;
+    }
+
+    let total_weight = weighted_routes
+        .iter()
+        .fold(Decimal::zero(), |total, weighted_route| {
+            total + weighted_route.weight
+        });
+
+    if total_weight != Decimal::one() {
+        return Err(ContractError::InvalidWeightedRouteWeights {
+            total: total_weight,
         });
     }
+
+    Ok(())
+}
+
+pub fn assert_pair_settings_are_valid(pair: &Pair) -> Result<(), ContractError> {
+    let mut minimum_swap_amount_denoms = Vec::<String>::new();
+
+    for minimum_swap_amount in pair.minimum_swap_amounts.clone().unwrap_or_default() {
+        if !pair.denoms().contains(&minimum_swap_amount.denom)
+            || minimum_swap_amount_denoms.contains(&minimum_swap_amount.denom)
+        {
+            return Err(ContractError::InvalidMinimumSwapAmountDenom {
+                denom: minimum_swap_amount.denom,
+            });
+        }
+
+        minimum_swap_amount_denoms.push(minimum_swap_amount.denom);
+    }
+
+    if let Some(twap_period) = pair.twap_period {
+        assert_twap_period_is_valid(twap_period)?;
+    }
+
+    if let Some(default_slippage_tolerance) = pair.default_slippage_tolerance {
+        assert_slippage_tolerance_is_less_than_or_equal_to_one(default_slippage_tolerance)?;
+    }
+
     Ok(())
 }
 
@@ -457,13 +826,10 @@ pub fn assert_swap_adjustment_value_is_valid(
     value: Decimal,
 ) -> Result<(), ContractError> {
     if value < strategy.min_adjustment() || value > strategy.max_adjustment() {
-        return Err(ContractError::CustomError {
-            val: format!(
-                "swap adjustment value for strategy {:?} must be between {} and {}",
-                strategy,
-                strategy.min_adjustment(),
-                strategy.max_adjustment()
-            ),
+        return Err(ContractError::InvalidSwapAdjustmentValue {
+            strategy: format!("{:?}", strategy),
+            min: strategy.min_adjustment(),
+            max: strategy.max_adjustment(),
         });
     }
     Ok(())
@@ -474,8 +840,8 @@ pub fn assert_swap_adjustment_strategy_params_are_valid(
 ) -> Result<(), ContractError> {
     if let SwapAdjustmentStrategyParams::WeightedScale { multiplier, .. } = strategy {
         if multiplier > &Decimal::percent(1000) {
-            return Err(ContractError::CustomError {
-                val: "Cannot set weighted scale multiplier to more than 10".to_string(),
+            return Err(ContractError::WeightedScaleMultiplierTooLarge {
+                multiplier: *multiplier,
             });
         }
     }
@@ -484,8 +850,8 @@ pub fn assert_swap_adjustment_strategy_params_are_valid(
 
 pub fn assert_fee_level_is_valid(swap_fee_percent: &Decimal) -> Result<(), ContractError> {
     if swap_fee_percent > &Decimal::percent(5) {
-        return Err(ContractError::CustomError {
-            val: "fee level cannot be larger than 5%".to_string(),
+        return Err(ContractError::FeeLevelTooLarge {
+            fee_percent: *swap_fee_percent,
         });
     }
     Ok(())
@@ -494,9 +860,7 @@ pub fn assert_fee_level_is_valid(swap_fee_percent: &Decimal) -> Result<(), Contr
 pub fn assert_denom_exists(storage: &dyn Storage, denom: String) -> Result<(), ContractError> {
     let pairs = get_pairs(storage, None, None);
     if !pairs.iter().any(|p| p.denoms().contains(&denom)) {
-        return Err(ContractError::CustomError {
-            val: format!("{} is not supported", denom),
-        });
+        return Err(ContractError::UnsupportedDenom { denom });
     }
     Ok(())
 }
@@ -505,9 +869,7 @@ pub fn assert_weighted_scale_multiplier_is_no_more_than_10(
     multiplier: Decimal,
 ) -> Result<(), ContractError> {
     if multiplier > Decimal::percent(1000) {
-        return Err(ContractError::CustomError {
-            val: "Cannot set weighted scale multiplier to more than 10".to_string(),
-        });
+        return Err(ContractError::WeightedScaleMultiplierTooLarge { multiplier });
     }
     Ok(())
 }
@@ -1,13 +1,13 @@
 use super::{
-    coin::add_to,
+    coin::{add_to, empty_of},
     fees::{get_automation_fee_rate, get_swap_fee_rate},
     price::{calculate_slippage, query_belief_price, query_price},
     time::get_total_execution_duration,
 };
 use crate::{
     state::{
-        events::create_event, pairs::find_pair, swap_adjustments::get_swap_adjustment,
-        vaults::update_vault,
+        disburse_escrow_tasks::save_disburse_escrow_task, events::create_event, pairs::find_pair,
+        swap_adjustments::get_swap_adjustment, triggers::delete_trigger, vaults::update_vault,
     },
     types::{
         event::{EventBuilder, EventData, ExecutionSkippedReason},
@@ -15,12 +15,12 @@ use crate::{
         position_type::PositionType,
         swap_adjustment_strategy::SwapAdjustmentStrategy,
         time_interval::TimeInterval,
-        vault::Vault,
+        vault::{Vault, VaultStatus},
     },
 };
 use cosmwasm_std::{
-    Coin, Decimal, Deps, Env, QuerierWrapper, Response, StdError, StdResult, Storage, Timestamp,
-    Uint128,
+    BankMsg, Coin, Decimal, Deps, Env, QuerierWrapper, Response, StdError, StdResult, Storage,
+    SubMsg, Timestamp, Uint128,
 };
 use std::cmp::min;
 
@@ -29,6 +29,62 @@ pub fn get_position_type(deps: &Deps, vault: &Vault) -> StdResult<PositionType>
     Ok(pair.position_type(vault.get_swap_denom()))
 }
 
+pub fn cancel_vault(storage: &mut dyn Storage, env: &Env, vault: Vault) -> StdResult<Vec<SubMsg>> {
+    create_event(
+        storage,
+        EventBuilder::new(vault.id, env.block.clone(), EventData::DcaVaultCancelled {}),
+    )?;
+
+    if vault.escrowed_amount.amount > Uint128::zero() {
+        save_disburse_escrow_task(
+            storage,
+            vault.id,
+            vault.get_expected_execution_completed_date(env.block.time),
+        )?;
+    };
+
+    let mut submessages = Vec::<SubMsg>::new();
+
+    if vault.balance.amount > Uint128::zero() {
+        submessages.push(SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![vault.balance.clone()],
+        }));
+    }
+
+    if vault.claimable_amount.amount > Uint128::zero() {
+        create_event(
+            storage,
+            EventBuilder::new(
+                vault.id,
+                env.block.clone(),
+                EventData::DcaVaultFundsClaimed {
+                    amount: vault.claimable_amount.clone(),
+                },
+            ),
+        )?;
+
+        submessages.push(SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![vault.claimable_amount.clone()],
+        }));
+    }
+
+    let updated_vault = update_vault(
+        storage,
+        Vault {
+            status: VaultStatus::Cancelled,
+            balance: empty_of(vault.balance.clone()),
+            claimable_amount: empty_of(vault.claimable_amount.clone()),
+            ..vault
+        },
+    )?;
+
+    delete_trigger(storage, updated_vault.id)?;
+
+    Ok(submessages)
+}
+
 pub fn get_swap_amount(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Coin> {
     let swap_adjustment = match vault.swap_adjustment_strategy.clone() {
         Some(SwapAdjustmentStrategy::WeightedScale {
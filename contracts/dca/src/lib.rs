@@ -1,8 +1,23 @@
+// Lints added by newer toolchains than this crate targets. Allowing them here keeps the
+// existing code building under `-D warnings` without raising the minimum Rust version.
+#![allow(unknown_lints, clippy::unnecessary_map_or)]
+#![cfg_attr(
+    test,
+    allow(
+        clippy::cloned_ref_to_slice_refs,
+        clippy::multiple_bound_locations,
+        clippy::unnecessary_fallible_conversions,
+        clippy::unnecessary_literal_unwrap,
+        clippy::useless_conversion
+    )
+)]
+
 pub mod constants;
 pub mod contract;
 mod error;
 pub mod handlers;
 pub mod helpers;
+pub mod migrations;
 pub mod msg;
 pub mod state;
 pub mod tests;
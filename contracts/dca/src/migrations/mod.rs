@@ -0,0 +1,142 @@
+pub mod v1_1_0;
+
+use crate::{error::ContractError, msg::MigrateMsg};
+use cosmwasm_std::{Attribute, StdError, Storage};
+use semver::Version;
+
+pub type MigrationStep = fn(&mut dyn Storage, &MigrateMsg) -> Result<Vec<Attribute>, ContractError>;
+
+/// Migration steps in ascending version order. A step runs when migrating from a version
+/// below its own to a version at or above it.
+pub const MIGRATIONS: [(&str, MigrationStep); 1] = [("1.1.0", v1_1_0::migrate)];
+
+pub fn parse_version(version: &str) -> Result<Version, ContractError> {
+    Version::parse(version).map_err(|error| {
+        StdError::generic_err(format!("Invalid contract version {}: {}", version, error)).into()
+    })
+}
+
+pub fn pending_migrations(
+    migrations: &[(&str, MigrationStep)],
+    from: &Version,
+    to: &Version,
+) -> Result<Vec<(Version, MigrationStep)>, ContractError> {
+    let mut pending = Vec::<(Version, MigrationStep)>::new();
+
+    for (version, step) in migrations {
+        let version = parse_version(version)?;
+
+        if let Some((previous_version, _)) = pending.last() {
+            if *previous_version >= version {
+                return Err(
+                    StdError::generic_err("Migrations must be in ascending version order").into(),
+                );
+            }
+        }
+
+        if *from < version && version <= *to {
+            pending.push((version, *step));
+        }
+    }
+
+    Ok(pending)
+}
+
+#[cfg(test)]
+mod pending_migrations_tests {
+    use super::*;
+    use cosmwasm_std::Attribute;
+
+    fn step_a(_: &mut dyn Storage, _: &MigrateMsg) -> Result<Vec<Attribute>, ContractError> {
+        Ok(vec![Attribute::new("step", "a")])
+    }
+
+    fn step_b(_: &mut dyn Storage, _: &MigrateMsg) -> Result<Vec<Attribute>, ContractError> {
+        Ok(vec![Attribute::new("step", "b")])
+    }
+
+    fn versions(pending: Vec<(Version, MigrationStep)>) -> Vec<String> {
+        pending
+            .into_iter()
+            .map(|(version, _)| version.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn compares_versions_numerically() {
+        let migrations: [(&str, MigrationStep); 2] = [("1.0.9", step_a), ("1.0.10", step_b)];
+
+        let pending = pending_migrations(
+            &migrations,
+            &parse_version("1.0.9").unwrap(),
+            &parse_version("1.0.10").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(versions(pending), vec!["1.0.10"]);
+    }
+
+    #[test]
+    fn returns_all_steps_between_versions_in_order() {
+        let migrations: [(&str, MigrationStep); 2] = [("1.0.9", step_a), ("1.0.10", step_b)];
+
+        let pending = pending_migrations(
+            &migrations,
+            &parse_version("1.0.8").unwrap(),
+            &parse_version("1.1.0").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(versions(pending), vec!["1.0.9", "1.0.10"]);
+    }
+
+    #[test]
+    fn skips_steps_above_target_version() {
+        let migrations: [(&str, MigrationStep); 2] = [("1.0.9", step_a), ("1.0.10", step_b)];
+
+        let pending = pending_migrations(
+            &migrations,
+            &parse_version("1.0.8").unwrap(),
+            &parse_version("1.0.9").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(versions(pending), vec!["1.0.9"]);
+    }
+
+    #[test]
+    fn with_unordered_steps_fails() {
+        let migrations: [(&str, MigrationStep); 2] = [("1.0.10", step_b), ("1.0.9", step_a)];
+
+        let err = pending_migrations(
+            &migrations,
+            &parse_version("1.0.8").unwrap(),
+            &parse_version("1.1.0").unwrap(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: Migrations must be in ascending version order"
+        );
+    }
+
+    #[test]
+    fn with_invalid_version_fails() {
+        let err = parse_version("1.0").unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("Generic error: Invalid contract version 1.0"));
+    }
+
+    #[test]
+    fn contract_migrations_are_in_ascending_version_order() {
+        pending_migrations(
+            &MIGRATIONS,
+            &parse_version("0.0.0").unwrap(),
+            &parse_version("1.1.0").unwrap(),
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,359 @@
+use crate::{
+    constants::DEFAULT_MIGRATION_LIMIT,
+    error::ContractError,
+    msg::MigrateMsg,
+    state::{
+        migrations::{
+            clear_migration_cursor, get_migration_cursor, save_migration_cursor, MigrationCursor,
+        },
+        pairs::find_pair,
+        triggers::migrate_triggers,
+        vaults::{get_vault, migrate_vaults},
+    },
+    types::swap_adjustment_strategy::SwapAdjustmentStrategy,
+};
+use cosmwasm_std::{Attribute, Storage};
+
+pub fn migrate(store: &mut dyn Storage, msg: &MigrateMsg) -> Result<Vec<Attribute>, ContractError> {
+    let fix_position_types = msg.fix_position_types.unwrap_or(false);
+    let limit = msg.limit.unwrap_or(DEFAULT_MIGRATION_LIMIT);
+
+    let mut attributes = Vec::<Attribute>::new();
+
+    let triggers_start_after = match get_migration_cursor(store)?
+        .unwrap_or(MigrationCursor::Vaults { start_after: None })
+    {
+        MigrationCursor::Vaults { start_after } => {
+            // re-saving every vault also populates the vault indexes added in this version
+            let vault_ids = migrate_vaults(store, start_after, limit, |store, mut vault| {
+                if !fix_position_types {
+                    return Ok(vault);
+                }
+
+                if let Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+                    model_id,
+                    base_denom,
+                    ..
+                }) = vault.swap_adjustment_strategy.clone()
+                {
+                    // vaults for deleted pairs are left as they are
+                    if let Ok(pair) = find_pair(store, vault.denoms()) {
+                        vault.swap_adjustment_strategy =
+                            Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+                                model_id,
+                                base_denom,
+                                position_type: pair.position_type(vault.get_swap_denom()),
+                            });
+                    }
+                }
+
+                Ok(vault)
+            })?;
+
+            attributes.push(Attribute::new(
+                "vaults_migrated",
+                vault_ids.len().to_string(),
+            ));
+
+            if vault_ids.len() == limit as usize {
+                save_migration_cursor(
+                    store,
+                    &MigrationCursor::Vaults {
+                        start_after: vault_ids.last().copied(),
+                    },
+                )?;
+                return Ok(attributes);
+            }
+
+            None
+        }
+        MigrationCursor::Triggers { start_after } => start_after,
+    };
+
+    if msg.remove_orphaned_triggers.unwrap_or(false) {
+        let mut triggers_removed = 0u64;
+
+        let vault_ids =
+            migrate_triggers(
+                store,
+                triggers_start_after,
+                limit,
+                |store, trigger| match get_vault(store, trigger.vault_id) {
+                    Ok(vault) if !vault.is_cancelled() => Ok(Some(trigger)),
+                    _ => {
+                        triggers_removed += 1;
+                        Ok(None)
+                    }
+                },
+            )?;
+
+        attributes.push(Attribute::new(
+            "triggers_removed",
+            triggers_removed.to_string(),
+        ));
+
+        if vault_ids.len() == limit as usize {
+            save_migration_cursor(
+                store,
+                &MigrationCursor::Triggers {
+                    start_after: vault_ids.last().copied(),
+                },
+            )?;
+            return Ok(attributes);
+        }
+    }
+
+    clear_migration_cursor(store);
+
+    Ok(attributes)
+}
+
+#[cfg(test)]
+mod migrate_tests {
+    use super::*;
+    use crate::{
+        state::triggers::{get_trigger, save_trigger},
+        tests::helpers::{instantiate_contract, setup_vault},
+        tests::mocks::ADMIN,
+        types::{
+            position_type::PositionType,
+            swap_adjustment_strategy::BaseDenom,
+            trigger::{Trigger, TriggerConfiguration},
+            vault::{Vault, VaultStatus},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Uint128,
+    };
+
+    fn risk_weighted_average(position_type: PositionType) -> Option<SwapAdjustmentStrategy> {
+        Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type,
+        })
+    }
+
+    #[test]
+    fn fixes_position_types_when_requested() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                swap_adjustment_strategy: risk_weighted_average(PositionType::Exit),
+                ..Vault::default()
+            },
+        );
+
+        migrate(
+            deps.as_mut().storage,
+            &MigrateMsg {
+                fix_position_types: Some(true),
+                remove_orphaned_triggers: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.swap_adjustment_strategy,
+            risk_weighted_average(PositionType::Enter)
+        );
+    }
+
+    #[test]
+    fn leaves_position_types_unchanged_by_default() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                swap_adjustment_strategy: risk_weighted_average(PositionType::Exit),
+                ..Vault::default()
+            },
+        );
+
+        migrate(
+            deps.as_mut().storage,
+            &MigrateMsg {
+                fix_position_types: None,
+                remove_orphaned_triggers: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.swap_adjustment_strategy,
+            risk_weighted_average(PositionType::Exit)
+        );
+    }
+
+    #[test]
+    fn removes_triggers_for_cancelled_and_missing_vaults_when_requested() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let active_vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let cancelled_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Cancelled,
+                ..Vault::default()
+            },
+        );
+
+        let missing_vault_id = Uint128::new(100);
+
+        save_trigger(
+            deps.as_mut().storage,
+            Trigger {
+                vault_id: missing_vault_id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: env.block.time,
+                },
+            },
+        )
+        .unwrap();
+
+        let attributes = migrate(
+            deps.as_mut().storage,
+            &MigrateMsg {
+                fix_position_types: None,
+                remove_orphaned_triggers: Some(true),
+                limit: None,
+            },
+        )
+        .unwrap();
+
+        assert!(get_trigger(deps.as_ref().storage, active_vault.id)
+            .unwrap()
+            .is_some());
+        assert!(get_trigger(deps.as_ref().storage, cancelled_vault.id)
+            .unwrap()
+            .is_none());
+        assert!(get_trigger(deps.as_ref().storage, missing_vault_id)
+            .unwrap()
+            .is_none());
+        assert!(attributes.contains(&Attribute::new("triggers_removed", "2")));
+    }
+
+    #[test]
+    fn migrates_vaults_in_batches_resuming_from_the_cursor() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vaults = (0..3)
+            .map(|_| {
+                setup_vault(
+                    deps.as_mut(),
+                    env.clone(),
+                    Vault {
+                        swap_adjustment_strategy: risk_weighted_average(PositionType::Exit),
+                        ..Vault::default()
+                    },
+                )
+            })
+            .collect::<Vec<Vault>>();
+
+        let msg = MigrateMsg {
+            fix_position_types: Some(true),
+            remove_orphaned_triggers: None,
+            limit: Some(2),
+        };
+
+        let attributes = migrate(deps.as_mut().storage, &msg).unwrap();
+
+        assert!(attributes.contains(&Attribute::new("vaults_migrated", "2")));
+        assert_eq!(
+            get_migration_cursor(deps.as_ref().storage).unwrap(),
+            Some(MigrationCursor::Vaults {
+                start_after: Some(vaults[1].id)
+            })
+        );
+        assert_eq!(
+            get_vault(deps.as_ref().storage, vaults[2].id)
+                .unwrap()
+                .swap_adjustment_strategy,
+            risk_weighted_average(PositionType::Exit)
+        );
+
+        let attributes = migrate(deps.as_mut().storage, &msg).unwrap();
+
+        assert!(attributes.contains(&Attribute::new("vaults_migrated", "1")));
+        assert_eq!(get_migration_cursor(deps.as_ref().storage).unwrap(), None);
+        assert_eq!(
+            get_vault(deps.as_ref().storage, vaults[2].id)
+                .unwrap()
+                .swap_adjustment_strategy,
+            risk_weighted_average(PositionType::Enter)
+        );
+    }
+
+    #[test]
+    fn removes_orphaned_triggers_in_batches_resuming_from_the_cursor() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for vault_id in [100u128, 101u128] {
+            save_trigger(
+                deps.as_mut().storage,
+                Trigger {
+                    vault_id: Uint128::new(vault_id),
+                    configuration: TriggerConfiguration::Time {
+                        target_time: env.block.time,
+                    },
+                },
+            )
+            .unwrap();
+        }
+
+        let msg = MigrateMsg {
+            fix_position_types: None,
+            remove_orphaned_triggers: Some(true),
+            limit: Some(1),
+        };
+
+        migrate(deps.as_mut().storage, &msg).unwrap();
+
+        assert_eq!(
+            get_migration_cursor(deps.as_ref().storage).unwrap(),
+            Some(MigrationCursor::Triggers {
+                start_after: Some(Uint128::new(100))
+            })
+        );
+        assert!(get_trigger(deps.as_ref().storage, Uint128::new(101))
+            .unwrap()
+            .is_some());
+
+        migrate(deps.as_mut().storage, &msg).unwrap();
+        migrate(deps.as_mut().storage, &msg).unwrap();
+
+        assert_eq!(get_migration_cursor(deps.as_ref().storage).unwrap(), None);
+        assert!(get_trigger(deps.as_ref().storage, Uint128::new(101))
+            .unwrap()
+            .is_none());
+    }
+}
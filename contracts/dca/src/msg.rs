@@ -1,18 +1,24 @@
+use crate::types::accumulation::Accumulation;
 use crate::types::config::Config;
 use crate::types::destination::Destination;
-use crate::types::event::Event;
+use crate::types::event::{Event, ExecutionSkippedReason};
+use crate::types::execution_window::ExecutionWindow;
 use crate::types::fee_collector::FeeCollector;
+use crate::types::ibc_transfer::IbcLifecycleComplete;
 use crate::types::lockable_duration::LockableDuration;
-use crate::types::pair::Pair;
+use crate::types::pair::{Pair, WeightedRoute};
 use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategyParams;
 use crate::types::position_type::PositionType;
+use crate::types::post_execution_action::FailedPostExecutionAction;
+use crate::types::retry_policy::RetryPolicy;
 use crate::types::swap_adjustment_strategy::{
     SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
+use crate::types::termination_condition::{TerminationCondition, TerminationReason};
 use crate::types::time_interval::TimeInterval;
 use crate::types::vault::{Vault, VaultStatus};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Decimal, Uint128, Uint64};
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128, Uint64};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -27,10 +33,22 @@ pub struct InstantiateMsg {
     pub risk_weighted_average_escrow_level: Decimal,
     pub twap_period: u64,
     pub default_slippage_tolerance: Decimal,
+    pub retry_policy: Option<RetryPolicy>,
+    pub keeper_reward_percent: Option<Decimal>,
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    pub fix_position_types: Option<bool>,
+    pub remove_orphaned_triggers: Option<bool>,
+    pub limit: Option<u16>,
+}
+
+#[cw_serde]
+pub enum SudoMsg {
+    #[serde(rename = "ibc_lifecycle_complete")]
+    IbcLifecycleComplete(IbcLifecycleComplete),
+}
 
 #[cw_serde]
 pub enum ExecuteMsg {
@@ -38,6 +56,10 @@ pub enum ExecuteMsg {
         base_denom: String,
         quote_denom: String,
         route: Vec<u64>,
+        weighted_routes: Option<Vec<WeightedRoute>>,
+        minimum_swap_amounts: Option<Vec<Coin>>,
+        twap_period: Option<u64>,
+        default_slippage_tolerance: Option<Decimal>,
     },
     CreateVault {
         owner: Option<Addr>,
@@ -52,11 +74,22 @@ pub enum ExecuteMsg {
         target_start_time_utc_seconds: Option<Uint64>,
         performance_assessment_strategy: Option<PerformanceAssessmentStrategyParams>,
         swap_adjustment_strategy: Option<SwapAdjustmentStrategyParams>,
+        target_price: Option<Decimal>,
+        stop_loss: Option<TerminationCondition>,
+        take_profit: Option<TerminationCondition>,
+        execution_window: Option<ExecutionWindow>,
+        end_time_utc_seconds: Option<Uint64>,
+        max_executions: Option<u64>,
+        accumulation: Option<Accumulation>,
     },
     Deposit {
         address: Addr,
         vault_id: Uint128,
     },
+    WithdrawFromVault {
+        vault_id: Uint128,
+        amount: Uint128,
+    },
     UpdateVault {
         vault_id: Uint128,
         label: Option<String>,
@@ -65,13 +98,33 @@ pub enum ExecuteMsg {
         minimum_receive_amount: Option<Uint128>,
         time_interval: Option<TimeInterval>,
         swap_adjustment_strategy: Option<SwapAdjustmentStrategyParams>,
+        stop_loss: Option<TerminationCondition>,
+        take_profit: Option<TerminationCondition>,
+        clear_stop_loss: Option<bool>,
+        clear_take_profit: Option<bool>,
     },
     CancelVault {
         vault_id: Uint128,
     },
+    PauseVault {
+        vault_id: Uint128,
+    },
+    ResumeVault {
+        vault_id: Uint128,
+    },
+    TransferVaultOwnership {
+        vault_id: Uint128,
+        new_owner: Addr,
+    },
+    AcceptVaultOwnership {
+        vault_id: Uint128,
+    },
     ExecuteTrigger {
         trigger_id: Uint128,
     },
+    ExecuteTriggers {
+        trigger_ids: Vec<Uint128>,
+    },
     UpdateConfig {
         executors: Option<Vec<Addr>>,
         fee_collectors: Option<Vec<FeeCollector>>,
@@ -83,6 +136,11 @@ pub enum ExecuteMsg {
         risk_weighted_average_escrow_level: Option<Decimal>,
         twap_period: Option<u64>,
         default_slippage_tolerance: Option<Decimal>,
+        retry_policy: Option<RetryPolicy>,
+        keeper_reward_percent: Option<Decimal>,
+        pair_managers: Option<Vec<Addr>>,
+        fee_managers: Option<Vec<Addr>>,
+        pausers: Option<Vec<Addr>>,
     },
     CreateCustomSwapFee {
         denom: String,
@@ -111,9 +169,28 @@ pub enum ExecuteMsg {
     CreatePairs {
         pairs: Vec<Pair>,
     },
-    FixPositionType {
+    DeletePair {
+        base_denom: String,
+        quote_denom: String,
+        force_cancel_vaults: Option<bool>,
+    },
+    TransferAdmin {
+        new_admin: Addr,
+    },
+    AcceptAdmin {},
+    PauseContract {},
+    ResumeContract {},
+    ClaimVault {
+        vault_id: Uint128,
+    },
+    RetryPostExecutionAction {
         vault_id: Uint128,
     },
+    IbcHook {
+        channel_id: String,
+        original_sender: String,
+        msg: Box<ExecuteMsg>,
+    },
 }
 
 #[cw_serde]
@@ -128,6 +205,11 @@ pub enum QueryMsg {
     },
     #[returns(TriggerIdsResponse)]
     GetTimeTriggerIds { limit: Option<u16> },
+    #[returns(PriceTriggerIdsResponse)]
+    GetPriceTriggerIds {
+        start_after: Option<u128>,
+        limit: Option<u16>,
+    },
     #[returns(VaultResponse)]
     GetVault { vault_id: Uint128 },
     #[returns(VaultsResponse)]
@@ -161,6 +243,15 @@ pub enum QueryMsg {
     GetVaultPerformance { vault_id: Uint128 },
     #[returns(DisburseEscrowTasksResponse)]
     GetDisburseEscrowTasks { limit: Option<u16> },
+    #[returns(SimulateExecutionResponse)]
+    SimulateExecution { vault_id: Uint128 },
+    #[returns(VaultScheduleResponse)]
+    GetVaultSchedule {
+        vault_id: Uint128,
+        limit: Option<u16>,
+    },
+    #[returns(FailedPostExecutionActionsResponse)]
+    GetFailedPostExecutionActions { vault_id: Uint128 },
 }
 
 #[cw_serde]
@@ -183,6 +274,12 @@ pub struct TriggerIdsResponse {
     pub trigger_ids: Vec<Uint128>,
 }
 
+#[cw_serde]
+pub struct PriceTriggerIdsResponse {
+    pub trigger_ids: Vec<Uint128>,
+    pub last_checked_trigger_id: Option<Uint128>,
+}
+
 #[cw_serde]
 pub struct VaultResponse {
     pub vault: Vault,
@@ -213,3 +310,24 @@ pub struct CustomFeesResponse {
 pub struct DisburseEscrowTasksResponse {
     pub vault_ids: Vec<Uint128>,
 }
+
+#[cw_serde]
+pub struct SimulateExecutionResponse {
+    pub sent: Coin,
+    pub received: Coin,
+    pub fee: Coin,
+    pub skipped_reason: Option<ExecutionSkippedReason>,
+    pub termination_reason: Option<TerminationReason>,
+}
+
+#[cw_serde]
+pub struct VaultScheduleResponse {
+    pub next_execution_times: Vec<Timestamp>,
+    pub expected_completion_date: Option<Timestamp>,
+    pub remaining_swaps: Uint128,
+}
+
+#[cw_serde]
+pub struct FailedPostExecutionActionsResponse {
+    pub actions: Vec<FailedPostExecutionAction>,
+}
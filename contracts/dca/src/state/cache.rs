@@ -1,6 +1,8 @@
 use std::collections::VecDeque;
 
+use crate::types::destination::Destination;
 use crate::types::lockable_duration::LockableDuration;
+use crate::types::post_execution_action::FailedPostExecutionAction;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin, SubMsg, Uint128};
 use cw_storage_plus::{Item, Map};
@@ -9,21 +11,37 @@ pub const VAULT_CACHE: Item<Uint128> = Item::new("vault_cache_v8");
 
 #[cw_serde]
 pub struct SwapCache {
+    pub vault_id: Uint128,
     pub swap_denom_balance: Coin,
     pub receive_denom_balance: Coin,
+    pub keeper: Option<Addr>,
 }
 
 pub const SWAP_CACHE: Item<SwapCache> = Item::new("swap_cache_v8");
 
+pub const EXECUTE_TRIGGERS_CACHE: Item<VecDeque<Uint128>> = Item::new("execute_triggers_cache_v8");
+
+pub const KEEPER_CACHE: Item<Addr> = Item::new("keeper_cache_v8");
+
 #[cw_serde]
 pub struct PostExecutionActionCacheEntry {
     pub msg: SubMsg,
     pub funds: Vec<Coin>,
+    pub destination: Destination,
 }
 
 pub const POST_EXECUTION_ACTION_CACHE: Map<u128, VecDeque<PostExecutionActionCacheEntry>> =
     Map::new("post_execution_action_cache_v8");
 
+#[cw_serde]
+pub struct RetryPostExecutionActionCache {
+    pub vault_id: Uint128,
+    pub action: FailedPostExecutionAction,
+}
+
+pub const RETRY_POST_EXECUTION_ACTION_CACHE: Item<RetryPostExecutionActionCache> =
+    Item::new("retry_post_execution_action_cache_v8");
+
 #[cw_serde]
 pub struct ProvideLiquidityCache {
     pub provider_address: Addr,
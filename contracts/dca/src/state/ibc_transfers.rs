@@ -0,0 +1,33 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, StdResult, Storage, SubMsg, Uint128};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+pub struct IbcTransfer {
+    pub vault_id: Uint128,
+    pub msg: SubMsg,
+    pub funds: Vec<Coin>,
+}
+
+const IBC_TRANSFERS: Map<(&str, u64), IbcTransfer> = Map::new("ibc_transfers_v8");
+
+pub fn save_ibc_transfer(
+    storage: &mut dyn Storage,
+    channel_id: &str,
+    sequence: u64,
+    transfer: &IbcTransfer,
+) -> StdResult<()> {
+    IBC_TRANSFERS.save(storage, (channel_id, sequence), transfer)
+}
+
+pub fn get_ibc_transfer(
+    storage: &dyn Storage,
+    channel_id: &str,
+    sequence: u64,
+) -> StdResult<Option<IbcTransfer>> {
+    IBC_TRANSFERS.may_load(storage, (channel_id, sequence))
+}
+
+pub fn remove_ibc_transfer(storage: &mut dyn Storage, channel_id: &str, sequence: u64) {
+    IBC_TRANSFERS.remove(storage, (channel_id, sequence))
+}
@@ -0,0 +1,23 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Item;
+
+#[cw_serde]
+pub enum MigrationCursor {
+    Vaults { start_after: Option<Uint128> },
+    Triggers { start_after: Option<Uint128> },
+}
+
+const MIGRATION_CURSOR: Item<MigrationCursor> = Item::new("migration_cursor_v8");
+
+pub fn get_migration_cursor(store: &dyn Storage) -> StdResult<Option<MigrationCursor>> {
+    MIGRATION_CURSOR.may_load(store)
+}
+
+pub fn save_migration_cursor(store: &mut dyn Storage, cursor: &MigrationCursor) -> StdResult<()> {
+    MIGRATION_CURSOR.save(store, cursor)
+}
+
+pub fn clear_migration_cursor(store: &mut dyn Storage) {
+    MIGRATION_CURSOR.remove(store)
+}
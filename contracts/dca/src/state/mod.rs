@@ -2,7 +2,10 @@ pub mod cache;
 pub mod config;
 pub mod disburse_escrow_tasks;
 pub mod events;
+pub mod ibc_transfers;
+pub mod migrations;
 pub mod pairs;
+pub mod post_execution_actions;
 pub mod swap_adjustments;
 pub mod triggers;
 pub mod vaults;
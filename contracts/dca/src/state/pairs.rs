@@ -19,6 +19,10 @@ pub fn find_pair(storage: &dyn Storage, denoms: [String; 2]) -> StdResult<Pair>
     PAIRS.load(storage, key_from(denoms))
 }
 
+pub fn delete_pair(storage: &mut dyn Storage, denoms: [String; 2]) {
+    PAIRS.remove(storage, key_from(denoms))
+}
+
 pub fn get_pairs(
     storage: &dyn Storage,
     start_after: Option<Pair>,
@@ -65,6 +69,31 @@ mod pairs_state_tests {
         assert_eq!(pair, saved_pair);
     }
 
+    #[test]
+    fn deletes_pair() {
+        let mut deps = mock_dependencies();
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+        delete_pair(deps.as_mut().storage, pair.denoms());
+
+        assert!(find_pair(&deps.storage, pair.denoms()).is_err());
+    }
+
+    #[test]
+    fn deletes_pair_with_denoms_reversed() {
+        let mut deps = mock_dependencies();
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+        delete_pair(
+            deps.as_mut().storage,
+            [pair.denoms()[1].clone(), pair.denoms()[0].clone()],
+        );
+
+        assert!(find_pair(&deps.storage, pair.denoms()).is_err());
+    }
+
     #[test]
     fn find_pair_that_does_not_exist_fails() {
         let deps = mock_dependencies();
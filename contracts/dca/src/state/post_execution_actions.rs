@@ -0,0 +1,65 @@
+use crate::types::post_execution_action::FailedPostExecutionAction;
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use std::collections::VecDeque;
+
+const FAILED_POST_EXECUTION_ACTIONS: Map<u128, VecDeque<FailedPostExecutionAction>> =
+    Map::new("failed_post_execution_actions_v8");
+
+fn get_queue(
+    store: &dyn Storage,
+    vault_id: Uint128,
+) -> StdResult<VecDeque<FailedPostExecutionAction>> {
+    Ok(FAILED_POST_EXECUTION_ACTIONS
+        .may_load(store, vault_id.into())?
+        .unwrap_or_default())
+}
+
+fn save_queue(
+    store: &mut dyn Storage,
+    vault_id: Uint128,
+    queue: &VecDeque<FailedPostExecutionAction>,
+) -> StdResult<()> {
+    if queue.is_empty() {
+        FAILED_POST_EXECUTION_ACTIONS.remove(store, vault_id.into());
+        return Ok(());
+    }
+    FAILED_POST_EXECUTION_ACTIONS.save(store, vault_id.into(), queue)
+}
+
+pub fn add_failed_post_execution_action(
+    store: &mut dyn Storage,
+    vault_id: Uint128,
+    action: FailedPostExecutionAction,
+) -> StdResult<()> {
+    let mut queue = get_queue(store, vault_id)?;
+    queue.push_back(action);
+    save_queue(store, vault_id, &queue)
+}
+
+pub fn requeue_failed_post_execution_action(
+    store: &mut dyn Storage,
+    vault_id: Uint128,
+    action: FailedPostExecutionAction,
+) -> StdResult<()> {
+    let mut queue = get_queue(store, vault_id)?;
+    queue.push_front(action);
+    save_queue(store, vault_id, &queue)
+}
+
+pub fn pop_failed_post_execution_action(
+    store: &mut dyn Storage,
+    vault_id: Uint128,
+) -> StdResult<Option<FailedPostExecutionAction>> {
+    let mut queue = get_queue(store, vault_id)?;
+    let action = queue.pop_front();
+    save_queue(store, vault_id, &queue)?;
+    Ok(action)
+}
+
+pub fn get_failed_post_execution_actions(
+    store: &dyn Storage,
+    vault_id: Uint128,
+) -> StdResult<Vec<FailedPostExecutionAction>> {
+    Ok(get_queue(store, vault_id)?.into_iter().collect())
+}
@@ -1,6 +1,6 @@
 use crate::types::trigger::{Trigger, TriggerConfiguration};
 use cosmwasm_std::{Order, StdResult, Storage, Timestamp, Uint128};
-use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Map, MultiIndex};
 use std::marker::PhantomData;
 
 use super::config::get_config;
@@ -21,6 +21,8 @@ fn trigger_store<'a>() -> IndexedMap<'a, u128, Trigger, TriggerIndexes<'a>> {
         due_date: MultiIndex::new(
             |_, trigger| match trigger.configuration {
                 TriggerConfiguration::Time { target_time } => target_time.seconds(),
+                // price triggers are saved in PRICE_TRIGGERS, so this key is never indexed
+                TriggerConfiguration::Price { .. } => u64::MAX,
             },
             "triggers_v8",
             "triggers_v8__due_date",
@@ -29,15 +31,30 @@ fn trigger_store<'a>() -> IndexedMap<'a, u128, Trigger, TriggerIndexes<'a>> {
     IndexedMap::new("triggers_v8", indexes)
 }
 
+const PRICE_TRIGGERS: Map<u128, Trigger> = Map::new("price_triggers_v8");
+
 pub fn save_trigger(store: &mut dyn Storage, trigger: Trigger) -> StdResult<()> {
-    trigger_store().save(store, trigger.vault_id.into(), &trigger)
+    delete_trigger(store, trigger.vault_id)?;
+
+    match trigger.configuration {
+        TriggerConfiguration::Time { .. } => {
+            trigger_store().save(store, trigger.vault_id.into(), &trigger)
+        }
+        TriggerConfiguration::Price { .. } => {
+            PRICE_TRIGGERS.save(store, trigger.vault_id.into(), &trigger)
+        }
+    }
 }
 
 pub fn get_trigger(store: &dyn Storage, vault_id: Uint128) -> StdResult<Option<Trigger>> {
-    trigger_store().may_load(store, vault_id.into())
+    match trigger_store().may_load(store, vault_id.into())? {
+        Some(trigger) => Ok(Some(trigger)),
+        None => PRICE_TRIGGERS.may_load(store, vault_id.into()),
+    }
 }
 
 pub fn delete_trigger(store: &mut dyn Storage, vault_id: Uint128) -> StdResult<()> {
+    PRICE_TRIGGERS.remove(store, vault_id.into());
     trigger_store().remove(store, vault_id.into())
 }
 
@@ -63,12 +80,69 @@ pub fn get_time_triggers(
         .collect::<Vec<Uint128>>())
 }
 
+pub fn get_price_triggers(
+    store: &dyn Storage,
+    start_after: Option<Uint128>,
+    limit: Option<u16>,
+) -> StdResult<Vec<Trigger>> {
+    Ok(PRICE_TRIGGERS
+        .range(
+            store,
+            start_after.map(|vault_id| Bound::exclusive(u128::from(vault_id))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.unwrap_or_else(|| get_config(store).unwrap().default_page_limit) as usize)
+        .flat_map(|result| result.map(|(_, trigger)| trigger))
+        .collect::<Vec<Trigger>>())
+}
+
+pub fn migrate_triggers<F>(
+    store: &mut dyn Storage,
+    start_after: Option<Uint128>,
+    limit: u16,
+    mut migrate: F,
+) -> StdResult<Vec<Uint128>>
+where
+    F: FnMut(&dyn Storage, Trigger) -> StdResult<Option<Trigger>>,
+{
+    let min = start_after.map(|vault_id| Bound::exclusive(u128::from(vault_id)));
+
+    let mut vault_ids = trigger_store()
+        .keys(store, min.clone(), None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<u128>>>()?;
+
+    vault_ids.extend(
+        PRICE_TRIGGERS
+            .keys(store, min, None, Order::Ascending)
+            .take(limit as usize)
+            .collect::<StdResult<Vec<u128>>>()?,
+    );
+
+    vault_ids.sort();
+    vault_ids.truncate(limit as usize);
+
+    for vault_id in vault_ids.iter() {
+        let Some(trigger) = get_trigger(store, (*vault_id).into())? else {
+            continue;
+        };
+
+        match migrate(store, trigger)? {
+            Some(trigger) => save_trigger(store, trigger)?,
+            None => delete_trigger(store, (*vault_id).into())?,
+        }
+    }
+
+    Ok(vault_ids.into_iter().map(Uint128::from).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::trigger::TriggerConfiguration;
+    use crate::types::trigger::{PriceDirection, TriggerConfiguration};
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
-    use cosmwasm_std::Uint128;
+    use cosmwasm_std::{Decimal, Uint128};
 
     #[test]
     fn fetches_trigger_ids_for_triggers_that_are_due() {
@@ -196,4 +270,112 @@ mod tests {
         );
         assert_eq!(trigger_ids_after_delete, vec![trigger_2.vault_id]);
     }
+
+    #[test]
+    fn saves_and_fetches_price_trigger_by_vault_id() {
+        let mut deps = mock_dependencies();
+
+        let trigger = Trigger {
+            vault_id: Uint128::from(1u128),
+            configuration: TriggerConfiguration::Price {
+                target_price: Decimal::percent(150),
+                direction: PriceDirection::Above,
+            },
+        };
+
+        save_trigger(&mut deps.storage, trigger.clone()).unwrap();
+
+        assert_eq!(
+            get_trigger(&deps.storage, trigger.vault_id).unwrap(),
+            Some(trigger.clone())
+        );
+        assert_eq!(
+            get_price_triggers(&deps.storage, None, Some(100)).unwrap(),
+            vec![trigger]
+        );
+    }
+
+    #[test]
+    fn does_not_fetch_price_triggers_as_time_triggers() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        save_trigger(
+            &mut deps.storage,
+            Trigger {
+                vault_id: Uint128::from(1u128),
+                configuration: TriggerConfiguration::Price {
+                    target_price: Decimal::percent(150),
+                    direction: PriceDirection::Above,
+                },
+            },
+        )
+        .unwrap();
+
+        let trigger_ids = get_time_triggers(&deps.storage, env.block.time, Some(100)).unwrap();
+
+        assert!(trigger_ids.is_empty());
+    }
+
+    #[test]
+    fn replaces_price_trigger_when_saving_time_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault_id = Uint128::from(1u128);
+
+        save_trigger(
+            &mut deps.storage,
+            Trigger {
+                vault_id,
+                configuration: TriggerConfiguration::Price {
+                    target_price: Decimal::percent(150),
+                    direction: PriceDirection::Above,
+                },
+            },
+        )
+        .unwrap();
+
+        save_trigger(
+            &mut deps.storage,
+            Trigger {
+                vault_id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: env.block.time,
+                },
+            },
+        )
+        .unwrap();
+
+        assert!(get_price_triggers(&deps.storage, None, Some(100))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            get_time_triggers(&deps.storage, env.block.time, Some(100)).unwrap(),
+            vec![vault_id]
+        );
+    }
+
+    #[test]
+    fn deletes_price_trigger_by_vault_id() {
+        let mut deps = mock_dependencies();
+
+        let vault_id = Uint128::from(1u128);
+
+        save_trigger(
+            &mut deps.storage,
+            Trigger {
+                vault_id,
+                configuration: TriggerConfiguration::Price {
+                    target_price: Decimal::percent(150),
+                    direction: PriceDirection::Below,
+                },
+            },
+        )
+        .unwrap();
+
+        delete_trigger(&mut deps.storage, vault_id).unwrap();
+
+        assert_eq!(get_trigger(&deps.storage, vault_id).unwrap(), None);
+    }
 }
@@ -2,15 +2,18 @@ use super::{config::get_config, triggers::get_trigger};
 use crate::{
     helpers::state::fetch_and_increment_counter,
     types::{
-        destination::Destination,
+        accumulation::Accumulation,
+        destination::{Destination, DestinationAction},
+        execution_window::ExecutionWindow,
         performance_assessment_strategy::PerformanceAssessmentStrategy,
         swap_adjustment_strategy::SwapAdjustmentStrategy,
+        termination_condition::TerminationCondition,
         time_interval::TimeInterval,
         vault::{Vault, VaultBuilder, VaultStatus},
     },
 };
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin, Decimal, StdResult, Storage, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, StdResult, Storage, Timestamp, Uint128};
 use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, UniqueIndex};
 
 const VAULT_COUNTER: Item<u64> = Item::new("vault_counter_v8");
@@ -18,11 +21,13 @@ const VAULT_COUNTER: Item<u64> = Item::new("vault_counter_v8");
 struct VaultIndexes<'a> {
     pub owner: UniqueIndex<'a, (Addr, u128), VaultData, u128>,
     pub owner_status: UniqueIndex<'a, (Addr, u8, u128), VaultData, u128>,
+    pub pair_status: UniqueIndex<'a, ((String, String), u8, u128), VaultData, u128>,
 }
 
 impl<'a> IndexList<VaultData> for VaultIndexes<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<VaultData>> + '_> {
-        let v: Vec<&dyn Index<VaultData>> = vec![&self.owner, &self.owner_status];
+        let v: Vec<&dyn Index<VaultData>> =
+            vec![&self.owner, &self.owner_status, &self.pair_status];
         Box::new(v.into_iter())
     }
 }
@@ -34,10 +39,26 @@ fn vault_store<'a>() -> IndexedMap<'a, u128, VaultData, VaultIndexes<'a>> {
             |v| (v.owner.clone(), v.status.clone() as u8, v.id.into()),
             "vaults_v8__owner_status",
         ),
+        pair_status: UniqueIndex::new(
+            |v| {
+                (
+                    pair_key([v.balance.denom.clone(), v.target_denom.clone()]),
+                    v.status.clone() as u8,
+                    v.id.into(),
+                )
+            },
+            "vaults_v8__pair_status",
+        ),
     };
     IndexedMap::new("vaults_v8", indexes)
 }
 
+fn pair_key(mut denoms: [String; 2]) -> (String, String) {
+    denoms.sort();
+    let [first_denom, second_denom] = denoms;
+    (first_denom, second_denom)
+}
+
 pub fn save_vault(store: &mut dyn Storage, vault_builder: VaultBuilder) -> StdResult<Vault> {
     let vault = vault_builder.build(fetch_and_increment_counter(store, VAULT_COUNTER)?.into());
     vault_store().save(store, vault.id.into(), &vault.clone().into())?;
@@ -77,6 +98,20 @@ pub fn get_vaults_by_address(
         .collect::<Vec<Vault>>())
 }
 
+pub fn get_vaults_by_pair(
+    store: &dyn Storage,
+    denoms: [String; 2],
+    status: VaultStatus,
+) -> StdResult<Vec<Vault>> {
+    vault_store()
+        .idx
+        .pair_status
+        .prefix((pair_key(denoms), status as u8))
+        .range(store, None, None, cosmwasm_std::Order::Ascending)
+        .map(|result| result.and_then(|(_, vault_data)| vault_from(store, &vault_data)))
+        .collect::<StdResult<Vec<Vault>>>()
+}
+
 pub fn get_vaults(
     store: &dyn Storage,
     start_after: Option<u128>,
@@ -100,13 +135,75 @@ pub fn update_vault(store: &mut dyn Storage, vault: Vault) -> StdResult<Vault> {
     Ok(vault)
 }
 
+pub fn migrate_vaults<F>(
+    store: &mut dyn Storage,
+    start_after: Option<Uint128>,
+    limit: u16,
+    mut migrate: F,
+) -> StdResult<Vec<Uint128>>
+where
+    F: FnMut(&dyn Storage, Vault) -> StdResult<Vault>,
+{
+    let vault_ids = vault_store()
+        .keys(
+            store,
+            start_after.map(|vault_id| Bound::exclusive(u128::from(vault_id))),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|result| result.map(Uint128::from))
+        .collect::<StdResult<Vec<Uint128>>>()?;
+
+    for vault_id in vault_ids.iter() {
+        let vault = migrate(store, get_vault(store, *vault_id)?)?;
+        update_vault(store, vault)?;
+    }
+
+    Ok(vault_ids)
+}
+
+#[cw_serde]
+struct DestinationData {
+    allocation: Decimal,
+    address: Addr,
+    action: Option<DestinationAction>,
+    msg: Option<Binary>,
+}
+
+impl From<Destination> for DestinationData {
+    fn from(destination: Destination) -> Self {
+        Self {
+            allocation: destination.allocation,
+            address: destination.address,
+            action: Some(destination.action),
+            msg: None,
+        }
+    }
+}
+
+impl From<DestinationData> for Destination {
+    fn from(data: DestinationData) -> Self {
+        Self {
+            allocation: data.allocation,
+            address: data.address,
+            // destinations saved before typed actions keep executing their raw callback
+            action: data.action.unwrap_or_else(|| {
+                data.msg.map_or(DestinationAction::Send {}, |msg| {
+                    DestinationAction::ExecuteContract { msg }
+                })
+            }),
+        }
+    }
+}
+
 #[cw_serde]
 struct VaultData {
     id: Uint128,
     created_at: Timestamp,
     owner: Addr,
     label: Option<String>,
-    destinations: Vec<Destination>,
+    destinations: Vec<DestinationData>,
     status: VaultStatus,
     balance: Coin,
     target_denom: String,
@@ -122,6 +219,18 @@ struct VaultData {
     escrowed_amount: Coin,
     performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
     swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+    stop_loss: Option<TerminationCondition>,
+    take_profit: Option<TerminationCondition>,
+    pending_owner: Option<Addr>,
+    execution_window: Option<ExecutionWindow>,
+    end_time: Option<Timestamp>,
+    max_executions: Option<u64>,
+    #[serde(default)]
+    execution_count: u64,
+    #[serde(default)]
+    retry_count: u32,
+    accumulation: Option<Accumulation>,
+    claimable_amount: Option<Coin>,
 }
 
 impl From<Vault> for VaultData {
@@ -134,7 +243,11 @@ impl From<Vault> for VaultData {
             status: vault.status,
             balance: vault.balance,
             target_denom: vault.target_denom,
-            destinations: vault.destinations,
+            destinations: vault
+                .destinations
+                .into_iter()
+                .map(DestinationData::from)
+                .collect(),
             swap_amount: vault.swap_amount,
             slippage_tolerance: vault.slippage_tolerance,
             minimum_receive_amount: vault.minimum_receive_amount,
@@ -147,6 +260,16 @@ impl From<Vault> for VaultData {
             escrowed_amount: vault.escrowed_amount,
             performance_assessment_strategy: vault.performance_assessment_strategy,
             swap_adjustment_strategy: vault.swap_adjustment_strategy,
+            stop_loss: vault.stop_loss,
+            take_profit: vault.take_profit,
+            pending_owner: vault.pending_owner,
+            execution_window: vault.execution_window,
+            end_time: vault.end_time,
+            max_executions: vault.max_executions,
+            execution_count: vault.execution_count,
+            retry_count: vault.retry_count,
+            accumulation: vault.accumulation,
+            claimable_amount: Some(vault.claimable_amount),
         }
     }
 }
@@ -163,7 +286,12 @@ fn vault_from(store: &dyn Storage, data: &VaultData) -> StdResult<Vault> {
         balance: data.balance.clone(),
         swap_amount: data.swap_amount,
         target_denom: data.target_denom.clone(),
-        destinations: data.destinations.clone(),
+        destinations: data
+            .destinations
+            .iter()
+            .cloned()
+            .map(Destination::from)
+            .collect(),
         slippage_tolerance: data.slippage_tolerance,
         minimum_receive_amount: data.minimum_receive_amount,
         time_interval: data.time_interval.clone(),
@@ -175,6 +303,77 @@ fn vault_from(store: &dyn Storage, data: &VaultData) -> StdResult<Vault> {
         escrowed_amount: data.escrowed_amount.clone(),
         performance_assessment_strategy: data.performance_assessment_strategy.clone(),
         swap_adjustment_strategy: data.swap_adjustment_strategy.clone(),
+        stop_loss: data.stop_loss.clone(),
+        take_profit: data.take_profit.clone(),
+        pending_owner: data.pending_owner.clone(),
+        execution_window: data.execution_window.clone(),
+        end_time: data.end_time,
+        max_executions: data.max_executions,
+        execution_count: data.execution_count,
+        retry_count: data.retry_count,
+        accumulation: data.accumulation.clone(),
+        claimable_amount: data
+            .claimable_amount
+            .clone()
+            .unwrap_or_else(|| Coin::new(0, data.target_denom.clone())),
         trigger,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::setup_vault;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::to_binary;
+
+    #[test]
+    fn loads_destinations_saved_with_raw_callbacks_as_actions() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(deps.as_mut(), mock_env(), Vault::default());
+
+        let mut vault_data = vault_store()
+            .load(deps.as_ref().storage, vault.id.into())
+            .unwrap();
+
+        vault_data.destinations = vec![
+            DestinationData {
+                allocation: Decimal::percent(50),
+                address: Addr::unchecked("owner"),
+                action: None,
+                msg: None,
+            },
+            DestinationData {
+                allocation: Decimal::percent(50),
+                address: Addr::unchecked("contract"),
+                action: None,
+                msg: Some(to_binary("callback").unwrap()),
+            },
+        ];
+
+        vault_store()
+            .save(deps.as_mut().storage, vault.id.into(), &vault_data)
+            .unwrap();
+
+        let vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            vault.destinations,
+            vec![
+                Destination {
+                    allocation: Decimal::percent(50),
+                    address: Addr::unchecked("owner"),
+                    action: DestinationAction::Send {},
+                },
+                Destination {
+                    allocation: Decimal::percent(50),
+                    address: Addr::unchecked("contract"),
+                    action: DestinationAction::ExecuteContract {
+                        msg: to_binary("callback").unwrap(),
+                    },
+                },
+            ]
+        );
+    }
+}
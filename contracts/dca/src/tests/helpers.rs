@@ -3,11 +3,11 @@ use crate::{
     constants::{ONE, TEN},
     contract::instantiate,
     handlers::get_vault::get_vault_handler,
-    msg::{ExecuteMsg, InstantiateMsg},
+    msg::InstantiateMsg,
     state::{cache::VAULT_CACHE, pairs::save_pair, triggers::save_trigger, vaults::update_vault},
     types::{
         config::Config,
-        destination::Destination,
+        destination::{Destination, DestinationAction},
         event::{EventBuilder, EventData},
         fee_collector::FeeCollector,
         pair::Pair,
@@ -21,9 +21,7 @@ use crate::{
         vault::{Vault, VaultStatus},
     },
 };
-use cosmwasm_std::{
-    to_binary, Addr, BlockInfo, Coin, Decimal, DepsMut, Env, MessageInfo, Timestamp, Uint128,
-};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal, DepsMut, Env, MessageInfo, Timestamp, Uint128};
 use std::{cmp::max, str::FromStr};
 
 pub fn instantiate_contract(deps: DepsMut, env: Env, info: MessageInfo) {
@@ -42,6 +40,8 @@ pub fn instantiate_contract(deps: DepsMut, env: Env, info: MessageInfo) {
         risk_weighted_average_escrow_level: Decimal::percent(5),
         twap_period: 30,
         default_slippage_tolerance: Decimal::percent(2),
+        retry_policy: None,
+        keeper_reward_percent: None,
     };
 
     instantiate(deps, env, info, instantiate_message).unwrap();
@@ -65,6 +65,8 @@ pub fn instantiate_contract_with_multiple_fee_collectors(
         risk_weighted_average_escrow_level: Decimal::from_str("0.0075").unwrap(),
         twap_period: 30,
         default_slippage_tolerance: Decimal::percent(2),
+        retry_policy: None,
+        keeper_reward_percent: None,
     };
 
     instantiate(deps, env, info, instantiate_message).unwrap();
@@ -87,6 +89,12 @@ impl Default for Config {
             risk_weighted_average_escrow_level: Decimal::from_str("0.0075").unwrap(),
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
+            retry_policy: None,
+            keeper_reward_percent: None,
+            pending_admin: None,
+            pair_managers: vec![],
+            fee_managers: vec![],
+            pausers: vec![],
         }
     }
 }
@@ -97,6 +105,10 @@ impl Default for Pair {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![3],
+            weighted_routes: None,
+            minimum_swap_amounts: None,
+            twap_period: None,
+            default_slippage_tolerance: None,
         }
     }
 }
@@ -106,7 +118,7 @@ impl Default for Destination {
         Self {
             allocation: Decimal::percent(100),
             address: Addr::unchecked(USER),
-            msg: None,
+            action: DestinationAction::Send {},
         }
     }
 }
@@ -120,14 +132,10 @@ impl Default for Vault {
             label: Some("vault".to_string()),
             destinations: vec![Destination {
                 allocation: Decimal::percent(100),
-                address: Addr::unchecked("contractaddress"),
-                msg: Some(
-                    to_binary(&ExecuteMsg::ZDelegate {
-                        delegator_address: Addr::unchecked(USER),
-                        validator_address: Addr::unchecked(VALIDATOR),
-                    })
-                    .unwrap(),
-                ),
+                address: Addr::unchecked(USER),
+                action: DestinationAction::Delegate {
+                    validator_address: Addr::unchecked(VALIDATOR),
+                },
             }],
             status: VaultStatus::Active,
             balance: Coin::new(TEN.into(), DENOM_UOSMO),
@@ -147,6 +155,16 @@ impl Default for Vault {
             }),
             swap_adjustment_strategy: None,
             performance_assessment_strategy: None,
+            stop_loss: None,
+            take_profit: None,
+            pending_owner: None,
+            execution_window: None,
+            end_time: None,
+            max_executions: None,
+            execution_count: 0,
+            retry_count: 0,
+            accumulation: None,
+            claimable_amount: Coin::new(0, DENOM_STAKE),
         }
     }
 }
@@ -222,16 +240,19 @@ pub fn setup_vault(deps: DepsMut, env: Env, mut vault: Vault) -> Vault {
 
     update_vault(deps.storage, vault.clone()).unwrap();
 
-    if let Some(TriggerConfiguration::Time { target_time }) = vault.trigger {
-        let trigger_time =
-            Timestamp::from_seconds(max(target_time.seconds(), env.block.time.seconds()));
-
+    if let Some(configuration) = vault.trigger.clone() {
         save_trigger(
             deps.storage,
             Trigger {
                 vault_id: vault.id,
-                configuration: TriggerConfiguration::Time {
-                    target_time: trigger_time,
+                configuration: match configuration {
+                    TriggerConfiguration::Time { target_time } => TriggerConfiguration::Time {
+                        target_time: Timestamp::from_seconds(max(
+                            target_time.seconds(),
+                            env.block.time.seconds(),
+                        )),
+                    },
+                    price_trigger => price_trigger,
                 },
             },
         )
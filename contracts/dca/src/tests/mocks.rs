@@ -1,25 +1,29 @@
 use crate::constants::{ONE, ONE_DECIMAL, SWAP_FEE_RATE, TEN};
+use crate::types::pool::{ConcentratedLiquidityPool, PoolRequest, PoolResponse, QueriedPool};
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
-    from_slice, to_binary, Binary, ContractResult, CustomQuery, Empty, OwnedDeps, Querier,
-    QuerierResult, QueryRequest, StdError, StdResult, SystemError, SystemResult, WasmQuery,
+    from_slice, to_binary, Binary, ContractResult, CustomQuery, Decimal, Empty, OwnedDeps, Querier,
+    QuerierResult, QueryRequest, StdError, StdResult, SystemError, SystemResult, Uint64, WasmQuery,
 };
-use osmosis_std::shim::Any;
 use osmosis_std::types::cosmos::base::v1beta1::Coin;
+use osmosis_std::types::osmosis::gamm::poolmodels::stableswap::v1beta1::{
+    Pool as StableswapPool, PoolParams as StableswapPoolParams,
+};
 use osmosis_std::types::osmosis::gamm::v1beta1::{
-    Pool, PoolAsset, PoolParams, QueryCalcJoinPoolSharesResponse, QueryPoolRequest,
-    QueryPoolResponse,
+    Pool, PoolAsset, PoolParams, QueryCalcJoinPoolSharesResponse,
 };
 use osmosis_std::types::osmosis::poolmanager::v1beta1::EstimateSwapExactAmountInResponse;
 use osmosis_std::types::osmosis::twap::v1beta1::ArithmeticTwapResponse;
 use prost::Message;
 use serde::de::DeserializeOwned;
 use std::marker::PhantomData;
+use std::str::FromStr;
 
 pub const USER: &str = "user";
 pub const ADMIN: &str = "admin";
 pub const FEE_COLLECTOR: &str = "fee_collector";
 pub const VALIDATOR: &str = "validator";
+pub const KEEPER: &str = "keeper";
 
 pub const DENOM_UOSMO: &str = "uosmo";
 pub const DENOM_STAKE: &str = "stake";
@@ -55,8 +59,8 @@ impl<C: DeserializeOwned> CalcMockQuerier<C> {
                         tokens_out: vec![],
                     })
                 }
-                "/osmosis.gamm.v1beta1.Query/Pool" => {
-                    let pools = vec![
+                "/osmosis.poolmanager.v1beta1.Query/Pool" => {
+                    let balancer_pools = [
                         Pool {
                             id: 0,
                             pool_assets: vec![
@@ -178,16 +182,73 @@ impl<C: DeserializeOwned> CalcMockQuerier<C> {
                             }),
                             ..Pool::default()
                         },
+                        Pool {
+                            id: 6,
+                            pool_assets: vec![
+                                PoolAsset {
+                                    token: Some(Coin {
+                                        denom: DENOM_UATOM.to_string(),
+                                        amount: TEN.to_string(),
+                                    }),
+                                    weight: TEN.to_string(),
+                                },
+                                PoolAsset {
+                                    token: Some(Coin {
+                                        denom: DENOM_UION.to_string(),
+                                        amount: TEN.to_string(),
+                                    }),
+                                    weight: TEN.to_string(),
+                                },
+                                PoolAsset {
+                                    token: Some(Coin {
+                                        denom: DENOM_USDC.to_string(),
+                                        amount: TEN.to_string(),
+                                    }),
+                                    weight: TEN.to_string(),
+                                },
+                            ],
+                            pool_params: Some(PoolParams {
+                                swap_fee: SWAP_FEE_RATE.to_string(),
+                                ..PoolParams::default()
+                            }),
+                            ..Pool::default()
+                        },
                     ];
 
-                    let pool_id = QueryPoolRequest::decode(data.as_slice()).unwrap().pool_id;
+                    let pool_id = PoolRequest::decode(data.as_slice()).unwrap().pool_id;
 
-                    to_binary(&QueryPoolResponse {
-                        pool: Some(Any {
-                            type_url: Pool::TYPE_URL.to_string(),
-                            value: pools[pool_id as usize].clone().encode_to_vec(),
+                    let pool = match pool_id {
+                        5 => QueriedPool::Stableswap(StableswapPool {
+                            id: 5,
+                            pool_liquidity: [DENOM_UOSMO, DENOM_STAKE, DENOM_UATOM]
+                                .iter()
+                                .map(|denom| Coin {
+                                    denom: denom.to_string(),
+                                    amount: TEN.to_string(),
+                                })
+                                .collect(),
+                            pool_params: Some(StableswapPoolParams {
+                                swap_fee: SWAP_FEE_RATE.to_string(),
+                                ..StableswapPoolParams::default()
+                            }),
+                            ..StableswapPool::default()
                         }),
-                    })
+                        7 => QueriedPool::ConcentratedLiquidity(ConcentratedLiquidityPool {
+                            id: Uint64::new(7),
+                            token0: DENOM_STAKE.to_string(),
+                            token1: DENOM_UOSMO.to_string(),
+                            spread_factor: Decimal::from_str(SWAP_FEE_RATE).unwrap(),
+                        }),
+                        _ => QueriedPool::Balancer(
+                            balancer_pools
+                                .iter()
+                                .find(|pool| pool.id == pool_id)
+                                .unwrap()
+                                .clone(),
+                        ),
+                    };
+
+                    to_binary(&PoolResponse { pool })
                 }
                 _ => panic!("Unexpected path: {}", path),
             }),
@@ -0,0 +1,7 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+#[cw_serde]
+pub struct Accumulation {
+    pub claim_threshold: Option<Uint128>,
+}
@@ -1,4 +1,4 @@
-use super::fee_collector::FeeCollector;
+use super::{fee_collector::FeeCollector, retry_policy::RetryPolicy, role::Role};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Decimal};
 
@@ -15,4 +15,24 @@ pub struct Config {
     pub risk_weighted_average_escrow_level: Decimal,
     pub twap_period: u64,
     pub default_slippage_tolerance: Decimal,
+    pub retry_policy: Option<RetryPolicy>,
+    pub keeper_reward_percent: Option<Decimal>,
+    pub pending_admin: Option<Addr>,
+    #[serde(default)]
+    pub pair_managers: Vec<Addr>,
+    #[serde(default)]
+    pub fee_managers: Vec<Addr>,
+    #[serde(default)]
+    pub pausers: Vec<Addr>,
+}
+
+impl Config {
+    pub fn has_role(&self, address: &Addr, role: Role) -> bool {
+        *address == self.admin
+            || match role {
+                Role::PairManager => self.pair_managers.contains(address),
+                Role::FeeManager => self.fee_managers.contains(address),
+                Role::Pauser => self.pausers.contains(address),
+            }
+    }
 }
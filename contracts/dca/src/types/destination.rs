@@ -1,9 +1,40 @@
+use super::lockable_duration::LockableDuration;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Decimal};
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
+
+#[cw_serde]
+pub enum DestinationAction {
+    Send {},
+    ExecuteContract {
+        msg: Binary,
+    },
+    Delegate {
+        validator_address: Addr,
+    },
+    ProvideLiquidity {
+        pool_id: u64,
+        duration: LockableDuration,
+        slippage_tolerance: Option<Decimal>,
+    },
+    DepositToVault {
+        vault_id: Uint128,
+    },
+    IbcTransfer {
+        channel_id: String,
+        receiver: String,
+        memo: Option<String>,
+    },
+}
 
 #[cw_serde]
 pub struct Destination {
     pub allocation: Decimal,
     pub address: Addr,
-    pub msg: Option<Binary>,
+    pub action: DestinationAction,
+}
+
+impl Destination {
+    pub fn is_automated(&self) -> bool {
+        self.action != DestinationAction::Send {}
+    }
 }
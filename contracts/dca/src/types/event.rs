@@ -1,11 +1,13 @@
+use super::termination_condition::TerminationReason;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{BlockInfo, Coin, Decimal, SubMsg, Timestamp, Uint128};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal, SubMsg, Timestamp, Uint128};
 
 #[cw_serde]
 pub enum ExecutionSkippedReason {
     SlippageToleranceExceeded,
     PriceThresholdExceeded { price: Decimal },
     SwapAmountAdjustedToZero,
+    OutsideExecutionWindow,
 }
 
 #[cw_serde]
@@ -13,6 +15,12 @@ pub enum EventData {
     DcaVaultFundsDeposited {
         amount: Coin,
     },
+    DcaVaultFundsWithdrawn {
+        amount: Coin,
+    },
+    DcaVaultFundsClaimed {
+        amount: Coin,
+    },
     DcaVaultExecutionTriggered {
         base_denom: String,
         quote_denom: String,
@@ -31,10 +39,23 @@ pub enum EventData {
     DcaVaultExecutionSkipped {
         reason: ExecutionSkippedReason,
     },
+    DcaVaultExecutionRetryScheduled {
+        retry_count: u32,
+        target_time: Timestamp,
+    },
     SimulatedDcaVaultExecutionSkipped {
         reason: ExecutionSkippedReason,
     },
     DcaVaultCancelled {},
+    DcaVaultPaused {},
+    DcaVaultResumed {},
+    DcaVaultOwnershipTransferred {
+        previous_owner: Addr,
+        new_owner: Addr,
+    },
+    DcaVaultTerminated {
+        reason: TerminationReason,
+    },
     DcaVaultEscrowDisbursed {
         amount_disbursed: Coin,
         performance_fee: Coin,
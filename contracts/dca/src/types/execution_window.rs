@@ -0,0 +1,23 @@
+use super::time_interval::DayOfWeek;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct ExecutionWindow {
+    pub start_hour: u8,
+    pub end_hour: u8,
+    pub excluded_days: Vec<DayOfWeek>,
+}
+
+impl ExecutionWindow {
+    pub fn includes_hour(&self, hour: u8) -> bool {
+        if self.start_hour < self.end_hour {
+            self.start_hour <= hour && hour < self.end_hour
+        } else {
+            self.start_hour <= hour || hour < self.end_hour
+        }
+    }
+
+    pub fn excludes_day(&self, day: &DayOfWeek) -> bool {
+        self.excluded_days.contains(day)
+    }
+}
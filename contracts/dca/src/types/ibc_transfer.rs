@@ -0,0 +1,69 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, CosmosMsg, StdError, SubMsgResult};
+use osmosis_std::types::cosmos::base::v1beta1::Coin;
+use prost::Message;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgTransfer {
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    #[prost(string, tag = "2")]
+    pub source_channel: String,
+    #[prost(message, optional, tag = "3")]
+    pub token: Option<Coin>,
+    #[prost(string, tag = "4")]
+    pub sender: String,
+    #[prost(string, tag = "5")]
+    pub receiver: String,
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    pub memo: String,
+}
+
+impl MsgTransfer {
+    pub const TYPE_URL: &'static str = "/ibc.applications.transfer.v1.MsgTransfer";
+}
+
+impl<T> From<MsgTransfer> for CosmosMsg<T> {
+    fn from(msg: MsgTransfer) -> Self {
+        CosmosMsg::Stargate {
+            type_url: MsgTransfer::TYPE_URL.to_string(),
+            value: Binary(msg.encode_to_vec()),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgTransferResponse {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+}
+
+impl TryFrom<SubMsgResult> for MsgTransferResponse {
+    type Error = StdError;
+
+    fn try_from(result: SubMsgResult) -> Result<Self, Self::Error> {
+        let data = result
+            .into_result()
+            .map_err(StdError::generic_err)?
+            .data
+            .ok_or_else(|| StdError::generic_err("no data in ibc transfer response"))?;
+
+        MsgTransferResponse::decode(data.as_slice())
+            .map_err(|e| StdError::parse_err("MsgTransferResponse", e))
+    }
+}
+
+#[cw_serde]
+pub enum IbcLifecycleComplete {
+    #[serde(rename = "ibc_ack")]
+    IbcAck {
+        channel: String,
+        sequence: u64,
+        ack: String,
+        success: bool,
+    },
+    #[serde(rename = "ibc_timeout")]
+    IbcTimeout { channel: String, sequence: u64 },
+}
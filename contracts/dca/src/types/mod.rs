@@ -1,12 +1,22 @@
+pub mod accumulation;
 pub mod config;
 pub mod destination;
 pub mod event;
+pub mod execution_window;
 pub mod fee_collector;
+pub mod ibc_transfer;
 pub mod lockable_duration;
 pub mod pair;
 pub mod performance_assessment_strategy;
+pub mod pool;
 pub mod position_type;
+pub mod post_execution_action;
+pub mod retry_policy;
+pub mod role;
+pub mod split_route_swap;
 pub mod swap_adjustment_strategy;
+pub mod termination_condition;
 pub mod time_interval;
 pub mod trigger;
 pub mod vault;
+pub mod vault_settings;
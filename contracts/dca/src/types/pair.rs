@@ -1,11 +1,22 @@
 use super::position_type::PositionType;
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Decimal, Uint128};
+
+#[cw_serde]
+pub struct WeightedRoute {
+    pub route: Vec<u64>,
+    pub weight: Decimal,
+}
 
 #[cw_serde]
 pub struct Pair {
     pub base_denom: String,
     pub quote_denom: String,
     pub route: Vec<u64>,
+    pub weighted_routes: Option<Vec<WeightedRoute>>,
+    pub minimum_swap_amounts: Option<Vec<Coin>>,
+    pub twap_period: Option<u64>,
+    pub default_slippage_tolerance: Option<Decimal>,
 }
 
 impl Pair {
@@ -28,4 +39,43 @@ impl Pair {
             self.quote_denom.clone()
         }
     }
+
+    pub fn minimum_swap_amount(&self, swap_denom: &str) -> Option<Uint128> {
+        self.minimum_swap_amounts
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|minimum_swap_amount| minimum_swap_amount.denom == swap_denom)
+            .map(|minimum_swap_amount| minimum_swap_amount.amount)
+    }
+
+    pub fn routes(&self) -> Vec<WeightedRoute> {
+        self.weighted_routes.clone().unwrap_or_else(|| {
+            vec![WeightedRoute {
+                route: self.route.clone(),
+                weight: Decimal::one(),
+            }]
+        })
+    }
+
+    pub fn primary_route(&self) -> Vec<u64> {
+        self.routes()
+            .into_iter()
+            .reduce(|primary_route, weighted_route| {
+                if weighted_route.weight > primary_route.weight {
+                    weighted_route
+                } else {
+                    primary_route
+                }
+            })
+            .map_or_else(|| self.route.clone(), |weighted_route| weighted_route.route)
+    }
+
+    pub fn with_route(&self, route: Vec<u64>) -> Pair {
+        Pair {
+            route,
+            weighted_routes: None,
+            ..self.clone()
+        }
+    }
 }
@@ -0,0 +1,79 @@
+use cosmwasm_std::{Decimal, StdError, StdResult, Uint64};
+use osmosis_std::types::osmosis::gamm::{
+    poolmodels::stableswap::v1beta1::Pool as StableswapPool, v1beta1::Pool as BalancerPool,
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pool {
+    pub id: u64,
+    pub denoms: Vec<String>,
+    pub swap_fee: Decimal,
+}
+
+impl TryFrom<QueriedPool> for Pool {
+    type Error = StdError;
+
+    fn try_from(pool: QueriedPool) -> StdResult<Self> {
+        match pool {
+            QueriedPool::Balancer(pool) => Ok(Pool {
+                id: pool.id,
+                denoms: pool
+                    .pool_assets
+                    .into_iter()
+                    .flat_map(|asset| asset.token.map(|token| token.denom))
+                    .collect(),
+                swap_fee: pool.pool_params.unwrap_or_default().swap_fee.parse()?,
+            }),
+            QueriedPool::Stableswap(pool) => Ok(Pool {
+                id: pool.id,
+                denoms: pool
+                    .pool_liquidity
+                    .into_iter()
+                    .map(|coin| coin.denom)
+                    .collect(),
+                swap_fee: pool.pool_params.unwrap_or_default().swap_fee.parse()?,
+            }),
+            QueriedPool::ConcentratedLiquidity(pool) => Ok(Pool {
+                id: pool.id.u64(),
+                denoms: vec![pool.token0, pool.token1],
+                swap_fee: pool.spread_factor,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@type")]
+pub enum QueriedPool {
+    #[serde(rename = "/osmosis.gamm.v1beta1.Pool")]
+    Balancer(BalancerPool),
+    #[serde(rename = "/osmosis.gamm.poolmodels.stableswap.v1beta1.Pool")]
+    Stableswap(StableswapPool),
+    #[serde(rename = "/osmosis.concentratedliquidity.v1beta1.Pool")]
+    ConcentratedLiquidity(ConcentratedLiquidityPool),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConcentratedLiquidityPool {
+    pub id: Uint64,
+    pub token0: String,
+    pub token1: String,
+    pub spread_factor: Decimal,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PoolRequest {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+}
+
+impl PoolRequest {
+    pub const PATH: &'static str = "/osmosis.poolmanager.v1beta1.Query/Pool";
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PoolResponse {
+    pub pool: QueriedPool,
+}
@@ -0,0 +1,11 @@
+use super::destination::DestinationAction;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin};
+
+#[cw_serde]
+pub struct FailedPostExecutionAction {
+    pub address: Addr,
+    pub action: DestinationAction,
+    pub amount: Coin,
+    pub attempts: u8,
+}
@@ -0,0 +1,7 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub backoff_seconds: u64,
+}
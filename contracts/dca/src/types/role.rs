@@ -0,0 +1,8 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum Role {
+    PairManager,
+    FeeManager,
+    Pauser,
+}
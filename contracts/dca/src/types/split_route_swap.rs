@@ -0,0 +1,37 @@
+use cosmwasm_std::{Binary, CosmosMsg};
+use osmosis_std::types::osmosis::poolmanager::v1beta1::SwapAmountInRoute;
+use prost::Message;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct SwapAmountInSplitRoute {
+    #[prost(message, repeated, tag = "1")]
+    pub pools: Vec<SwapAmountInRoute>,
+    #[prost(string, tag = "2")]
+    pub token_in_amount: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgSplitRouteSwapExactAmountIn {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, repeated, tag = "2")]
+    pub routes: Vec<SwapAmountInSplitRoute>,
+    #[prost(string, tag = "3")]
+    pub token_in_denom: String,
+    #[prost(string, tag = "4")]
+    pub token_out_min_amount: String,
+}
+
+impl MsgSplitRouteSwapExactAmountIn {
+    pub const TYPE_URL: &'static str =
+        "/osmosis.poolmanager.v1beta1.MsgSplitRouteSwapExactAmountIn";
+}
+
+impl<T> From<MsgSplitRouteSwapExactAmountIn> for CosmosMsg<T> {
+    fn from(msg: MsgSplitRouteSwapExactAmountIn) -> Self {
+        CosmosMsg::Stargate {
+            type_url: MsgSplitRouteSwapExactAmountIn::TYPE_URL.to_string(),
+            value: Binary(msg.encode_to_vec()),
+        }
+    }
+}
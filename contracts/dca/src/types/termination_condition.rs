@@ -0,0 +1,22 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Timestamp};
+
+#[cw_serde]
+pub enum TerminationAction {
+    Refund,
+    Swap,
+}
+
+#[cw_serde]
+pub struct TerminationCondition {
+    pub price: Decimal,
+    pub action: TerminationAction,
+}
+
+#[cw_serde]
+pub enum TerminationReason {
+    StopLoss { price: Decimal },
+    TakeProfit { price: Decimal },
+    EndTimeReached { end_time: Timestamp },
+    MaxExecutionsReached { executions: u64 },
+}
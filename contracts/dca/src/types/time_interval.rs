@@ -1,5 +1,28 @@
 use cosmwasm_schema::cw_serde;
 
+#[cw_serde]
+pub enum DayOfWeek {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl DayOfWeek {
+    pub const ALL: [DayOfWeek; 7] = [
+        DayOfWeek::Monday,
+        DayOfWeek::Tuesday,
+        DayOfWeek::Wednesday,
+        DayOfWeek::Thursday,
+        DayOfWeek::Friday,
+        DayOfWeek::Saturday,
+        DayOfWeek::Sunday,
+    ];
+}
+
 #[cw_serde]
 pub enum TimeInterval {
     EveryBlock,
@@ -11,7 +34,19 @@ pub enum TimeInterval {
     Weekly,
     Fortnightly,
     Monthly,
-    Custom { seconds: u64 },
+    Custom {
+        seconds: u64,
+    },
+    DaysOfWeek {
+        days: Vec<DayOfWeek>,
+        hour: u8,
+        minute: u8,
+    },
+    DaysOfMonth {
+        days: Vec<u8>,
+        hour: u8,
+        minute: u8,
+    },
 }
 
 impl From<TimeInterval> for String {
@@ -27,6 +62,24 @@ impl From<TimeInterval> for String {
             TimeInterval::Fortnightly => "Fortnightly".to_string(),
             TimeInterval::Monthly => "Monthly".to_string(),
             TimeInterval::Custom { seconds } => format!("Custom:{}", seconds),
+            TimeInterval::DaysOfWeek { days, hour, minute } => format!(
+                "DaysOfWeek:{}@{:02}:{:02}",
+                days.iter()
+                    .map(|day| format!("{:?}", day))
+                    .collect::<Vec<String>>()
+                    .join(","),
+                hour,
+                minute
+            ),
+            TimeInterval::DaysOfMonth { days, hour, minute } => format!(
+                "DaysOfMonth:{}@{:02}:{:02}",
+                days.iter()
+                    .map(|day| day.to_string())
+                    .collect::<Vec<String>>()
+                    .join(","),
+                hour,
+                minute
+            ),
         }
     }
 }
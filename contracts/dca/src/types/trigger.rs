@@ -1,9 +1,30 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Timestamp, Uint128};
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+
+#[cw_serde]
+pub enum PriceDirection {
+    Above,
+    Below,
+}
+
+impl PriceDirection {
+    pub fn is_reached(&self, target_price: Decimal, current_price: Decimal) -> bool {
+        match self {
+            PriceDirection::Above => current_price >= target_price,
+            PriceDirection::Below => current_price <= target_price,
+        }
+    }
+}
 
 #[cw_serde]
 pub enum TriggerConfiguration {
-    Time { target_time: Timestamp },
+    Time {
+        target_time: Timestamp,
+    },
+    Price {
+        target_price: Decimal,
+        direction: PriceDirection,
+    },
 }
 
 #[cw_serde]
@@ -11,3 +32,23 @@ pub struct Trigger {
     pub vault_id: Uint128,
     pub configuration: TriggerConfiguration,
 }
+
+#[cfg(test)]
+mod price_direction_tests {
+    use super::PriceDirection;
+    use cosmwasm_std::Decimal;
+
+    #[test]
+    fn above_is_reached_when_price_is_at_or_above_target() {
+        assert!(!PriceDirection::Above.is_reached(Decimal::percent(100), Decimal::percent(99)));
+        assert!(PriceDirection::Above.is_reached(Decimal::percent(100), Decimal::percent(100)));
+        assert!(PriceDirection::Above.is_reached(Decimal::percent(100), Decimal::percent(101)));
+    }
+
+    #[test]
+    fn below_is_reached_when_price_is_at_or_below_target() {
+        assert!(PriceDirection::Below.is_reached(Decimal::percent(100), Decimal::percent(99)));
+        assert!(PriceDirection::Below.is_reached(Decimal::percent(100), Decimal::percent(100)));
+        assert!(!PriceDirection::Below.is_reached(Decimal::percent(100), Decimal::percent(101)));
+    }
+}
@@ -1,8 +1,15 @@
 use super::{
-    destination::Destination, performance_assessment_strategy::PerformanceAssessmentStrategy,
-    position_type::PositionType, swap_adjustment_strategy::SwapAdjustmentStrategy,
-    time_interval::TimeInterval, trigger::TriggerConfiguration,
+    accumulation::Accumulation,
+    destination::Destination,
+    execution_window::ExecutionWindow,
+    performance_assessment_strategy::PerformanceAssessmentStrategy,
+    position_type::PositionType,
+    swap_adjustment_strategy::SwapAdjustmentStrategy,
+    termination_condition::{TerminationAction, TerminationCondition, TerminationReason},
+    time_interval::TimeInterval,
+    trigger::TriggerConfiguration,
 };
+use crate::error::ContractError;
 use crate::helpers::time::get_total_execution_duration;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin, Decimal, StdResult, Timestamp, Uint128};
@@ -14,6 +21,7 @@ pub enum VaultStatus {
     Active,
     Inactive,
     Cancelled,
+    Paused,
 }
 
 #[cw_serde]
@@ -39,6 +47,16 @@ pub struct Vault {
     pub trigger: Option<TriggerConfiguration>,
     pub performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
     pub swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+    pub stop_loss: Option<TerminationCondition>,
+    pub take_profit: Option<TerminationCondition>,
+    pub pending_owner: Option<Addr>,
+    pub execution_window: Option<ExecutionWindow>,
+    pub end_time: Option<Timestamp>,
+    pub max_executions: Option<u64>,
+    pub execution_count: u64,
+    pub retry_count: u32,
+    pub accumulation: Option<Accumulation>,
+    pub claimable_amount: Coin,
 }
 
 impl Vault {
@@ -94,6 +112,60 @@ impl Vault {
             })
     }
 
+    pub fn termination_condition_met(
+        &self,
+        position_type: PositionType,
+        belief_price: Decimal,
+    ) -> Result<Option<(TerminationReason, TerminationAction)>, ContractError> {
+        let asset_price = match position_type {
+            PositionType::Enter => belief_price,
+            PositionType::Exit => Decimal::one()
+                .checked_div(belief_price)
+                .map_err(|_| ContractError::ZeroBeliefPrice {})?,
+        };
+
+        if let Some(stop_loss) = self.stop_loss.clone() {
+            if asset_price <= stop_loss.price {
+                return Ok(Some((
+                    TerminationReason::StopLoss { price: asset_price },
+                    stop_loss.action,
+                )));
+            }
+        }
+
+        if let Some(take_profit) = self.take_profit.clone() {
+            if asset_price >= take_profit.price {
+                return Ok(Some((
+                    TerminationReason::TakeProfit { price: asset_price },
+                    take_profit.action,
+                )));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn end_condition_met(&self, current_time: Timestamp) -> Option<TerminationReason> {
+        if let Some(end_time) = self.end_time {
+            if current_time >= end_time {
+                return Some(TerminationReason::EndTimeReached { end_time });
+            }
+        }
+
+        if self.max_executions_reached() {
+            return Some(TerminationReason::MaxExecutionsReached {
+                executions: self.execution_count,
+            });
+        }
+
+        None
+    }
+
+    pub fn max_executions_reached(&self) -> bool {
+        self.max_executions
+            .is_some_and(|max_executions| self.execution_count >= max_executions)
+    }
+
     pub fn is_active(&self) -> bool {
         self.status == VaultStatus::Active
     }
@@ -119,6 +191,10 @@ impl Vault {
     pub fn is_cancelled(&self) -> bool {
         self.status == VaultStatus::Cancelled
     }
+
+    pub fn is_paused(&self) -> bool {
+        self.status == VaultStatus::Paused
+    }
 }
 
 pub struct VaultBuilder {
@@ -142,6 +218,12 @@ pub struct VaultBuilder {
     pub escrowed_amount: Coin,
     pub performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
     pub swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+    pub stop_loss: Option<TerminationCondition>,
+    pub take_profit: Option<TerminationCondition>,
+    pub execution_window: Option<ExecutionWindow>,
+    pub end_time: Option<Timestamp>,
+    pub max_executions: Option<u64>,
+    pub accumulation: Option<Accumulation>,
 }
 
 impl VaultBuilder {
@@ -188,6 +270,12 @@ impl VaultBuilder {
             escrowed_amount,
             performance_assessment_strategy,
             swap_adjustment_strategy,
+            stop_loss: None,
+            take_profit: None,
+            execution_window: None,
+            end_time: None,
+            max_executions: None,
+            accumulation: None,
         }
     }
 
@@ -201,6 +289,7 @@ impl VaultBuilder {
             destinations: self.destinations,
             status: self.status,
             balance: self.balance.clone(),
+            claimable_amount: Coin::new(0, self.target_denom.clone()),
             target_denom: self.target_denom,
             swap_amount: self.swap_amount,
             slippage_tolerance: self.slippage_tolerance,
@@ -213,6 +302,15 @@ impl VaultBuilder {
             escrowed_amount: self.escrowed_amount,
             performance_assessment_strategy: self.performance_assessment_strategy,
             swap_adjustment_strategy: self.swap_adjustment_strategy,
+            stop_loss: self.stop_loss,
+            take_profit: self.take_profit,
+            pending_owner: None,
+            execution_window: self.execution_window,
+            end_time: self.end_time,
+            max_executions: self.max_executions,
+            execution_count: 0,
+            retry_count: 0,
+            accumulation: self.accumulation,
             trigger: None,
         }
     }
@@ -420,3 +518,193 @@ mod price_threshold_exceeded_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod termination_condition_met_tests {
+    use super::*;
+    use crate::types::termination_condition::{TerminationAction, TerminationCondition};
+
+    #[test]
+    fn should_be_none_when_no_conditions_are_set() {
+        let vault = Vault::default();
+
+        assert_eq!(
+            vault
+                .termination_condition_met(PositionType::Enter, Decimal::one())
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn should_be_stop_loss_when_asset_price_falls_to_stop_loss_price() {
+        let vault = Vault {
+            stop_loss: Some(TerminationCondition {
+                price: Decimal::percent(80),
+                action: TerminationAction::Refund,
+            }),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault
+                .termination_condition_met(PositionType::Enter, Decimal::percent(81))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            vault
+                .termination_condition_met(PositionType::Enter, Decimal::percent(80))
+                .unwrap(),
+            Some((
+                TerminationReason::StopLoss {
+                    price: Decimal::percent(80)
+                },
+                TerminationAction::Refund
+            ))
+        );
+    }
+
+    #[test]
+    fn should_be_take_profit_when_asset_price_rises_to_take_profit_price() {
+        let vault = Vault {
+            take_profit: Some(TerminationCondition {
+                price: Decimal::percent(120),
+                action: TerminationAction::Swap,
+            }),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault
+                .termination_condition_met(PositionType::Enter, Decimal::percent(119))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            vault
+                .termination_condition_met(PositionType::Enter, Decimal::percent(120))
+                .unwrap(),
+            Some((
+                TerminationReason::TakeProfit {
+                    price: Decimal::percent(120)
+                },
+                TerminationAction::Swap
+            ))
+        );
+    }
+
+    #[test]
+    fn should_invert_belief_price_for_exit_positions() {
+        let vault = Vault {
+            stop_loss: Some(TerminationCondition {
+                price: Decimal::percent(50),
+                action: TerminationAction::Swap,
+            }),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault
+                .termination_condition_met(PositionType::Enter, Decimal::percent(200))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            vault
+                .termination_condition_met(PositionType::Exit, Decimal::percent(200))
+                .unwrap(),
+            Some((
+                TerminationReason::StopLoss {
+                    price: Decimal::percent(50)
+                },
+                TerminationAction::Swap
+            ))
+        );
+    }
+
+    #[test]
+    fn should_fail_for_exit_positions_with_zero_belief_price() {
+        let vault = Vault {
+            stop_loss: Some(TerminationCondition {
+                price: Decimal::percent(50),
+                action: TerminationAction::Refund,
+            }),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault
+                .termination_condition_met(PositionType::Exit, Decimal::zero())
+                .unwrap_err()
+                .to_string(),
+            "Error: belief price must be greater than 0"
+        );
+    }
+}
+
+#[cfg(test)]
+mod end_condition_met_tests {
+    use crate::types::{termination_condition::TerminationReason, vault::Vault};
+    use cosmwasm_std::Timestamp;
+
+    #[test]
+    fn with_no_end_time_or_max_executions_is_none() {
+        let vault = Vault {
+            execution_count: 100,
+            ..Vault::default()
+        };
+
+        assert_eq!(vault.end_condition_met(Timestamp::from_seconds(100)), None);
+    }
+
+    #[test]
+    fn before_end_time_is_none() {
+        let vault = Vault {
+            end_time: Some(Timestamp::from_seconds(100)),
+            ..Vault::default()
+        };
+
+        assert_eq!(vault.end_condition_met(Timestamp::from_seconds(99)), None);
+    }
+
+    #[test]
+    fn at_end_time_is_end_time_reached() {
+        let vault = Vault {
+            end_time: Some(Timestamp::from_seconds(100)),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault.end_condition_met(Timestamp::from_seconds(100)),
+            Some(TerminationReason::EndTimeReached {
+                end_time: Timestamp::from_seconds(100)
+            })
+        );
+    }
+
+    #[test]
+    fn with_fewer_executions_than_max_executions_is_none() {
+        let vault = Vault {
+            max_executions: Some(3),
+            execution_count: 2,
+            ..Vault::default()
+        };
+
+        assert_eq!(vault.end_condition_met(Timestamp::from_seconds(100)), None);
+    }
+
+    #[test]
+    fn with_max_executions_reached_is_max_executions_reached() {
+        let vault = Vault {
+            max_executions: Some(3),
+            execution_count: 3,
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault.end_condition_met(Timestamp::from_seconds(100)),
+            Some(TerminationReason::MaxExecutionsReached { executions: 3 })
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use super::{
+    accumulation::Accumulation, execution_window::ExecutionWindow,
+    termination_condition::TerminationCondition,
+};
+use cosmwasm_std::{Decimal, Uint64};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VaultSettings {
+    pub target_price: Option<Decimal>,
+    pub stop_loss: Option<TerminationCondition>,
+    pub take_profit: Option<TerminationCondition>,
+    pub execution_window: Option<ExecutionWindow>,
+    pub end_time_utc_seconds: Option<Uint64>,
+    pub max_executions: Option<u64>,
+    pub accumulation: Option<Accumulation>,
+}